@@ -0,0 +1,99 @@
+//! Elementary AD operations, compared against derivatives generated by Symars.
+
+use criterion::measurement::WallTime;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use nalgebra::{Const, SMatrix, SVector};
+use raddy::{make::var, types::advec, Ad};
+
+// Symars-generated code
+#[allow(clippy::all, unused_parens)]
+#[path = "../src/misc/symbolic_1.rs"]
+mod symbolic_1;
+
+/// A scalar depending on all `N` variables, with a non-zero Hessian
+fn scalar_of<const N: usize>() -> Ad<N> {
+    let x: advec<N, N> = var::vector(&SVector::from_fn(|i, _| 0.3 + 0.1 * i as f64));
+    x.norm_squared().mul_value(0.1) + Ad::inactive_scalar(0.2)
+}
+
+fn elementary<const N: usize>(group: &mut BenchmarkGroup<'_, WallTime>) {
+    let a = scalar_of::<N>();
+    let b = a.sin() + Ad::inactive_scalar(2.0);
+
+    group.bench_function(format!("sin/{}", N), |bench| {
+        bench.iter(|| black_box(&a).sin())
+    });
+    group.bench_function(format!("exp/{}", N), |bench| {
+        bench.iter(|| black_box(&a).exp())
+    });
+    group.bench_function(format!("sqrt/{}", N), |bench| {
+        bench.iter(|| black_box(&a).sqrt())
+    });
+    group.bench_function(format!("powi/{}", N), |bench| {
+        bench.iter(|| black_box(&a).powi(3))
+    });
+    group.bench_function(format!("mul/{}", N), |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+    group.bench_function(format!("div/{}", N), |bench| {
+        bench.iter(|| black_box(&a) / black_box(&b))
+    });
+}
+
+fn scalar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scalar");
+    elementary::<1>(&mut group);
+    elementary::<4>(&mut group);
+    elementary::<12>(&mut group);
+    elementary::<30>(&mut group);
+    group.finish();
+
+    // cosh(s) * sinh(s) * 1.245 * s^2 + tanh(s)
+    let mut group = c.benchmark_group("scalar_expression");
+    group.bench_function("raddy", |bench| {
+        bench.iter(|| {
+            let s = &var::scalar(black_box(1.4623));
+            let expr = s.cosh() * (s.sinh() * (Ad::inactive_scalar(1.245) / s.powi(-2))) + s.tanh();
+            (expr.grad()[0], expr.hess()[(0, 0)])
+        })
+    });
+    group.bench_function("symars", |bench| {
+        bench.iter(|| {
+            let s = black_box(1.4623);
+            (symbolic_1::grad_0(s), symbolic_1::hess_0(s))
+        })
+    });
+    group.finish();
+}
+
+fn matrix(c: &mut Criterion) {
+    let vals = [1.2, -4.2, 2.4, 0.4, 3.1, -0.7, 1.9, 2.2, -1.3];
+    let a: SMatrix<Ad<9>, 3, 3> =
+        var::vector_from_slice::<9>(&vals).reshape_generic(Const::<3>, Const::<3>);
+    let b = a.map(|x| x.sin());
+
+    let mut group = c.benchmark_group("admat_3x3");
+    group.bench_function("determinant/raddy", |bench| {
+        bench.iter(|| {
+            let det = black_box(&a).determinant();
+            (det.grad(), det.hess())
+        })
+    });
+    group.bench_function("determinant/symars", |bench| {
+        bench.iter(|| {
+            let [a0, a1, a2, a3, a4, a5, a6, a7, a8] = black_box(vals);
+            (
+                symbolic_1::grad_det3(a0, a1, a2, a3, a4, a5, a6, a7, a8),
+                symbolic_1::hess_det3(a0, a1, a2, a3, a4, a5, a6, a7, a8),
+            )
+        })
+    });
+    group.bench_function("norm", |bench| bench.iter(|| black_box(&a).norm()));
+    group.bench_function("matmul", |bench| {
+        bench.iter(|| black_box(&a) * black_box(&b))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, scalar, matrix);
+criterion_main!(benches);
@@ -0,0 +1,169 @@
+//! Sparse assembly of mass-spring and Neo-Hookean energies on meshes.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use faer::Col;
+use nalgebra::{SVector, Vector3};
+use raddy::{
+    energy::{MassSpring, StableNeoHookean, TetRest},
+    mesh::dofs,
+    solve::{ElementwiseTerm, Problem},
+    sparse::objective::Objective,
+    types::advec,
+    Ad,
+};
+
+// Symars-generated code
+#[allow(clippy::all, unused_parens)]
+#[path = "../src/misc/symbolic_massspring.rs"]
+mod symbolic_massspring;
+
+/// The three springs of a triangle with a common rest length, as in `spring3_energy`
+struct TriangleSprings {
+    springs: MassSpring,
+}
+
+impl Objective<6> for TriangleSprings {
+    type EvalArgs = f64;
+
+    fn eval(&self, variables: &advec<6, 6>, restlen: &f64) -> Ad<6> {
+        let vertex =
+            |i: usize| advec::<6, 2>::new(variables[2 * i].clone(), variables[2 * i + 1].clone());
+        let (p1, p2, p3) = (vertex(0), vertex(1), vertex(2));
+
+        [(&p1, &p2), (&p2, &p3), (&p1, &p3)]
+            .into_iter()
+            .map(|(a, b)| (b - a).norm() - Ad::inactive_scalar(*restlen))
+            .fold(Ad::inactive_scalar(0.0), |acc, stretch| {
+                acc + stretch.powi(2)
+            })
+            .mul_value(0.5 * self.springs.stiffness)
+    }
+}
+
+/// Triangles of an `n` by `n` grid of unit squares in 2D
+fn triangle_grid(n: usize) -> (Col<f64>, Vec<[usize; 3]>) {
+    let vertex = |i: usize, j: usize| i * (n + 1) + j;
+    let x = Col::from_fn(2 * (n + 1) * (n + 1), |k| {
+        let (v, axis) = (k / 2, k % 2);
+        let (i, j) = (v / (n + 1), v % (n + 1));
+        // Slightly stretched, away from rest
+        1.1 * if axis == 0 { j as f64 } else { i as f64 }
+    });
+    let triangles = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .flat_map(|(i, j)| {
+            [
+                [vertex(i, j), vertex(i, j + 1), vertex(i + 1, j + 1)],
+                [vertex(i, j), vertex(i + 1, j + 1), vertex(i + 1, j)],
+            ]
+        })
+        .collect();
+    (x, triangles)
+}
+
+/// Tetrahedra of an `n`^3 grid of unit cubes, 6 per cube
+fn tet_grid(n: usize) -> (Vec<Vector3<f64>>, Vec<[usize; 4]>) {
+    let vertex = |i: usize, j: usize, k: usize| (i * (n + 1) + j) * (n + 1) + k;
+    let positions = (0..(n + 1).pow(3))
+        .map(|v| {
+            let (i, j, k) = (v / (n + 1).pow(2), v / (n + 1) % (n + 1), v % (n + 1));
+            Vector3::new(i as f64, j as f64, k as f64)
+        })
+        .collect();
+
+    let mut tets = Vec::new();
+    for (i, j, k) in (0..n).flat_map(|i| (0..n).flat_map(move |j| (0..n).map(move |k| (i, j, k)))) {
+        let c = |di: usize, dj: usize, dk: usize| vertex(i + di, j + dj, k + dk);
+        // Kuhn subdivision along the diagonal c(0,0,0) - c(1,1,1)
+        let paths = [
+            [c(1, 0, 0), c(1, 1, 0)],
+            [c(1, 0, 0), c(1, 0, 1)],
+            [c(0, 1, 0), c(1, 1, 0)],
+            [c(0, 1, 0), c(0, 1, 1)],
+            [c(0, 0, 1), c(1, 0, 1)],
+            [c(0, 0, 1), c(0, 1, 1)],
+        ];
+        tets.extend(paths.map(|[a, b]| [c(0, 0, 0), a, b, c(1, 1, 1)]));
+    }
+    (positions, tets)
+}
+
+fn mass_spring(c: &mut Criterion) {
+    let energy = TriangleSprings {
+        springs: MassSpring { stiffness: 10.0 },
+    };
+
+    let mut group = c.benchmark_group("mass_spring_grid");
+    for n in [16, 64] {
+        let (x, triangles) = triangle_grid(n);
+        let operand_indices: Vec<[usize; 6]> = dofs(&triangles, 2);
+
+        group.bench_with_input(BenchmarkId::new("raddy", n), &n, |b, _| {
+            b.iter(|| energy.compute(black_box(&x), &operand_indices, &1.0))
+        });
+
+        group.bench_with_input(BenchmarkId::new("symars", n), &n, |b, _| {
+            b.iter(|| {
+                let x = black_box(&x);
+                let mut value = 0.0;
+                let mut grad = Col::<f64>::zeros(x.nrows());
+                let mut hess_trips = Vec::with_capacity(operand_indices.len() * 36);
+                for inds in &operand_indices {
+                    let [x1, y1, x2, y2, x3, y3] = inds.map(|i| x[i]);
+                    value += symbolic_massspring::spring3_energy(10.0, 1.0, x1, y1, x2, y2, x3, y3);
+                    let g = symbolic_massspring::spring3_grad(10.0, 1.0, x1, y1, x2, y2, x3, y3);
+                    let h = symbolic_massspring::spring3_hess(10.0, 1.0, x1, y1, x2, y2, x3, y3);
+                    for (a, &ia) in inds.iter().enumerate() {
+                        grad[ia] += g[a];
+                        for (b, &ib) in inds.iter().enumerate() {
+                            hess_trips.push((ia, ib, h[(a, b)]));
+                        }
+                    }
+                }
+                (value, grad, hess_trips)
+            })
+        });
+
+        #[cfg(feature = "rayon")]
+        {
+            use raddy::sparse::parallel::ParObjective;
+            group.bench_with_input(BenchmarkId::new("raddy_parallel", n), &n, |b, _| {
+                b.iter(|| energy.par_compute(black_box(&x), &operand_indices, &1.0))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn neo_hookean(c: &mut Criterion) {
+    let material = StableNeoHookean::from_young_poisson(1e3, 0.3);
+
+    let mut group = c.benchmark_group("neo_hookean_tet_grid");
+    group.sample_size(20);
+    for n in [4, 8] {
+        let (positions, tets) = tet_grid(n);
+        let rest: Vec<TetRest> = tets
+            .iter()
+            .map(|t| TetRest::new(&t.map(|i| positions[i])))
+            .collect();
+        let operand_indices: Vec<[usize; 12]> = dofs(&tets, 3);
+        let x = Col::from_fn(3 * positions.len(), |i| {
+            let p: SVector<f64, 3> = positions[i / 3];
+            // Compressed along z
+            if i % 3 == 2 {
+                0.8 * p[2]
+            } else {
+                p[i % 3]
+            }
+        });
+
+        let term = ElementwiseTerm::new(&material, &operand_indices, &rest);
+        group.bench_with_input(BenchmarkId::new("grad_hess", n), &n, |b, _| {
+            b.iter(|| (term.grad(black_box(&x)), term.hess_trips(black_box(&x))))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, mass_spring, neo_hookean);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use faer::Col;
+use nalgebra::SVector;
+use raddy::{
+    energy::MassSpring,
+    make::var,
+    sparse::objective::{ComputedObjective, Objective},
+    Ad,
+};
+
+/// A chain of springs in 2D
+fn chain(n_springs: usize) -> (Col<f64>, Vec<[usize; 4]>) {
+    let x = Col::from_fn(2 * (n_springs + 1), |i| (i as f64 * 0.7).sin() + i as f64);
+    let springs = (0..n_springs)
+        .map(|i| [2 * i, 2 * i + 1, 2 * i + 2, 2 * i + 3])
+        .collect();
+    (x, springs)
+}
+
+/// Active vector construction through a heap-allocated `Vec`, as done before
+fn active_vector_via_vec<const N: usize>(values: &[f64]) -> SVector<Ad<N>, N> {
+    let scalars: Vec<Ad<N>> = (0..N)
+        .map(|i| {
+            let mut grad = SVector::zeros();
+            grad[i] = 1.0;
+            Ad::given_vector(values[i], &grad, &nalgebra::SMatrix::zeros())
+        })
+        .collect();
+    SVector::from_column_slice(&scalars)
+}
+
+fn active_vector(c: &mut Criterion) {
+    let values = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2];
+
+    let mut group = c.benchmark_group("active_vector_12");
+    group.bench_function("via_vec", |b| {
+        b.iter(|| active_vector_via_vec::<12>(black_box(&values)))
+    });
+    group.bench_function("direct", |b| {
+        b.iter(|| var::vector_from_fn::<12>(|i| black_box(&values)[i]))
+    });
+    group.finish();
+}
+
+fn compute(c: &mut Criterion) {
+    let spring = MassSpring { stiffness: 2.0 };
+
+    let mut group = c.benchmark_group("mass_spring_compute");
+    for n_springs in [1_000, 100_000] {
+        let (x, springs) = chain(n_springs);
+
+        group.bench_with_input(
+            BenchmarkId::new("compute", n_springs),
+            &n_springs,
+            |b, _| b.iter(|| spring.compute(black_box(&x), &springs, &0.5)),
+        );
+
+        let mut computed = ComputedObjective::new();
+        group.bench_with_input(
+            BenchmarkId::new("compute_into", n_springs),
+            &n_springs,
+            |b, _| b.iter(|| spring.compute_into(black_box(&x), &springs, &0.5, &mut computed)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, active_vector, compute);
+criterion_main!(benches);
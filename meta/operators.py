@@ -4,11 +4,11 @@ from datetime import datetime
 def neg(ref):
     t = "&" if ref else ""
     code = f"""
-impl<const N: usize> Neg for {t}Ad<N> {{
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Neg for {t}Ad<N, T> {{
+    type Output = Ad<N, T>;
 
-    fn neg(self) -> Ad<N> {{
-        let mut res = Ad::<N>::_zeroed();
+    fn neg(self) -> Ad<N, T> {{
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = -self.value;
         res.grad = -self.grad;
         res.hess = -self.hess;
@@ -26,11 +26,11 @@ def add(l_ref, r_ref):
 
     code = f"""
 // {left}T + {right}T
-impl<const N: usize> Add<{right}Ad<N>> for {left}Ad<N> {{
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Add<{right}Ad<N, T>> for {left}Ad<N, T> {{
+    type Output = Ad<N, T>;
 
-    fn add(self, rhs: {right}Ad<N>) -> Self::Output {{
-        let mut res = Ad::<N>::_zeroed();
+    fn add(self, rhs: {right}Ad<N, T>) -> Self::Output {{
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value + rhs.value;
         res.grad = self.grad + rhs.grad;
         res.hess = self.hess + rhs.hess;
@@ -50,11 +50,11 @@ def sub(l_ref, r_ref):
 
     code = f"""
 // {left}T - {right}T
-impl<const N: usize> Sub<{right}Ad<N>> for {left}Ad<N> {{
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Sub<{right}Ad<N, T>> for {left}Ad<N, T> {{
+    type Output = Ad<N, T>;
 
-    fn sub(self, rhs: {right}Ad<N>) -> Self::Output {{
-        let mut res = Ad::<N>::_zeroed();
+    fn sub(self, rhs: {right}Ad<N, T>) -> Self::Output {{
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value - rhs.value;
         res.grad = self.grad - rhs.grad;
         res.hess = self.hess - rhs.hess;
@@ -74,16 +74,16 @@ def mul(l_ref, r_ref):
 
     code = f"""
 // {left}T * {right}T
-impl<const N: usize> Mul<{right}Ad<N>> for {left}Ad<N> {{
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Mul<{right}Ad<N, T>> for {left}Ad<N, T> {{
+    type Output = Ad<N, T>;
 
-    fn mul(self, rhs: {right}Ad<N>) -> Self::Output {{
-        let mut res = Ad::<N>::_zeroed();
+    fn mul(self, rhs: {right}Ad<N, T>) -> Self::Output {{
+        let mut res = Ad::<N, T>::_zeroed();
 
         res.value = self.value * rhs.value;
-        res.grad = self.grad * rhs.value + self.value * rhs.grad;
-        res.hess = rhs.value * self.hess
-            + self.value * rhs.hess
+        res.grad = self.grad * rhs.value + rhs.grad * self.value;
+        res.hess = self.hess * rhs.value
+            + rhs.hess * self.value
             + self.grad * rhs.grad.transpose()
             + rhs.grad * self.grad.transpose();
 
@@ -102,22 +102,22 @@ def div(l_ref, r_ref):
 
     code = f"""
 // {left}T / {right}T
-impl<const N: usize> Div<{right}Ad<N>> for {left}Ad<N> {{
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Div<{right}Ad<N, T>> for {left}Ad<N, T> {{
+    type Output = Ad<N, T>;
 
-    fn div(self, rhs: {right}Ad<N>) -> Self::Output {{
-        if rhs.value.abs() == 0.0 {{
+    fn div(self, rhs: {right}Ad<N, T>) -> Self::Output {{
+        if rhs.value.is_zero() {{
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Division By Zero!");
         }}
 
-        let mut res = Ad::<N>::_zeroed();
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value / rhs.value;
-        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+        res.grad = (self.grad * rhs.value - rhs.grad * self.value) / (rhs.value * rhs.value);
         res.hess = (self.hess
             - res.grad * rhs.grad.transpose()
             - rhs.grad * res.grad.transpose()
-            - res.value * rhs.hess)
+            - rhs.hess * res.value)
             / rhs.value;
 
         res
@@ -135,10 +135,10 @@ def rem(l_ref, r_ref):
 
     code = f"""
 // {left}T % {right}T
-impl<const N: usize> Rem<{right}Ad<N>> for {left}Ad<N> {{
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Rem<{right}Ad<N, T>> for {left}Ad<N, T> {{
+    type Output = Ad<N, T>;
 
-    fn rem(self, rhs: {right}Ad<N>) -> Self::Output {{
+    fn rem(self, rhs: {right}Ad<N, T>) -> Self::Output {{
         unimplemented!();
     }}
 }}
@@ -153,8 +153,8 @@ def op_assign(cased_op_name, operator, r_ref):
 
     code = f"""
 // T {operator}= {right}T
-impl<const N: usize> {cased_op_name}Assign<{right}Ad<N>> for Ad<N> {{
-    fn {cased_op_name.lower()}_assign(&mut self, rhs: {right}Ad<N>) {{
+impl<const N: usize, T: Real> {cased_op_name}Assign<{right}Ad<N, T>> for Ad<N, T> {{
+    fn {cased_op_name.lower()}_assign(&mut self, rhs: {right}Ad<N, T>) {{
         *self = &*self {operator} rhs;
     }}
 }}
@@ -170,8 +170,306 @@ def rem_assign_unimpl(r_ref):
 
     code = f"""
 // T %= {right}T
-impl<const N: usize> RemAssign<{right}Ad<N>> for Ad<N> {{
-    fn rem_assign(&mut self, rhs: {right}Ad<N>) {{
+impl<const N: usize, T: Real> RemAssign<{right}Ad<N, T>> for Ad<N, T> {{
+    fn rem_assign(&mut self, rhs: {right}Ad<N, T>) {{
+        unimplemented!();
+    }}
+}}
+
+
+"""
+
+    return code
+
+
+def dyn_neg(ref):
+    t = "&" if ref else ""
+    code = f"""
+impl Neg for {t}AdDyn {{
+    type Output = AdDyn;
+
+    fn neg(self) -> AdDyn {{
+        AdDyn {{
+            value: -self.value,
+            grad: -&self.grad,
+            hess: -&self.hess,
+        }}
+    }}
+}}
+"""
+    return code
+
+
+def dyn_binary(trait, method, operator, l_ref, r_ref, body):
+    left = "&" if l_ref else ""
+    right = "&" if r_ref else ""
+
+    code = f"""
+// {left}T {operator} {right}T
+impl {trait}<{right}AdDyn> for {left}AdDyn {{
+    type Output = AdDyn;
+
+    fn {method}(self, rhs: {right}AdDyn) -> Self::Output {{
+{body(l_ref, r_ref)}
+    }}
+}}
+
+"""
+
+    return code
+
+
+def dyn_operands(l_ref, r_ref):
+    lhs = "self" if l_ref else "&self"
+    rhs = "rhs" if r_ref else "&rhs"
+    return lhs, rhs
+
+
+def dyn_add_body(l_ref, r_ref):
+    lhs, rhs = dyn_operands(l_ref, r_ref)
+    return f"""        AdDyn::chain2(
+            self.value + rhs.value,
+            1.0,
+            1.0,
+            0.0,
+            0.0,
+            0.0,
+            {lhs},
+            {rhs},
+        )"""
+
+
+def dyn_sub_body(l_ref, r_ref):
+    lhs, rhs = dyn_operands(l_ref, r_ref)
+    return f"""        AdDyn::chain2(
+            self.value - rhs.value,
+            1.0,
+            -1.0,
+            0.0,
+            0.0,
+            0.0,
+            {lhs},
+            {rhs},
+        )"""
+
+
+def dyn_mul_body(l_ref, r_ref):
+    lhs, rhs = dyn_operands(l_ref, r_ref)
+    return f"""        AdDyn::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            {lhs},
+            {rhs},
+        )"""
+
+
+def dyn_div_body(l_ref, r_ref):
+    lhs, rhs = dyn_operands(l_ref, r_ref)
+    return f"""        if rhs.value.abs() == 0.0 {{
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }}
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdDyn::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            {lhs},
+            {rhs},
+        )"""
+
+
+def dyn_rem_body(l_ref, r_ref):
+    return """        unimplemented!();"""
+
+
+def dyn_op_assign(cased_op_name, operator, r_ref):
+    right = "&" if r_ref else ""
+
+    code = f"""
+// T {operator}= {right}T
+impl {cased_op_name}Assign<{right}AdDyn> for AdDyn {{
+    fn {cased_op_name.lower()}_assign(&mut self, rhs: {right}AdDyn) {{
+        *self = &*self {operator} rhs;
+    }}
+}}
+
+
+"""
+
+    return code
+
+
+def dyn_rem_assign_unimpl(r_ref):
+    right = "&" if r_ref else ""
+
+    code = f"""
+// T %= {right}T
+impl RemAssign<{right}AdDyn> for AdDyn {{
+    fn rem_assign(&mut self, rhs: {right}AdDyn) {{
+        unimplemented!();
+    }}
+}}
+
+
+"""
+
+    return code
+
+
+def generate_dyn(formatted_date):
+    res = f"""/*
+
+This code is generated by meta/operators.py at {formatted_date}
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdDyn;
+use std::ops::{{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
+"""
+
+    res += dyn_neg(True)
+    res += dyn_neg(False)
+
+    comb = [[True, True], [True, False], [False, True], [False, False]]
+
+    binaries = [
+        ["Add", "add", "+", dyn_add_body],
+        ["Sub", "sub", "-", dyn_sub_body],
+        ["Mul", "mul", "*", dyn_mul_body],
+        ["Div", "div", "/", dyn_div_body],
+        ["Rem", "rem", "%", dyn_rem_body],
+    ]
+
+    for trait, method, opr, body in binaries:
+        for ll, rr in comb:
+            res += dyn_binary(trait, method, opr, ll, rr, body)
+
+    ops = [
+        ["Add", "+"],
+        ["Sub", "-"],
+        ["Mul", "*"],
+        ["Div", "/"],
+    ]
+
+    for name, opr in ops:
+        res += dyn_op_assign(name, opr, True)
+        res += dyn_op_assign(name, opr, False)
+
+    res += dyn_rem_assign_unimpl(True)
+    res += dyn_rem_assign_unimpl(False)
+
+    with open("src/dynamic/operator_traits_impl.rs", "w") as sf:
+        sf.write(res)
+
+
+def grad_neg(ref):
+    t = "&" if ref else ""
+    code = f"""
+impl<const N: usize> Neg for {t}AdGrad<N> {{
+    type Output = AdGrad<N>;
+
+    fn neg(self) -> AdGrad<N> {{
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = -self.value;
+        res.grad = -self.grad;
+
+        res
+    }}
+}}
+"""
+    return code
+
+
+def grad_binary(trait, method, operator, l_ref, r_ref, body):
+    left = "&" if l_ref else ""
+    right = "&" if r_ref else ""
+
+    code = f"""
+// {left}T {operator} {right}T
+impl<const N: usize> {trait}<{right}AdGrad<N>> for {left}AdGrad<N> {{
+    type Output = AdGrad<N>;
+
+    fn {method}(self, rhs: {right}AdGrad<N>) -> Self::Output {{
+{body}
+    }}
+}}
+
+"""
+
+    return code
+
+
+GRAD_ADD_BODY = """        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+
+        res"""
+
+GRAD_SUB_BODY = """        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+
+        res"""
+
+GRAD_MUL_BODY = """        let mut res = AdGrad::<N>::_zeroed();
+
+        res.value = self.value * rhs.value;
+        res.grad = self.grad * rhs.value + self.value * rhs.grad;
+
+        res"""
+
+GRAD_DIV_BODY = """        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value / rhs.value;
+        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+
+        res"""
+
+GRAD_REM_BODY = """        unimplemented!();"""
+
+
+def grad_op_assign(cased_op_name, operator, r_ref):
+    right = "&" if r_ref else ""
+
+    code = f"""
+// T {operator}= {right}T
+impl<const N: usize> {cased_op_name}Assign<{right}AdGrad<N>> for AdGrad<N> {{
+    fn {cased_op_name.lower()}_assign(&mut self, rhs: {right}AdGrad<N>) {{
+        *self = &*self {operator} rhs;
+    }}
+}}
+
+
+"""
+
+    return code
+
+
+def grad_rem_assign_unimpl(r_ref):
+    right = "&" if r_ref else ""
+
+    code = f"""
+// T %= {right}T
+impl<const N: usize> RemAssign<{right}AdGrad<N>> for AdGrad<N> {{
+    fn rem_assign(&mut self, rhs: {right}AdGrad<N>) {{
         unimplemented!();
     }}
 }}
@@ -182,6 +480,334 @@ impl<const N: usize> RemAssign<{right}Ad<N>> for Ad<N> {{
     return code
 
 
+def generate_grad(formatted_date):
+    res = f"""/*
+
+This code is generated by meta/operators.py at {formatted_date}
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdGrad;
+use std::ops::{{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
+"""
+
+    res += grad_neg(True)
+    res += grad_neg(False)
+
+    comb = [[True, True], [True, False], [False, True], [False, False]]
+
+    binaries = [
+        ["Add", "add", "+", GRAD_ADD_BODY],
+        ["Sub", "sub", "-", GRAD_SUB_BODY],
+        ["Mul", "mul", "*", GRAD_MUL_BODY],
+        ["Div", "div", "/", GRAD_DIV_BODY],
+        ["Rem", "rem", "%", GRAD_REM_BODY],
+    ]
+
+    for trait, method, opr, body in binaries:
+        for ll, rr in comb:
+            res += grad_binary(trait, method, opr, ll, rr, body)
+
+    ops = [
+        ["Add", "+"],
+        ["Sub", "-"],
+        ["Mul", "*"],
+        ["Div", "/"],
+    ]
+
+    for name, opr in ops:
+        res += grad_op_assign(name, opr, True)
+        res += grad_op_assign(name, opr, False)
+
+    res += grad_rem_assign_unimpl(True)
+    res += grad_rem_assign_unimpl(False)
+
+    with open("src/first_order/operator_traits_impl.rs", "w") as sf:
+        sf.write(res)
+
+
+def third_neg(ref):
+    t = "&" if ref else ""
+    code = f"""
+impl<const N: usize> Neg for {t}AdThird<N> {{
+    type Output = AdThird<N>;
+
+    fn neg(self) -> AdThird<N> {{
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = -self.value;
+        res.grad = -self.grad;
+        res.hess = -self.hess;
+        for k in 0..N {{
+            res.third[k] = -self.third[k];
+        }}
+
+        res
+    }}
+}}
+"""
+    return code
+
+
+def third_binary(trait, method, operator, l_ref, r_ref, body):
+    left = "&" if l_ref else ""
+    right = "&" if r_ref else ""
+
+    code = f"""
+// {left}T {operator} {right}T
+impl<const N: usize> {trait}<{right}AdThird<N>> for {left}AdThird<N> {{
+    type Output = AdThird<N>;
+
+    fn {method}(self, rhs: {right}AdThird<N>) -> Self::Output {{
+{body(l_ref, r_ref)}
+    }}
+}}
+
+"""
+
+    return code
+
+
+def third_add_body(l_ref, r_ref):
+    return """        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+        res.hess = self.hess + rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] + rhs.third[k];
+        }
+
+        res"""
+
+
+def third_sub_body(l_ref, r_ref):
+    return """        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+        res.hess = self.hess - rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] - rhs.third[k];
+        }
+
+        res"""
+
+
+def third_mul_body(l_ref, r_ref):
+    lhs, rhs = dyn_operands(l_ref, r_ref)
+    return f"""        AdThird::compose(
+            self.value * rhs.value,
+            [rhs.value, self.value],
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[[0.0; 2]; 2]; 2],
+            [{lhs}, {rhs}],
+        )"""
+
+
+def third_div_body(l_ref, r_ref):
+    lhs, rhs = dyn_operands(l_ref, r_ref)
+    return f"""        if rhs.value.abs() == 0.0 {{
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }}
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+        let inv2 = inv * inv;
+
+        AdThird::compose(
+            value,
+            [inv, -value * inv],
+            [[0.0, -inv2], [-inv2, 2.0 * value * inv2]],
+            [
+                [[0.0, 0.0], [0.0, 2.0 * inv2 * inv]],
+                [[0.0, 2.0 * inv2 * inv], [2.0 * inv2 * inv, -6.0 * value * inv2 * inv]],
+            ],
+            [{lhs}, {rhs}],
+        )"""
+
+
+def third_rem_body(l_ref, r_ref):
+    return """        unimplemented!();"""
+
+
+def third_op_assign(cased_op_name, operator, r_ref):
+    right = "&" if r_ref else ""
+
+    code = f"""
+// T {operator}= {right}T
+impl<const N: usize> {cased_op_name}Assign<{right}AdThird<N>> for AdThird<N> {{
+    fn {cased_op_name.lower()}_assign(&mut self, rhs: {right}AdThird<N>) {{
+        *self = &*self {operator} rhs;
+    }}
+}}
+
+
+"""
+
+    return code
+
+
+def third_rem_assign_unimpl(r_ref):
+    right = "&" if r_ref else ""
+
+    code = f"""
+// T %= {right}T
+impl<const N: usize> RemAssign<{right}AdThird<N>> for AdThird<N> {{
+    fn rem_assign(&mut self, rhs: {right}AdThird<N>) {{
+        unimplemented!();
+    }}
+}}
+
+
+"""
+
+    return code
+
+
+def generate_third(formatted_date):
+    res = f"""/*
+
+This code is generated by meta/operators.py at {formatted_date}
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdThird;
+use std::ops::{{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
+"""
+
+    res += third_neg(True)
+    res += third_neg(False)
+
+    comb = [[True, True], [True, False], [False, True], [False, False]]
+
+    binaries = [
+        ["Add", "add", "+", third_add_body],
+        ["Sub", "sub", "-", third_sub_body],
+        ["Mul", "mul", "*", third_mul_body],
+        ["Div", "div", "/", third_div_body],
+        ["Rem", "rem", "%", third_rem_body],
+    ]
+
+    for trait, method, opr, body in binaries:
+        for ll, rr in comb:
+            res += third_binary(trait, method, opr, ll, rr, body)
+
+    ops = [
+        ["Add", "+"],
+        ["Sub", "-"],
+        ["Mul", "*"],
+        ["Div", "/"],
+    ]
+
+    for name, opr in ops:
+        res += third_op_assign(name, opr, True)
+        res += third_op_assign(name, opr, False)
+
+    res += third_rem_assign_unimpl(True)
+    res += third_rem_assign_unimpl(False)
+
+    with open("src/third_order/operator_traits_impl.rs", "w") as sf:
+        sf.write(res)
+
+
+def sparse_neg(ref):
+    t = "&" if ref else ""
+    code = f"""
+impl Neg for {t}AdSparse {{
+    type Output = AdSparse;
+
+    fn neg(self) -> AdSparse {{
+        AdSparse {{
+            value: -self.value,
+            grad: self.grad.iter().map(|(&i, &g)| (i, -g)).collect(),
+            hess: self.hess.iter().map(|(&ij, &h)| (ij, -h)).collect(),
+        }}
+    }}
+}}
+"""
+    return code
+
+
+def sparse_accumulate_assign(cased_op_name, operator, r_ref):
+    right = "&" if r_ref else ""
+    rhs = "rhs" if r_ref else "&rhs"
+    sign = "" if operator == "+" else "-"
+
+    code = f"""
+// T {operator}= {right}T
+// Accumulates in place, so summing many terms does not copy the sum each time
+impl {cased_op_name}Assign<{right}AdSparse> for AdSparse {{
+    fn {cased_op_name.lower()}_assign(&mut self, rhs: {right}AdSparse) {{
+        self.value {operator}= rhs.value;
+        self.add_scaled({sign}1.0, {rhs});
+    }}
+}}
+
+
+"""
+
+    return code
+
+
+def generate_sparse(formatted_date):
+    res = f"""/*
+
+This code is generated by meta/operators.py at {formatted_date}
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdSparse;
+use std::ops::{{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
+"""
+
+    res += sparse_neg(True)
+    res += sparse_neg(False)
+
+    comb = [[True, True], [True, False], [False, True], [False, False]]
+
+    # The bodies only go through `chain2`, which both types share
+    binaries = [
+        ["Add", "add", "+", dyn_add_body],
+        ["Sub", "sub", "-", dyn_sub_body],
+        ["Mul", "mul", "*", dyn_mul_body],
+        ["Div", "div", "/", dyn_div_body],
+        ["Rem", "rem", "%", dyn_rem_body],
+    ]
+
+    for trait, method, opr, body in binaries:
+        for ll, rr in comb:
+            res += dyn_binary(trait, method, opr, ll, rr, body).replace("AdDyn", "AdSparse")
+
+    ops = [
+        ["Add", "+"],
+        ["Sub", "-"],
+        ["Mul", "*"],
+        ["Div", "/"],
+    ]
+
+    for name, opr in ops[:2]:
+        res += sparse_accumulate_assign(name, opr, True)
+        res += sparse_accumulate_assign(name, opr, False)
+
+    for name, opr in ops[2:]:
+        res += dyn_op_assign(name, opr, True).replace("AdDyn", "AdSparse")
+        res += dyn_op_assign(name, opr, False).replace("AdDyn", "AdSparse")
+
+    res += dyn_rem_assign_unimpl(True).replace("AdDyn", "AdSparse")
+    res += dyn_rem_assign_unimpl(False).replace("AdDyn", "AdSparse")
+
+    with open("src/sparse_ad/operator_traits_impl.rs", "w") as sf:
+        sf.write(res)
+
+
 if __name__ == "__main__":
     now = datetime.now()
     formatted_date = now.strftime("%H:%M:%S @ %Y.%m.%d")
@@ -194,7 +820,8 @@ Do not modify it directly.
 
 #![allow(unused)]
 
-use crate::Ad;
+use crate::{{types::Real, Ad}};
+use num_traits::Zero;
 use std::ops::{{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
 """
 
@@ -234,3 +861,8 @@ use std::ops::{{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAss
 
     with open("src/scalar/operator_traits_impl.rs", "w") as sf:
         sf.write(res)
+
+    generate_dyn(formatted_date)
+    generate_grad(formatted_date)
+    generate_third(formatted_date)
+    generate_sparse(formatted_date)
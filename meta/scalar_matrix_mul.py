@@ -6,16 +6,123 @@ def scalar_matrix_mul_template(scalar_ref, matrix_ref):
     matrix = "&" if matrix_ref else ""
 
     return f"""
-impl<const N: usize, const R: usize, const C: usize> Mul<{matrix}SMatrix<Ad<N>, R, C>> for {scalar}Ad<N> {{
-    type Output = SMatrix<Ad<N>, R, C>;
+impl<const N: usize, const R: usize, const C: usize, T: Real> Mul<{matrix}SMatrix<Ad<N, T>, R, C>>
+    for {scalar}Ad<N, T>
+{{
+    type Output = SMatrix<Ad<N, T>, R, C>;
 
-    fn mul(self, rhs: {matrix}SMatrix<Ad<N>, R, C>) -> Self::Output {{
+    fn mul(self, rhs: {matrix}SMatrix<Ad<N, T>, R, C>) -> Self::Output {{
         rhs * self{'.clone()' if scalar_ref else ""}
     }}
 }}
 """
 
 
+def dyn_scalar_matrix_mul_template(scalar_ref, matrix_ref):
+    scalar = "&" if scalar_ref else ""
+    matrix = "&" if matrix_ref else ""
+
+    return f"""
+impl<R: Dim, C: Dim> Mul<{matrix}OMatrix<AdDyn, R, C>> for {scalar}AdDyn
+where
+    DefaultAllocator: Allocator<R, C>,
+{{
+    type Output = OMatrix<AdDyn, R, C>;
+
+    fn mul(self, rhs: {matrix}OMatrix<AdDyn, R, C>) -> Self::Output {{
+        rhs * self{'.clone()' if scalar_ref else ""}
+    }}
+}}
+"""
+
+
+def generate_dyn(formatted_date):
+    res = f"""/*
+
+This code is generated by meta/scalar_matrix_mul.py at {formatted_date}
+Do not modify it directly.
+
+*/
+use crate::AdDyn;
+use na::{{allocator::Allocator, DefaultAllocator, Dim, OMatrix}};
+use std::ops::Mul;
+"""
+    res += dyn_scalar_matrix_mul_template(scalar_ref=False, matrix_ref=False)
+    res += dyn_scalar_matrix_mul_template(scalar_ref=True, matrix_ref=False)
+    res += dyn_scalar_matrix_mul_template(scalar_ref=False, matrix_ref=True)
+    res += dyn_scalar_matrix_mul_template(scalar_ref=True, matrix_ref=True)
+    with open("src/dynamic/scalar_matrix_mul.rs", "w") as sf:
+        sf.write(res)
+
+
+def grad_scalar_matrix_mul_template(scalar_ref, matrix_ref):
+    scalar = "&" if scalar_ref else ""
+    matrix = "&" if matrix_ref else ""
+
+    return f"""
+impl<const N: usize, const R: usize, const C: usize> Mul<{matrix}SMatrix<AdGrad<N>, R, C>> for {scalar}AdGrad<N> {{
+    type Output = SMatrix<AdGrad<N>, R, C>;
+
+    fn mul(self, rhs: {matrix}SMatrix<AdGrad<N>, R, C>) -> Self::Output {{
+        rhs * self{'.clone()' if scalar_ref else ""}
+    }}
+}}
+"""
+
+
+def generate_grad(formatted_date):
+    res = f"""/*
+
+This code is generated by meta/scalar_matrix_mul.py at {formatted_date}
+Do not modify it directly.
+
+*/
+use crate::AdGrad;
+use na::SMatrix;
+use std::ops::Mul;
+"""
+    res += grad_scalar_matrix_mul_template(scalar_ref=False, matrix_ref=False)
+    res += grad_scalar_matrix_mul_template(scalar_ref=True, matrix_ref=False)
+    res += grad_scalar_matrix_mul_template(scalar_ref=False, matrix_ref=True)
+    res += grad_scalar_matrix_mul_template(scalar_ref=True, matrix_ref=True)
+    with open("src/first_order/scalar_matrix_mul.rs", "w") as sf:
+        sf.write(res)
+
+
+def third_scalar_matrix_mul_template(scalar_ref, matrix_ref):
+    scalar = "&" if scalar_ref else ""
+    matrix = "&" if matrix_ref else ""
+
+    return f"""
+impl<const N: usize, const R: usize, const C: usize> Mul<{matrix}SMatrix<AdThird<N>, R, C>> for {scalar}AdThird<N> {{
+    type Output = SMatrix<AdThird<N>, R, C>;
+
+    fn mul(self, rhs: {matrix}SMatrix<AdThird<N>, R, C>) -> Self::Output {{
+        rhs * self{'.clone()' if scalar_ref else ""}
+    }}
+}}
+"""
+
+
+def generate_third(formatted_date):
+    res = f"""/*
+
+This code is generated by meta/scalar_matrix_mul.py at {formatted_date}
+Do not modify it directly.
+
+*/
+use crate::AdThird;
+use na::SMatrix;
+use std::ops::Mul;
+"""
+    res += third_scalar_matrix_mul_template(scalar_ref=False, matrix_ref=False)
+    res += third_scalar_matrix_mul_template(scalar_ref=True, matrix_ref=False)
+    res += third_scalar_matrix_mul_template(scalar_ref=False, matrix_ref=True)
+    res += third_scalar_matrix_mul_template(scalar_ref=True, matrix_ref=True)
+    with open("src/third_order/scalar_matrix_mul.rs", "w") as sf:
+        sf.write(res)
+
+
 if __name__ == "__main__":
     now = datetime.now()
     formatted_date = now.strftime("%H:%M:%S @ %Y.%m.%d")
@@ -25,7 +132,7 @@ This code is generated by meta/scalar_matrix_mul.py at {formatted_date}
 Do not modify it directly.
 
 */
-use crate::Ad;
+use crate::{{types::Real, Ad}};
 use na::SMatrix;
 use std::ops::Mul;
 """
@@ -35,3 +142,7 @@ use std::ops::Mul;
     res += scalar_matrix_mul_template(scalar_ref=True, matrix_ref=True)
     with open("src/scalar/scalar_matrix_mul.rs", "w") as sf:
         sf.write(res)
+
+    generate_dyn(formatted_date)
+    generate_grad(formatted_date)
+    generate_third(formatted_date)
@@ -0,0 +1,297 @@
+use crate::{
+    make::var,
+    sparse::objective::{
+        compute_elements_into, elements_grad_into, elements_value, ComputedObjective, ElementArgs,
+        Objective,
+    },
+    types::{advec, vec},
+    Ad,
+};
+use faer::{sparse::SparseColMat, Col};
+use std::fmt;
+
+/// Central finite difference settings used to verify derivatives.
+///
+/// Gradients are compared against central differences of the value, and Hessians
+/// against central differences of the (already verified) gradient.
+/// Both are estimated at the same `2 * n` shifted points, where `n` is the number of variables:
+/// `check_scalar` evaluates the function once per point, `check_objective` calls
+/// [`Objective::value`] and [`Objective::grad`] at each.
+///
+/// The finite difference step `h` is set with [`FiniteDiff::new`], which checks it is positive.
+///
+/// ## Example
+/// ```
+/// use raddy::{check::FiniteDiff, types::advec, Ad};
+/// use nalgebra::Vector2;
+///
+/// let check = FiniteDiff::default().check_scalar(
+///     |x: &advec<2, 2>| -> Ad<2> { &x[0] * x[1].sin() },
+///     &Vector2::new(0.3, 1.2),
+/// );
+/// assert!(check.passes(1e-6, 1e-6), "{}", check);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FiniteDiff {
+    step: f64,
+}
+
+impl Default for FiniteDiff {
+    fn default() -> Self {
+        Self { step: 1e-6 }
+    }
+}
+
+/// Comparison of a single derivative entry with its finite difference estimate
+///
+/// ## Fields
+/// - `row`, `col`: Position of the entry; `col` is `0` for gradients
+/// - `computed`: The derivative computed by AD
+/// - `estimated`: The finite difference estimate
+/// - `abs_error`: `|computed - estimated|`
+/// - `rel_error`: `abs_error / max(|computed|, |estimated|)`, `0.0` if both are zero
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntryError {
+    pub row: usize,
+    pub col: usize,
+    pub computed: f64,
+    pub estimated: f64,
+    pub abs_error: f64,
+    pub rel_error: f64,
+}
+
+impl EntryError {
+    fn new(row: usize, col: usize, computed: f64, estimated: f64) -> Self {
+        let abs_error = (computed - estimated).abs();
+        let scale = computed.abs().max(estimated.abs());
+        let rel_error = if scale == 0.0 { 0.0 } else { abs_error / scale };
+
+        Self {
+            row,
+            col,
+            computed,
+            estimated,
+            abs_error,
+            rel_error,
+        }
+    }
+
+    /// Returns whether the entry is within the absolute *or* the relative tolerance
+    pub fn passes(&self, abs_tol: f64, rel_tol: f64) -> bool {
+        self.abs_error <= abs_tol || self.rel_error <= rel_tol
+    }
+}
+
+/// Result of a derivative check, with one entry per gradient and Hessian entry
+///
+/// ## Fields
+/// - `grad`: Errors of the gradient entries
+/// - `hess`: Errors of the Hessian entries, in column-major order
+#[derive(Debug, Clone)]
+pub struct DerivativeCheck {
+    pub grad: Vec<EntryError>,
+    pub hess: Vec<EntryError>,
+}
+
+impl DerivativeCheck {
+    /// Returns whether every entry is within the absolute or the relative tolerance
+    pub fn passes(&self, abs_tol: f64, rel_tol: f64) -> bool {
+        self.grad
+            .iter()
+            .chain(&self.hess)
+            .all(|e| e.passes(abs_tol, rel_tol))
+    }
+
+    /// Returns the `count` gradient entries with the largest absolute errors
+    pub fn worst_grad(&self, count: usize) -> Vec<EntryError> {
+        worst(&self.grad, count)
+    }
+
+    /// Returns the `count` Hessian entries with the largest absolute errors
+    pub fn worst_hess(&self, count: usize) -> Vec<EntryError> {
+        worst(&self.hess, count)
+    }
+
+    /// Returns the largest absolute error over the gradient
+    pub fn max_grad_error(&self) -> f64 {
+        max_abs_error(&self.grad)
+    }
+
+    /// Returns the largest absolute error over the Hessian
+    pub fn max_hess_error(&self) -> f64 {
+        max_abs_error(&self.hess)
+    }
+}
+
+fn worst(entries: &[EntryError], count: usize) -> Vec<EntryError> {
+    let mut sorted = entries.to_vec();
+    sorted.sort_by(|a, b| b.abs_error.total_cmp(&a.abs_error));
+    sorted.truncate(count);
+    sorted
+}
+
+fn max_abs_error(entries: &[EntryError]) -> f64 {
+    entries.iter().map(|e| e.abs_error).fold(0.0, f64::max)
+}
+
+impl fmt::Display for DerivativeCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SHOWN: usize = 5;
+
+        for (name, worst) in [
+            ("Gradient", self.worst_grad(SHOWN)),
+            ("Hessian", self.worst_hess(SHOWN)),
+        ] {
+            writeln!(f, "{} worst entries:", name)?;
+            for e in worst {
+                writeln!(
+                    f,
+                    "  ({}, {}): computed {:e}, estimated {:e}, abs {:e}, rel {:e}",
+                    e.row, e.col, e.computed, e.estimated, e.abs_error, e.rel_error
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FiniteDiff {
+    /// Creates finite difference settings with the given step
+    ///
+    /// # Panics
+    /// If `step` is not positive
+    pub fn new(step: f64) -> Self {
+        assert!(
+            step > 0.0,
+            "Finite difference step must be positive, got {}",
+            step
+        );
+        Self { step }
+    }
+
+    /// Returns the finite difference step `h`
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Central difference of `f` along each unit vector around `x`
+    fn central<T>(
+        &self,
+        n: usize,
+        mut f: impl FnMut(usize, f64) -> T,
+        sub_scaled: impl Fn(T, T, f64) -> T,
+    ) -> Vec<T> {
+        (0..n)
+            .map(|j| sub_scaled(f(j, self.step), f(j, -self.step), 0.5 / self.step))
+            .collect()
+    }
+
+    /// Checks the gradient and Hessian of a scalar function built with `Ad<N>`
+    ///
+    /// ## Arguments
+    /// - `f`: The function, receiving active variables
+    /// - `x`: The point to check at
+    ///
+    /// ## Returns
+    /// A `DerivativeCheck` with `N` gradient and `N * N` Hessian entries
+    pub fn check_scalar<const N: usize>(
+        &self,
+        f: impl Fn(&advec<N, N>) -> Ad<N>,
+        x: &vec<N>,
+    ) -> DerivativeCheck {
+        let eval = |x: &vec<N>| f(&var::vector(x));
+        let shifted = |j: usize, h: f64| {
+            let mut x = *x;
+            x[j] += h;
+            eval(&x)
+        };
+
+        let computed = eval(x);
+        // One evaluation per shifted point yields both the value and the gradient
+        let (value_diffs, grad_diffs): (Vec<_>, Vec<_>) = self
+            .central(
+                N,
+                |j, h| {
+                    let f = shifted(j, h);
+                    (f.value(), f.grad())
+                },
+                |a, b, s| ((a.0 - b.0) * s, (a.1 - b.1) * s),
+            )
+            .into_iter()
+            .unzip();
+
+        let grad = (0..N)
+            .map(|i| EntryError::new(i, 0, computed.grad()[i], value_diffs[i]))
+            .collect();
+        let hess = (0..N)
+            .flat_map(|j| (0..N).map(move |i| (i, j)))
+            .map(|(i, j)| EntryError::new(i, j, computed.hess()[(i, j)], grad_diffs[j][i]))
+            .collect();
+
+        DerivativeCheck { grad, hess }
+    }
+
+    /// Checks the global gradient and Hessian of [`Objective::compute`].
+    /// The Hessian is compared densely, so this is meant for small test problems.
+    ///
+    /// ## Arguments
+    /// - `objective`: The objective to check
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments, shared or per element
+    ///
+    /// ## Returns
+    /// A `DerivativeCheck` with `n` gradient and `n * n` Hessian entries
+    pub fn check_objective<const N: usize, O, A>(
+        &self,
+        objective: &O,
+        x: &Col<f64>,
+        operand_indices: &[[usize; N]],
+        args: &A,
+    ) -> DerivativeCheck
+    where
+        O: Objective<N>,
+        A: ElementArgs<O::EvalArgs> + ?Sized,
+    {
+        let n = x.nrows();
+        let shifted = |j: usize, h: f64| {
+            let mut x = x.clone();
+            x[j] += h;
+            x
+        };
+
+        let grad_at = |x: &Col<f64>| {
+            let mut grad = Col::zeros(n);
+            elements_grad_into(objective, x, operand_indices, args, &mut grad);
+            grad
+        };
+
+        let mut computed = ComputedObjective::new();
+        compute_elements_into(objective, x, operand_indices, args, &mut computed);
+        let computed_hess = SparseColMat::try_new_from_triplets(n, n, &computed.hess_trips)
+            .expect("Operand indices out of bounds")
+            .to_dense();
+
+        let value_diffs = self.central(
+            n,
+            |j, h| elements_value(objective, &shifted(j, h), operand_indices, args),
+            |a, b, s| (a - b) * s,
+        );
+        let grad_diffs = self.central(
+            n,
+            |j, h| grad_at(&shifted(j, h)),
+            |a, b, s| (a - b) * faer::scale(s),
+        );
+
+        let grad = (0..n)
+            .map(|i| EntryError::new(i, 0, computed.grad[i], value_diffs[i]))
+            .collect();
+        let hess = (0..n)
+            .flat_map(|j| (0..n).map(move |i| (i, j)))
+            .map(|(i, j)| EntryError::new(i, j, computed_hess.read(i, j), grad_diffs[j][i]))
+            .collect();
+
+        DerivativeCheck { grad, hess }
+    }
+}
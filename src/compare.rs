@@ -1,38 +1,194 @@
-use crate::Ad;
+use crate::{types::Real, Ad, AdDyn, AdGrad, AdSparse, AdThird};
 use std::cmp::Ordering;
 
-impl<const N: usize> PartialEq for Ad<N> {
+impl<const N: usize, T: Real> PartialEq for Ad<N, T> {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
     }
 }
 
-impl<const N: usize> PartialOrd for Ad<N> {
+impl<const N: usize, T: Real> PartialOrd for Ad<N, T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.value.partial_cmp(&other.value)
     }
 }
 
-impl<const N: usize> PartialEq<f64> for Ad<N> {
+impl<const N: usize, T: Real> PartialEq<T> for Ad<N, T> {
+    fn eq(&self, other: &T) -> bool {
+        self.value == *other
+    }
+}
+
+impl<const N: usize, T: Real> PartialOrd<T> for Ad<N, T> {
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl<const N: usize> PartialEq<Ad<N, f64>> for f64 {
+    fn eq(&self, other: &Ad<N, f64>) -> bool {
+        *self == other.value
+    }
+}
+
+impl<const N: usize> PartialOrd<Ad<N, f64>> for f64 {
+    fn partial_cmp(&self, other: &Ad<N, f64>) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize> PartialEq<Ad<N, f32>> for f32 {
+    fn eq(&self, other: &Ad<N, f32>) -> bool {
+        *self == other.value
+    }
+}
+
+impl<const N: usize> PartialOrd<Ad<N, f32>> for f32 {
+    fn partial_cmp(&self, other: &Ad<N, f32>) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+impl PartialEq for AdDyn {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for AdDyn {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl PartialEq<f64> for AdDyn {
+    fn eq(&self, other: &f64) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialOrd<f64> for AdDyn {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialEq<AdDyn> for f64 {
+    fn eq(&self, other: &AdDyn) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialOrd<AdDyn> for f64 {
+    fn partial_cmp(&self, other: &AdDyn) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+impl PartialEq for AdSparse {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl PartialOrd for AdSparse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl PartialEq<f64> for AdSparse {
+    fn eq(&self, other: &f64) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialOrd<f64> for AdSparse {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialEq<AdSparse> for f64 {
+    fn eq(&self, other: &AdSparse) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialOrd<AdSparse> for f64 {
+    fn partial_cmp(&self, other: &AdSparse) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize> PartialEq for AdGrad<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const N: usize> PartialOrd for AdGrad<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize> PartialEq<f64> for AdGrad<N> {
+    fn eq(&self, other: &f64) -> bool {
+        self.value == *other
+    }
+}
+
+impl<const N: usize> PartialOrd<f64> for AdGrad<N> {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl<const N: usize> PartialEq<AdGrad<N>> for f64 {
+    fn eq(&self, other: &AdGrad<N>) -> bool {
+        *self == other.value
+    }
+}
+
+impl<const N: usize> PartialOrd<AdGrad<N>> for f64 {
+    fn partial_cmp(&self, other: &AdGrad<N>) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize> PartialEq for AdThird<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const N: usize> PartialOrd for AdThird<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize> PartialEq<f64> for AdThird<N> {
     fn eq(&self, other: &f64) -> bool {
         self.value == *other
     }
 }
 
-impl<const N: usize> PartialOrd<f64> for Ad<N> {
+impl<const N: usize> PartialOrd<f64> for AdThird<N> {
     fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
         self.value.partial_cmp(other)
     }
 }
 
-impl<const N: usize> PartialEq<Ad<N>> for f64 {
-    fn eq(&self, other: &Ad<N>) -> bool {
+impl<const N: usize> PartialEq<AdThird<N>> for f64 {
+    fn eq(&self, other: &AdThird<N>) -> bool {
         *self == other.value
     }
 }
 
-impl<const N: usize> PartialOrd<Ad<N>> for f64 {
-    fn partial_cmp(&self, other: &Ad<N>) -> Option<Ordering> {
+impl<const N: usize> PartialOrd<AdThird<N>> for f64 {
+    fn partial_cmp(&self, other: &AdThird<N>) -> Option<Ordering> {
         self.partial_cmp(&other.value)
     }
 }
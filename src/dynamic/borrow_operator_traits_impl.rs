@@ -0,0 +1,368 @@
+use crate::AdDyn;
+
+// ################################### Unary Operators ###################################
+
+impl AdDyn {
+    pub fn neg(&self) -> Self {
+        -self
+    }
+
+    pub fn sqrt(&self) -> Self {
+        if self.value < -0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Sqrt on negative value!");
+        }
+        let f = self.value.sqrt();
+
+        Self::chain(f, 0.5 / f, -0.25 / (f * self.value), self)
+    }
+
+    pub fn square(&self) -> Self {
+        Self::chain(self.value * self.value, 2.0 * self.value, 2.0, self)
+    }
+
+    pub fn powi(&self, exponent: i32) -> Self {
+        if self.value.abs() == 0.0 && exponent == 0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("0.pow(0) is undefined!");
+        }
+
+        let f2 = self.value.powi(exponent - 2);
+        let f1 = f2 * self.value;
+        let f = f1 * self.value;
+
+        // exponent in float
+        let ef = exponent as f64;
+
+        Self::chain(f, ef * f1, ef * (ef - 1.0) * f2, self)
+    }
+
+    pub fn powf(&self, exponent: f64) -> Self {
+        if self.value.abs() == 0.0 && exponent.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("0.pow(0) is undefined!");
+        }
+
+        let f2 = self.value.powf(exponent - 2.0);
+        let f1 = f2 * self.value;
+        let f = f1 * self.value;
+
+        Self::chain(f, exponent * f1, exponent * (exponent - 1.0) * f2, self)
+    }
+
+    pub fn abs(&self) -> Self {
+        let sign = if self.value >= 0.0 { 1.0 } else { -1.0 };
+
+        Self::chain(self.value.abs(), sign, 0.0, self)
+    }
+
+    pub fn exp(&self) -> Self {
+        let exp_val = self.value.exp();
+
+        Self::chain(exp_val, exp_val, exp_val, self)
+    }
+
+    pub fn ln(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Ln on non-positive value!");
+        }
+        let inv = 1.0 / self.value;
+
+        Self::chain(self.value.ln(), inv, -inv * inv, self)
+    }
+
+    pub fn log(&self, base: f64) -> Self {
+        if self.value <= 0.0 {
+            panic!("Log on non-positive value!");
+        }
+        if base <= 0.0 {
+            panic!("Base must be positive!");
+        }
+
+        let inv = 1.0 / self.value / base.ln();
+
+        Self::chain(self.value.log(base), inv, -inv / self.value, self)
+    }
+
+    pub fn log2(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Log2 on non-positive value!");
+        }
+        let inv = 1.0 / self.value / std::f64::consts::LN_2;
+
+        Self::chain(self.value.log2(), inv, -inv / self.value, self)
+    }
+
+    pub fn log10(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Log10 on non-positive value!");
+        }
+        let inv = 1.0 / self.value / std::f64::consts::LN_10;
+
+        Self::chain(self.value.log10(), inv, -inv / self.value, self)
+    }
+
+    pub fn sin(&self) -> Self {
+        let sin_val = self.value.sin();
+        let cos_val = self.value.cos();
+
+        Self::chain(sin_val, cos_val, -sin_val, self)
+    }
+
+    pub fn cos(&self) -> Self {
+        let cos_val = self.value.cos();
+        let sin_val = self.value.sin();
+
+        Self::chain(cos_val, -sin_val, -cos_val, self)
+    }
+
+    pub fn tan(&self) -> Self {
+        let cos_val = self.value.cos();
+        let cos_sq = cos_val * cos_val;
+
+        Self::chain(
+            self.value.tan(),
+            1.0 / cos_sq,
+            2.0 * self.value.sin() / (cos_sq * cos_val),
+            self,
+        )
+    }
+
+    pub fn asin(&self) -> Self {
+        if self.value < -1.0 || self.value > 1.0 {
+            panic!("Asin out of domain!");
+        }
+        let s = 1.0 - self.value * self.value;
+        let s_sqrt = s.sqrt();
+
+        Self::chain(
+            self.value.asin(),
+            1.0 / s_sqrt,
+            self.value / (s * s_sqrt),
+            self,
+        )
+    }
+
+    pub fn acos(&self) -> Self {
+        if self.value < -1.0 || self.value > 1.0 {
+            panic!("Acos out of domain!");
+        }
+        let s = 1.0 - self.value * self.value;
+        let s_sqrt = s.sqrt();
+
+        Self::chain(
+            self.value.acos(),
+            -1.0 / s_sqrt,
+            -self.value / (s * s_sqrt),
+            self,
+        )
+    }
+
+    #[deprecated = "Please use atan2 instead."]
+    pub fn atan(&self) -> Self {
+        let s = self.value * self.value + 1.0;
+
+        Self::chain(
+            self.value.atan(),
+            1.0 / s,
+            -2.0 * self.value / (s * s),
+            self,
+        )
+    }
+
+    pub fn sinh(&self) -> Self {
+        let sinh_val = self.value.sinh();
+        let cosh_val = self.value.cosh();
+
+        Self::chain(sinh_val, cosh_val, sinh_val, self)
+    }
+
+    pub fn cosh(&self) -> Self {
+        let sinh_val = self.value.sinh();
+        let cosh_val = self.value.cosh();
+
+        Self::chain(cosh_val, sinh_val, cosh_val, self)
+    }
+
+    pub fn tanh(&self) -> Self {
+        let cosh_val = self.value.cosh();
+        let cosh_sq = cosh_val * cosh_val;
+
+        Self::chain(
+            self.value.tanh(),
+            1.0 / cosh_sq,
+            -2.0 * self.value.sinh() / (cosh_sq * cosh_val),
+            self,
+        )
+    }
+
+    pub fn asinh(&self) -> Self {
+        let s = self.value * self.value + 1.0;
+        let s_sqrt = s.sqrt();
+
+        Self::chain(
+            self.value.asinh(),
+            1.0 / s_sqrt,
+            -self.value / (s * s_sqrt),
+            self,
+        )
+    }
+
+    pub fn acosh(&self) -> Self {
+        if self.value < 1.0 {
+            panic!("Acosh out of domain!");
+        }
+        let sm = self.value - 1.0;
+        let sp = self.value + 1.0;
+        let prod = (sm * sp).sqrt();
+
+        Self::chain(
+            self.value.acosh(),
+            1.0 / prod,
+            -self.value / (prod * sm * sp),
+            self,
+        )
+    }
+
+    pub fn atanh(&self) -> Self {
+        if self.value <= -1.0 || self.value >= 1.0 {
+            panic!("Atanh out of domain!");
+        }
+        let s = 1.0 - self.value * self.value;
+
+        Self::chain(
+            self.value.atanh(),
+            1.0 / s,
+            2.0 * self.value / (s * s),
+            self,
+        )
+    }
+}
+
+// ################################### Binary Operators ###################################
+
+impl AdDyn {
+    pub fn add_value(&self, other: f64) -> Self {
+        Self::chain(self.value + other, 1.0, 0.0, self)
+    }
+
+    pub fn sub_value(&self, other: f64) -> Self {
+        Self::chain(self.value - other, 1.0, 0.0, self)
+    }
+
+    pub fn mul_value(&self, other: f64) -> Self {
+        Self::chain(self.value * other, other, 0.0, self)
+    }
+
+    pub fn recip(&self) -> Self {
+        AdDyn::inactive_scalar(1.0) / self
+    }
+
+    pub fn div_value(&self, other: f64) -> Self {
+        if other.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        Self::chain(self.value / other, 1.0 / other, 0.0, self)
+    }
+
+    /// ## self is y
+    pub fn atan2(&self, x: &Self) -> Self {
+        // f(y, x) = atan2(y, x), r = x^2 + y^2
+        let r = x.value * x.value + self.value * self.value;
+        let r_sq = r * r;
+
+        Self::chain2(
+            self.value.atan2(x.value),
+            x.value / r,
+            -self.value / r,
+            -2.0 * x.value * self.value / r_sq,
+            (self.value * self.value - x.value * x.value) / r_sq,
+            2.0 * x.value * self.value / r_sq,
+            self,
+            x,
+        )
+    }
+
+    /// `self^exponent`, differentiated w.r.t. both base and exponent.
+    /// For a constant exponent this is `powf`, otherwise the base must be positive.
+    pub fn pow(&self, exponent: &Self) -> Self {
+        if exponent.is_constant() {
+            return self.powf(exponent.value);
+        }
+        if self.value <= 0.0 {
+            panic!("Pow of non-positive base with active exponent!");
+        }
+
+        let (a, b) = (self.value, exponent.value);
+        let ln_a = a.ln();
+        let f = a.powf(b);
+        let f_a = f / a;
+
+        Self::chain2(
+            f,
+            b * f_a,
+            f * ln_a,
+            b * (b - 1.0) * f_a / a,
+            f_a * (1.0 + b * ln_a),
+            f * ln_a * ln_a,
+            self,
+            exponent,
+        )
+    }
+
+    /// Logarithm of `self` to `base`, differentiated w.r.t. both.
+    /// For a constant base this is `log`.
+    pub fn log_base(&self, base: &Self) -> Self {
+        if base.is_constant() {
+            return self.log(base.value);
+        }
+        if self.value <= 0.0 {
+            panic!("Log on non-positive value!");
+        }
+        if base.value <= 0.0 || base.value == 1.0 {
+            panic!("Base must be positive and not 1!");
+        }
+
+        let (a, b) = (self.value, base.value);
+        let ln_b = b.ln();
+        let f = a.ln() / ln_b;
+
+        Self::chain2(
+            f,
+            1.0 / (a * ln_b),
+            -f / (b * ln_b),
+            -1.0 / (a * a * ln_b),
+            -1.0 / (a * b * ln_b * ln_b),
+            f * (ln_b + 2.0) / (b * b * ln_b * ln_b),
+            self,
+            base,
+        )
+    }
+
+    pub fn min(&self, other: &Self) -> Self {
+        if self < other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+
+    pub fn max(&self, other: &Self) -> Self {
+        if self > other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+
+    pub fn clamp(&self, low: &Self, high: &Self) -> Self {
+        self.max(low).min(high)
+    }
+
+    // Computes hypot(self, b) = sqrt(self^2 + b^2) with gradients and Hessians.
+    pub fn hypot(&self, other: &Self) -> Self {
+        (self * self + other * other).sqrt()
+    }
+}
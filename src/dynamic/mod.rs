@@ -0,0 +1,269 @@
+/// Implementations of operators on `&AdDyn`
+pub mod borrow_operator_traits_impl;
+/// Implementations of nalgebra field traits on `AdDyn`.
+pub mod field_impl;
+pub mod num_traits_impl;
+pub mod operator_traits_impl;
+pub mod scalar_matrix_mul;
+
+use na::{DMatrix, DVector};
+
+// ################################### Data Structure ###################################
+
+/// Automatic differentiation value with runtime dimension, tracking first and second derivatives
+///
+/// Unlike [`Ad<N>`](crate::Ad), the gradient and Hessian are allocated on the heap,
+/// so large local stencils neither overflow the stack nor bloat every clone by value.
+///
+/// # Value getters:
+/// - `value() -> f64`: Returns the current numerical value
+/// - `grad() -> DVector<f64>`: Returns the gradient vector
+/// - `hess() -> DMatrix<f64>`: Returns the Hessian matrix
+///
+/// # Dimension
+/// The dimension of the input space is the length of the gradient.
+/// A value of dimension `0` is a constant: it carries no derivatives and
+/// can be combined with values of any dimension.
+/// Combining two values of different non-zero dimensions panics.
+///
+/// # Fields (private)
+/// * `value` - The current value of the function
+/// * `grad` - The gradient (first derivatives) as a vector
+/// * `hess` - The Hessian matrix (second derivatives)
+#[derive(Debug, Clone)]
+pub struct AdDyn {
+    pub(crate) value: f64,
+    pub(crate) grad: DVector<f64>,
+    pub(crate) hess: DMatrix<f64>,
+}
+
+// ################################### Accessors ###################################
+
+impl AdDyn {
+    /// Returns the current value of the AD variable
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the gradient (first derivatives) of the AD variable
+    ///
+    /// # Returns
+    /// The gradient (A vector containing the partial derivatives with respect to each input variable).
+    /// Empty if the value is a constant.
+    pub fn grad(&self) -> DVector<f64> {
+        self.grad.clone()
+    }
+
+    /// Returns the Hessian matrix (second derivatives) of the AD variable
+    ///
+    /// # Returns
+    /// The [hessian](https://en.wikipedia.org/wiki/Hessian_matrix).
+    /// Empty if the value is a constant.
+    pub fn hess(&self) -> DMatrix<f64> {
+        self.hess.clone()
+    }
+
+    /// Returns the dimension of the input space, `0` for constants
+    pub fn dim(&self) -> usize {
+        self.grad.len()
+    }
+}
+
+// ################################### Public Constructors ###################################
+
+impl AdDyn {
+    /// Creates an inactive (constant) AD value, compatible with any dimension
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    ///
+    /// # Returns
+    /// A new `AdDyn` instance of dimension 0
+    pub fn inactive_scalar(value: f64) -> Self {
+        let mut res = Self::_zeroed(0);
+        res.value = value;
+        res
+    }
+
+    /// Creates a vector of inactive AD values from a slice of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    ///
+    /// # Returns
+    /// A vector of inactive AD values
+    pub fn inactive_from_slice(values: &[f64]) -> DVector<Self> {
+        DVector::from_iterator(
+            values.len(),
+            values.iter().map(|&v| Self::inactive_scalar(v)),
+        )
+    }
+
+    /// Creates an AD value with explicitly specified value, gradient and Hessian
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    /// * `grad` - The gradient vector
+    /// * `hess` - The Hessian matrix
+    ///
+    /// # Panics
+    /// If the Hessian is not square with the same dimension as the gradient
+    ///
+    /// # Returns
+    /// A new `AdDyn` instance with the specified properties
+    pub fn given_vector(value: f64, grad: &DVector<f64>, hess: &DMatrix<f64>) -> Self {
+        assert_eq!(
+            hess.shape(),
+            (grad.len(), grad.len()),
+            "Hessian shape mismatch: expected {}x{}, got {:?}",
+            grad.len(),
+            grad.len(),
+            hess.shape()
+        );
+        Self {
+            value,
+            grad: grad.clone(),
+            hess: hess.clone(),
+        }
+    }
+
+    /// Creates an active AD value which is the `index`-th of `dim` variables
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    /// * `index` - The index of this variable
+    /// * `dim` - The total number of variables
+    ///
+    /// # Panics
+    /// If `index >= dim`
+    pub fn active_scalar(value: f64, index: usize, dim: usize) -> Self {
+        assert!(
+            index < dim,
+            "Variable index out of range: index {}, dimension {}",
+            index,
+            dim
+        );
+        let mut res = Self::_zeroed(dim);
+
+        res.value = value;
+        res.grad[index] = 1.0;
+
+        res
+    }
+
+    /// Creates a vector of active AD values from a vector of f64 values
+    ///
+    /// # Arguments
+    /// * `vector` - Input vector of numerical values
+    ///
+    /// # Returns
+    /// A vector of active AD values where each element has unit gradient
+    /// in its corresponding dimension
+    pub fn active_vector(vector: &DVector<f64>) -> DVector<Self> {
+        Self::active_from_slice(vector.as_slice())
+    }
+
+    /// Creates a vector of active AD values from a slice of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    ///
+    /// # Returns
+    /// A vector of active AD values, whose dimension is the length of the slice
+    pub fn active_from_slice(values: &[f64]) -> DVector<Self> {
+        let dim = values.len();
+        DVector::from_iterator(
+            dim,
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| Self::active_scalar(v, i, dim)),
+        )
+    }
+}
+
+// ################################### Private Constructors ###################################
+
+impl AdDyn {
+    fn _zeroed(dim: usize) -> Self {
+        Self {
+            value: 0.0,
+            grad: DVector::zeros(dim),
+            hess: DMatrix::zeros(dim, dim),
+        }
+    }
+}
+
+// ################################### Utils ###################################
+
+impl AdDyn {
+    /// Dimension of the result of a binary operation on `a` and `b`
+    fn _common_dim(a: &Self, b: &Self) -> usize {
+        match (a.dim(), b.dim()) {
+            (0, n) | (n, 0) => n,
+            (m, n) => {
+                assert_eq!(m, n, "Dimension mismatch: {} vs {}", m, n);
+                n
+            }
+        }
+    }
+
+    fn chain(
+        value: f64, // f
+        d: f64,     // df/da
+        d2: f64,    // ddf/daa
+        a: &Self,
+    ) -> Self {
+        let mut res = Self::_zeroed(0);
+
+        res.value = value;
+        res.grad = d * &a.grad;
+        res.hess = d2 * &a.grad * a.grad.transpose() + d * &a.hess;
+
+        res
+    }
+
+    /// Whether this value has zero gradient and Hessian, i.e. is a constant
+    fn is_constant(&self) -> bool {
+        self.grad.iter().all(|&g| g == 0.0) && self.hess.iter().all(|&h| h == 0.0)
+    }
+
+    /// Binary counterpart of `chain`, treating constants as zero-derivative operands.
+    #[allow(clippy::too_many_arguments)]
+    fn chain2(
+        value: f64, // f
+        da: f64,    // df/da
+        db: f64,    // df/db
+        daa: f64,   // ddf/daa
+        dab: f64,   // ddf/dadb
+        dbb: f64,   // ddf/dbb
+        a: &Self,
+        b: &Self,
+    ) -> Self {
+        let mut res = Self::_zeroed(Self::_common_dim(a, b));
+        res.value = value;
+
+        if a.dim() > 0 {
+            res.grad.axpy(da, &a.grad, 1.0);
+            res.hess += da * &a.hess;
+            if daa != 0.0 {
+                res.hess.ger(daa, &a.grad, &a.grad, 1.0);
+            }
+        }
+
+        if b.dim() > 0 {
+            res.grad.axpy(db, &b.grad, 1.0);
+            res.hess += db * &b.hess;
+            if dbb != 0.0 {
+                res.hess.ger(dbb, &b.grad, &b.grad, 1.0);
+            }
+        }
+
+        if a.dim() > 0 && b.dim() > 0 && dab != 0.0 {
+            res.hess.ger(dab, &a.grad, &b.grad, 1.0);
+            res.hess.ger(dab, &b.grad, &a.grad, 1.0);
+        }
+
+        res
+    }
+}
@@ -0,0 +1,60 @@
+#![allow(unused)]
+use crate::AdDyn;
+use num_traits::{Num, One, Signed, Zero};
+use std::fmt::Display;
+
+impl Display for AdDyn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AdDyn[{}]", self.value)
+    }
+}
+
+impl Zero for AdDyn {
+    /// A constant zero, compatible with any dimension.
+    fn zero() -> Self {
+        AdDyn::_zeroed(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.abs() == 0.0
+            && self.grad.iter().all(|&x| x.abs() == 0.0)
+            && self.hess.iter().all(|&x| x.abs() == 0.0)
+    }
+}
+
+impl One for AdDyn {
+    /// A constant one, compatible with any dimension.
+    fn one() -> Self {
+        AdDyn::inactive_scalar(1.0)
+    }
+}
+
+impl Num for AdDyn {
+    type FromStrRadixErr = ();
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        unimplemented!()
+    }
+}
+
+impl Signed for AdDyn {
+    fn abs(&self) -> Self {
+        AdDyn::abs(self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        unimplemented!()
+    }
+
+    fn signum(&self) -> Self {
+        unimplemented!()
+    }
+
+    fn is_positive(&self) -> bool {
+        self.value > 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.value < -0.0
+    }
+}
@@ -0,0 +1,400 @@
+/*
+
+This code is generated by meta/operators.py at 10:53:20 @ 2026.10.18
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdDyn;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+impl Neg for &AdDyn {
+    type Output = AdDyn;
+
+    fn neg(self) -> AdDyn {
+        AdDyn {
+            value: -self.value,
+            grad: -&self.grad,
+            hess: -&self.hess,
+        }
+    }
+}
+
+impl Neg for AdDyn {
+    type Output = AdDyn;
+
+    fn neg(self) -> AdDyn {
+        AdDyn {
+            value: -self.value,
+            grad: -&self.grad,
+            hess: -&self.hess,
+        }
+    }
+}
+
+// &T + &T
+impl Add<&AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn add(self, rhs: &AdDyn) -> Self::Output {
+        AdDyn::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, self, rhs)
+    }
+}
+
+// &T + T
+impl Add<AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn add(self, rhs: AdDyn) -> Self::Output {
+        AdDyn::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, self, &rhs)
+    }
+}
+
+// T + &T
+impl Add<&AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn add(self, rhs: &AdDyn) -> Self::Output {
+        AdDyn::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, &self, rhs)
+    }
+}
+
+// T + T
+impl Add<AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn add(self, rhs: AdDyn) -> Self::Output {
+        AdDyn::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, &self, &rhs)
+    }
+}
+
+// &T - &T
+impl Sub<&AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn sub(self, rhs: &AdDyn) -> Self::Output {
+        AdDyn::chain2(self.value - rhs.value, 1.0, -1.0, 0.0, 0.0, 0.0, self, rhs)
+    }
+}
+
+// &T - T
+impl Sub<AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn sub(self, rhs: AdDyn) -> Self::Output {
+        AdDyn::chain2(self.value - rhs.value, 1.0, -1.0, 0.0, 0.0, 0.0, self, &rhs)
+    }
+}
+
+// T - &T
+impl Sub<&AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn sub(self, rhs: &AdDyn) -> Self::Output {
+        AdDyn::chain2(self.value - rhs.value, 1.0, -1.0, 0.0, 0.0, 0.0, &self, rhs)
+    }
+}
+
+// T - T
+impl Sub<AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn sub(self, rhs: AdDyn) -> Self::Output {
+        AdDyn::chain2(
+            self.value - rhs.value,
+            1.0,
+            -1.0,
+            0.0,
+            0.0,
+            0.0,
+            &self,
+            &rhs,
+        )
+    }
+}
+
+// &T * &T
+impl Mul<&AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn mul(self, rhs: &AdDyn) -> Self::Output {
+        AdDyn::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            self,
+            rhs,
+        )
+    }
+}
+
+// &T * T
+impl Mul<AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn mul(self, rhs: AdDyn) -> Self::Output {
+        AdDyn::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            self,
+            &rhs,
+        )
+    }
+}
+
+// T * &T
+impl Mul<&AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn mul(self, rhs: &AdDyn) -> Self::Output {
+        AdDyn::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            &self,
+            rhs,
+        )
+    }
+}
+
+// T * T
+impl Mul<AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn mul(self, rhs: AdDyn) -> Self::Output {
+        AdDyn::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            &self,
+            &rhs,
+        )
+    }
+}
+
+// &T / &T
+impl Div<&AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn div(self, rhs: &AdDyn) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdDyn::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            self,
+            rhs,
+        )
+    }
+}
+
+// &T / T
+impl Div<AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn div(self, rhs: AdDyn) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdDyn::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            self,
+            &rhs,
+        )
+    }
+}
+
+// T / &T
+impl Div<&AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn div(self, rhs: &AdDyn) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdDyn::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            &self,
+            rhs,
+        )
+    }
+}
+
+// T / T
+impl Div<AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn div(self, rhs: AdDyn) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdDyn::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            &self,
+            &rhs,
+        )
+    }
+}
+
+// &T % &T
+impl Rem<&AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn rem(self, rhs: &AdDyn) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// &T % T
+impl Rem<AdDyn> for &AdDyn {
+    type Output = AdDyn;
+
+    fn rem(self, rhs: AdDyn) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % &T
+impl Rem<&AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn rem(self, rhs: &AdDyn) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % T
+impl Rem<AdDyn> for AdDyn {
+    type Output = AdDyn;
+
+    fn rem(self, rhs: AdDyn) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T += &T
+impl AddAssign<&AdDyn> for AdDyn {
+    fn add_assign(&mut self, rhs: &AdDyn) {
+        *self = &*self + rhs;
+    }
+}
+
+// T += T
+impl AddAssign<AdDyn> for AdDyn {
+    fn add_assign(&mut self, rhs: AdDyn) {
+        *self = &*self + rhs;
+    }
+}
+
+// T -= &T
+impl SubAssign<&AdDyn> for AdDyn {
+    fn sub_assign(&mut self, rhs: &AdDyn) {
+        *self = &*self - rhs;
+    }
+}
+
+// T -= T
+impl SubAssign<AdDyn> for AdDyn {
+    fn sub_assign(&mut self, rhs: AdDyn) {
+        *self = &*self - rhs;
+    }
+}
+
+// T *= &T
+impl MulAssign<&AdDyn> for AdDyn {
+    fn mul_assign(&mut self, rhs: &AdDyn) {
+        *self = &*self * rhs;
+    }
+}
+
+// T *= T
+impl MulAssign<AdDyn> for AdDyn {
+    fn mul_assign(&mut self, rhs: AdDyn) {
+        *self = &*self * rhs;
+    }
+}
+
+// T /= &T
+impl DivAssign<&AdDyn> for AdDyn {
+    fn div_assign(&mut self, rhs: &AdDyn) {
+        *self = &*self / rhs;
+    }
+}
+
+// T /= T
+impl DivAssign<AdDyn> for AdDyn {
+    fn div_assign(&mut self, rhs: AdDyn) {
+        *self = &*self / rhs;
+    }
+}
+
+// T %= &T
+impl RemAssign<&AdDyn> for AdDyn {
+    fn rem_assign(&mut self, rhs: &AdDyn) {
+        unimplemented!();
+    }
+}
+
+// T %= T
+impl RemAssign<AdDyn> for AdDyn {
+    fn rem_assign(&mut self, rhs: AdDyn) {
+        unimplemented!();
+    }
+}
@@ -0,0 +1,53 @@
+/*
+
+This code is generated by meta/scalar_matrix_mul.py at 10:53:20 @ 2026.10.18
+Do not modify it directly.
+
+*/
+use crate::AdDyn;
+use na::{allocator::Allocator, DefaultAllocator, Dim, OMatrix};
+use std::ops::Mul;
+
+impl<R: Dim, C: Dim> Mul<OMatrix<AdDyn, R, C>> for AdDyn
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    type Output = OMatrix<AdDyn, R, C>;
+
+    fn mul(self, rhs: OMatrix<AdDyn, R, C>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<R: Dim, C: Dim> Mul<OMatrix<AdDyn, R, C>> for &AdDyn
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    type Output = OMatrix<AdDyn, R, C>;
+
+    fn mul(self, rhs: OMatrix<AdDyn, R, C>) -> Self::Output {
+        rhs * self.clone()
+    }
+}
+
+impl<R: Dim, C: Dim> Mul<&OMatrix<AdDyn, R, C>> for AdDyn
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    type Output = OMatrix<AdDyn, R, C>;
+
+    fn mul(self, rhs: &OMatrix<AdDyn, R, C>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<R: Dim, C: Dim> Mul<&OMatrix<AdDyn, R, C>> for &AdDyn
+where
+    DefaultAllocator: Allocator<R, C>,
+{
+    type Output = OMatrix<AdDyn, R, C>;
+
+    fn mul(self, rhs: &OMatrix<AdDyn, R, C>) -> Self::Output {
+        rhs * self.clone()
+    }
+}
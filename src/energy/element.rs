@@ -0,0 +1,149 @@
+use crate::{
+    make::val,
+    sparse::objective::Objective,
+    types::{admat, advec},
+    Ad,
+};
+use na::{Matrix2, Matrix3, Vector2, Vector3};
+
+/// An energy density `ψ(F)` of the deformation gradient `F`
+///
+/// Every density is an [`Objective`] on linear finite elements:
+/// - 2D triangles, with `N = 6` and [`TriangleRest`] as `EvalArgs`
+/// - 3D tetrahedra, with `N = 12` and [`TetRest`] as `EvalArgs`
+///
+/// The variables are the vertex positions, `[x0, y0, x1, y1, ...]`,
+/// and the energy is `ψ(F)` integrated over the rest element.
+/// The elements of a mesh differ in rest shape, so pass one per element
+/// to the `_elementwise` methods of [`Objective`].
+///
+/// ## Example
+/// ```ignore
+/// let material = StableNeoHookean::from_young_poisson(1e5, 0.4);
+/// let rest: Vec<TetRest> = tets
+///     .iter()
+///     .map(|tet| TetRest::new(&tet.map(|i| rest_vertices[i])))
+///     .collect();
+/// let tet_dofs: Vec<[usize; 12]> = mesh::dofs(&tets, 3);
+/// let computed = material.compute_elementwise(&x, &tet_dofs, &rest);
+/// ```
+pub trait Hyperelastic {
+    /// Evaluates the energy density per rest area or volume
+    ///
+    /// ## Arguments
+    /// - `f`: The deformation gradient, 2x2 or 3x3
+    fn density<const N: usize, const D: usize>(&self, f: &admat<N, D, D>) -> Ad<N>;
+}
+
+/// Rest shape of a linear triangle
+///
+/// ## Fields
+/// - `dm_inv`: The inverse of the rest edge matrix `[X1 - X0, X2 - X0]`
+/// - `area`: The rest area
+#[derive(Debug, Clone)]
+pub struct TriangleRest {
+    pub dm_inv: Matrix2<f64>,
+    pub area: f64,
+}
+
+impl TriangleRest {
+    /// Computes the rest shape from 2D rest vertex positions
+    ///
+    /// # Panics
+    /// If the triangle is degenerate
+    pub fn new(vertices: &[Vector2<f64>; 3]) -> Self {
+        let dm = Matrix2::from_columns(&[vertices[1] - vertices[0], vertices[2] - vertices[0]]);
+        let det = dm.determinant();
+        if det == 0.0 {
+            panic!("Degenerate rest triangle!");
+        }
+
+        Self {
+            dm_inv: dm.try_inverse().unwrap(),
+            area: 0.5 * det.abs(),
+        }
+    }
+
+    /// Computes the rest shape of a triangle in 3D, in an orthonormal frame of its plane.
+    /// This is the rest shape of parameterization energies, which map it to 2D.
+    ///
+    /// # Panics
+    /// If the triangle is degenerate
+    pub fn flattened(vertices: &[Vector3<f64>; 3]) -> Self {
+        let e1 = vertices[1] - vertices[0];
+        let e2 = vertices[2] - vertices[0];
+        let normal = e1.cross(&e2);
+        if normal.norm() == 0.0 {
+            panic!("Degenerate rest triangle!");
+        }
+
+        let t1 = e1.normalize();
+        let t2 = normal.cross(&e1).normalize();
+        Self::new(&[
+            Vector2::zeros(),
+            Vector2::new(e1.dot(&t1), 0.0),
+            Vector2::new(e2.dot(&t1), e2.dot(&t2)),
+        ])
+    }
+}
+
+/// Rest shape of a linear tetrahedron
+///
+/// ## Fields
+/// - `dm_inv`: The inverse of the rest edge matrix `[X1 - X0, X2 - X0, X3 - X0]`
+/// - `volume`: The rest volume
+#[derive(Debug, Clone)]
+pub struct TetRest {
+    pub dm_inv: Matrix3<f64>,
+    pub volume: f64,
+}
+
+impl TetRest {
+    /// Computes the rest shape from rest vertex positions
+    ///
+    /// # Panics
+    /// If the tetrahedron is degenerate
+    pub fn new(vertices: &[Vector3<f64>; 4]) -> Self {
+        let dm = Matrix3::from_columns(&[
+            vertices[1] - vertices[0],
+            vertices[2] - vertices[0],
+            vertices[3] - vertices[0],
+        ]);
+        let det = dm.determinant();
+        if det == 0.0 {
+            panic!("Degenerate rest tetrahedron!");
+        }
+
+        Self {
+            dm_inv: dm.try_inverse().unwrap(),
+            volume: det.abs() / 6.0,
+        }
+    }
+}
+
+/// Deformation gradient `F = Ds * Dm^-1` of a linear element with `D + 1` vertices
+fn deformation_gradient<const N: usize, const D: usize>(
+    variables: &advec<N, N>,
+    dm_inv: &na::SMatrix<f64, D, D>,
+) -> admat<N, D, D> {
+    let ds = admat::<N, D, D>::from_fn(|i, j| &variables[(j + 1) * D + i] - &variables[i]);
+    ds * val::valmat(*dm_inv)
+}
+
+impl<M: Hyperelastic> Objective<6> for M {
+    type EvalArgs = TriangleRest;
+
+    fn eval(&self, variables: &advec<6, 6>, rest: &TriangleRest) -> Ad<6> {
+        let f = deformation_gradient::<6, 2>(variables, &rest.dm_inv);
+        self.density(&f).mul_value(rest.area)
+    }
+}
+
+impl<M: Hyperelastic> Objective<12> for M {
+    type EvalArgs = TetRest;
+
+    fn eval(&self, variables: &advec<12, 12>, rest: &TetRest) -> Ad<12> {
+        let f = deformation_gradient::<12, 3>(variables, &rest.dm_inv);
+        self.density(&f).mul_value(rest.volume)
+    }
+}
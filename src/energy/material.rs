@@ -0,0 +1,172 @@
+use crate::{
+    energy::element::Hyperelastic,
+    linalg::{determinant, inverse, polar},
+    make::val,
+    types::admat,
+    Ad,
+};
+use na::SMatrix;
+
+/// Lamé parameters `(μ, λ)` from Young's modulus and Poisson's ratio in `(-1, 0.5)`
+fn lame(young: f64, poisson: f64) -> (f64, f64) {
+    let mu = young / (2.0 * (1.0 + poisson));
+    let lambda = young * poisson / ((1.0 + poisson) * (1.0 - 2.0 * poisson));
+    (mu, lambda)
+}
+
+/// `(A + Aᵀ) / 2 - I`
+fn symmetric_strain<const N: usize, const D: usize>(a: &admat<N, D, D>) -> admat<N, D, D> {
+    let sym = (a + a.transpose()).map(|x| x.mul_value(0.5));
+    sym - val::valmat(SMatrix::<f64, D, D>::identity())
+}
+
+/// `μ ε:ε + λ/2 tr(ε)^2`
+fn strain_energy<const N: usize, const D: usize>(
+    mu: f64,
+    lambda: f64,
+    eps: &admat<N, D, D>,
+) -> Ad<N> {
+    eps.norm_squared().mul_value(mu) + eps.trace().powi(2).mul_value(0.5 * lambda)
+}
+
+/// Linear elasticity, `ψ = μ ε:ε + λ/2 tr(ε)^2` with the small strain `ε = (F + Fᵀ) / 2 - I`.
+/// Not invariant to rotations, only valid for small deformations.
+#[derive(Debug, Clone)]
+pub struct LinearElasticity {
+    pub mu: f64,
+    pub lambda: f64,
+}
+
+impl LinearElasticity {
+    /// Creates the material from Young's modulus and Poisson's ratio
+    pub fn from_young_poisson(young: f64, poisson: f64) -> Self {
+        let (mu, lambda) = lame(young, poisson);
+        Self { mu, lambda }
+    }
+}
+
+impl Hyperelastic for LinearElasticity {
+    fn density<const N: usize, const D: usize>(&self, f: &admat<N, D, D>) -> Ad<N> {
+        strain_energy(self.mu, self.lambda, &symmetric_strain(f))
+    }
+}
+
+/// Corotated linear elasticity, `ψ = μ |F - R|^2 + λ/2 (J - 1)^2`
+/// with the rotation `R` of the polar decomposition `F = R S` and `J = det(F)`.
+///
+/// # Panics
+/// If the rank of `F` is below `D - 1`, see [`polar`]
+#[derive(Debug, Clone)]
+pub struct Corotated {
+    pub mu: f64,
+    pub lambda: f64,
+}
+
+impl Corotated {
+    /// Creates the material from Young's modulus and Poisson's ratio
+    pub fn from_young_poisson(young: f64, poisson: f64) -> Self {
+        let (mu, lambda) = lame(young, poisson);
+        Self { mu, lambda }
+    }
+}
+
+impl Hyperelastic for Corotated {
+    fn density<const N: usize, const D: usize>(&self, f: &admat<N, D, D>) -> Ad<N> {
+        let r = polar(f).r;
+        let j = determinant(f);
+
+        (f - r).norm_squared().mul_value(self.mu)
+            + (j - val::scalar(1.0)).powi(2).mul_value(0.5 * self.lambda)
+    }
+}
+
+/// St. Venant-Kirchhoff, `ψ = μ E:E + λ/2 tr(E)^2` with the Green strain `E = (FᵀF - I) / 2`.
+/// Softens and collapses under strong compression.
+#[derive(Debug, Clone)]
+pub struct StVenantKirchhoff {
+    pub mu: f64,
+    pub lambda: f64,
+}
+
+impl StVenantKirchhoff {
+    /// Creates the material from Young's modulus and Poisson's ratio
+    pub fn from_young_poisson(young: f64, poisson: f64) -> Self {
+        let (mu, lambda) = lame(young, poisson);
+        Self { mu, lambda }
+    }
+}
+
+impl Hyperelastic for StVenantKirchhoff {
+    fn density<const N: usize, const D: usize>(&self, f: &admat<N, D, D>) -> Ad<N> {
+        strain_energy(
+            self.mu,
+            self.lambda,
+            &symmetric_strain(&(f.transpose() * f)),
+        )
+    }
+}
+
+/// Stable Neo-Hookean (Smith et al. 2018), `ψ = μ/2 (tr(FᵀF) - D) + λ/2 (J - α)^2`
+/// with `α = 1 + μ / λ`, shifted to vanish at rest.
+/// Defined for inverted elements too, unlike `log(J)` based Neo-Hookean models.
+/// It is evaluated in the expanded form `μ/2 (tr(FᵀF) - D) - μ (J - 1) + λ/2 (J - 1)^2`,
+/// which stays finite for `λ = 0`, i.e. a Poisson's ratio of zero.
+///
+/// Note that the paper maps Lamé parameters to its own `μ, λ` to match linear
+/// elasticity; [`Self::from_young_poisson`] uses the Lamé parameters directly.
+#[derive(Debug, Clone)]
+pub struct StableNeoHookean {
+    pub mu: f64,
+    pub lambda: f64,
+}
+
+impl StableNeoHookean {
+    /// Creates the material from Young's modulus and Poisson's ratio
+    pub fn from_young_poisson(young: f64, poisson: f64) -> Self {
+        let (mu, lambda) = lame(young, poisson);
+        Self { mu, lambda }
+    }
+}
+
+impl Hyperelastic for StableNeoHookean {
+    fn density<const N: usize, const D: usize>(&self, f: &admat<N, D, D>) -> Ad<N> {
+        let j_minus_one = determinant(f) - val::scalar(1.0);
+
+        (f.norm_squared() - val::scalar(D as f64)).mul_value(0.5 * self.mu)
+            - j_minus_one.mul_value(self.mu)
+            + j_minus_one.powi(2).mul_value(0.5 * self.lambda)
+    }
+}
+
+/// Symmetric Dirichlet distortion, `ψ = |F|^2 + |F^-1|^2 - 2D`, vanishing at isometries.
+/// Infinite for degenerate elements, which keeps parameterizations free of flips.
+///
+/// # Panics
+/// If `F` is singular
+#[derive(Debug, Clone, Default)]
+pub struct SymmetricDirichlet;
+
+impl Hyperelastic for SymmetricDirichlet {
+    fn density<const N: usize, const D: usize>(&self, f: &admat<N, D, D>) -> Ad<N> {
+        let Some(f_inv) = inverse(f) else {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        };
+
+        f.norm_squared() + f_inv.norm_squared() - val::scalar(2.0 * D as f64)
+    }
+}
+
+/// As-rigid-as-possible distortion, `ψ = |F - R|^2` with the rotation `R` of the polar
+/// decomposition `F = R S`.
+///
+/// # Panics
+/// If the rank of `F` is below `D - 1`, see [`polar`]
+#[derive(Debug, Clone, Default)]
+pub struct Arap;
+
+impl Hyperelastic for Arap {
+    fn density<const N: usize, const D: usize>(&self, f: &admat<N, D, D>) -> Ad<N> {
+        (f - polar(f).r).norm_squared()
+    }
+}
@@ -0,0 +1,15 @@
+/// Finite element energies of triangles and tetrahedra, from a hyperelastic energy density.
+pub mod element;
+/// Energy densities of elastic materials and of parameterization distortions.
+pub mod material;
+/// Discrete shell bending energy on hinges.
+pub mod shell;
+/// Mass-spring energy.
+pub mod spring;
+
+pub use element::{Hyperelastic, TetRest, TriangleRest};
+pub use material::{
+    Arap, Corotated, LinearElasticity, StVenantKirchhoff, StableNeoHookean, SymmetricDirichlet,
+};
+pub use shell::{DiscreteShell, HingeRest};
+pub use spring::MassSpring;
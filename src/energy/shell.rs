@@ -0,0 +1,72 @@
+use crate::{make::val, sparse::objective::Objective, types::advec, Ad};
+use na::Vector3;
+
+/// Discrete shell bending (Grinspun et al. 2003) on hinges of two triangles
+///
+/// The variables are the 3D positions of the hinge `[x0, x1, x2, x3]`: the shared edge
+/// `x0 x1`, and the opposite vertices `x2` and `x3` of the two triangles.
+/// The energy is `k * w * (θ - θ0)^2` with the dihedral angle `θ`, which is `0` for a
+/// flat hinge, and the rest data `θ0`, `w` passed as `EvalArgs`, one [`HingeRest`] per hinge.
+///
+/// # Panics
+/// If a triangle of the hinge is degenerate
+#[derive(Debug, Clone)]
+pub struct DiscreteShell {
+    pub stiffness: f64,
+}
+
+/// Rest data of a hinge
+///
+/// ## Fields
+/// - `angle`: The rest dihedral angle `θ0`
+/// - `weight`: The weight `w = 3 |e|^2 / (A0 + A1)` from the rest edge length and triangle areas
+#[derive(Debug, Clone)]
+pub struct HingeRest {
+    pub angle: f64,
+    pub weight: f64,
+}
+
+impl HingeRest {
+    /// Computes the rest data from rest vertex positions `[x0, x1, x2, x3]`
+    ///
+    /// # Panics
+    /// If a triangle of the hinge is degenerate
+    pub fn new(vertices: &[Vector3<f64>; 4]) -> Self {
+        let [x0, x1, x2, x3] = vertices;
+        let e = x1 - x0;
+        let n0 = e.cross(&(x2 - x0));
+        let n1 = (x3 - x0).cross(&e);
+
+        let area_sum = 0.5 * (n0.norm() + n1.norm());
+        if area_sum == 0.0 || n0.norm() == 0.0 || n1.norm() == 0.0 {
+            panic!("Degenerate rest hinge!");
+        }
+
+        Self {
+            angle: n0.cross(&n1).dot(&e.normalize()).atan2(n0.dot(&n1)),
+            weight: 3.0 * e.norm_squared() / area_sum,
+        }
+    }
+}
+
+impl Objective<12> for DiscreteShell {
+    type EvalArgs = HingeRest;
+
+    fn eval(&self, variables: &advec<12, 12>, rest: &HingeRest) -> Ad<12> {
+        let vertex = |i: usize| advec::<12, 3>::from_fn(|r, _| variables[3 * i + r].clone());
+        let (x0, x1, x2, x3) = (vertex(0), vertex(1), vertex(2), vertex(3));
+
+        let e = &x1 - &x0;
+        let n0 = e.cross(&(&x2 - &x0));
+        let n1 = (&x3 - &x0).cross(&e);
+
+        // Unnormalized normals: atan2 only depends on the ratio
+        let sin = n0.cross(&n1).dot(&e) / e.norm();
+        let cos = n0.dot(&n1);
+        let angle = sin.atan2(&cos);
+
+        (angle - val::scalar(rest.angle))
+            .powi(2)
+            .mul_value(self.stiffness * rest.weight)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{make::val, sparse::objective::Objective, types::advec, Ad};
+
+/// Hooke's law springs between two vertices, in 2D (`N = 4`) or 3D (`N = 6`)
+///
+/// The energy is `k / 2 * (l - l0)^2` for current length `l` and rest length `l0`,
+/// which is passed as `EvalArgs`, shared or one per spring to the `_elementwise` methods.
+///
+/// ## Example
+/// ```ignore
+/// let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+/// let energy = MassSpring { stiffness: 1e3 };
+/// let computed = energy.compute(&x, &springs, &1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MassSpring {
+    pub stiffness: f64,
+}
+
+impl MassSpring {
+    fn energy<const N: usize, const D: usize>(
+        &self,
+        variables: &advec<N, N>,
+        restlen: f64,
+    ) -> Ad<N> {
+        let delta = advec::<N, D>::from_fn(|i, _| &variables[D + i] - &variables[i]);
+        let len = delta.norm();
+
+        val::scalar(0.5 * self.stiffness) * (len - val::scalar(restlen)).powi(2)
+    }
+}
+
+impl Objective<4> for MassSpring {
+    type EvalArgs = f64;
+
+    fn eval(&self, variables: &advec<4, 4>, restlen: &f64) -> Ad<4> {
+        self.energy::<4, 2>(variables, *restlen)
+    }
+}
+
+impl Objective<6> for MassSpring {
+    type EvalArgs = f64;
+
+    fn eval(&self, variables: &advec<6, 6>, restlen: &f64) -> Ad<6> {
+        self.energy::<6, 3>(variables, *restlen)
+    }
+}
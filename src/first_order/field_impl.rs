@@ -0,0 +1,472 @@
+#![allow(unused)]
+
+use crate::AdGrad;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use na::{ComplexField, Field, RealField, SimdValue};
+use num_traits::FromPrimitive;
+use simba::scalar::SubsetOf;
+use std::f64::consts::{self, LN_2};
+
+// ################################################
+// ################# Value-based ##################
+// ################################################
+
+// Comparisons, constants and conversions only look at (or produce) values;
+// derivatives are either ignored or zero.
+
+impl<const N: usize> AbsDiffEq for AdGrad<N> {
+    type Epsilon = Self;
+
+    fn default_epsilon() -> Self::Epsilon {
+        AdGrad::inactive_scalar(f64::default_epsilon())
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon.value)
+    }
+}
+
+impl<const N: usize> UlpsEq for AdGrad<N> {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon.value, max_ulps)
+    }
+}
+
+impl<const N: usize> RelativeEq for AdGrad<N> {
+    fn default_max_relative() -> Self::Epsilon {
+        AdGrad::inactive_scalar(f64::default_max_relative())
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.value
+            .relative_eq(&other.value, epsilon.value, max_relative.value)
+    }
+}
+
+impl<const N: usize> Field for AdGrad<N> {}
+
+impl<const N: usize> SimdValue for AdGrad<N> {
+    const LANES: usize = 1;
+
+    type Element = Self;
+
+    type SimdBool = bool;
+
+    fn splat(val: Self::Element) -> Self {
+        val
+    }
+
+    fn extract(&self, i: usize) -> Self::Element {
+        self.clone()
+    }
+
+    unsafe fn extract_unchecked(&self, i: usize) -> Self::Element {
+        self.clone()
+    }
+
+    fn replace(&mut self, i: usize, val: Self::Element) {
+        *self = val
+    }
+
+    unsafe fn replace_unchecked(&mut self, i: usize, val: Self::Element) {
+        *self = val
+    }
+
+    fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<const N: usize> FromPrimitive for AdGrad<N> {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(AdGrad::inactive_scalar(n as f64))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(AdGrad::inactive_scalar(n as f64))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(AdGrad::inactive_scalar(n))
+    }
+}
+
+impl<const N: usize> SubsetOf<AdGrad<N>> for AdGrad<N> {
+    fn to_superset(&self) -> AdGrad<N> {
+        self.clone()
+    }
+
+    fn from_superset_unchecked(element: &AdGrad<N>) -> Self {
+        element.clone()
+    }
+
+    fn is_in_subset(element: &AdGrad<N>) -> bool {
+        true
+    }
+}
+
+impl<const N: usize> SubsetOf<AdGrad<N>> for f64 {
+    fn to_superset(&self) -> AdGrad<N> {
+        AdGrad::inactive_scalar(*self)
+    }
+
+    fn from_superset_unchecked(element: &AdGrad<N>) -> Self {
+        element.value
+    }
+
+    fn is_in_subset(element: &AdGrad<N>) -> bool {
+        element.grad.iter().all(|&x| x == 0.0)
+    }
+}
+
+impl<const N: usize> SubsetOf<AdGrad<N>> for f32 {
+    fn to_superset(&self) -> AdGrad<N> {
+        AdGrad::inactive_scalar(*self as f64)
+    }
+
+    fn from_superset_unchecked(element: &AdGrad<N>) -> Self {
+        element.value as f32
+    }
+
+    fn is_in_subset(element: &AdGrad<N>) -> bool {
+        element.grad.iter().all(|&x| x == 0.0)
+    }
+}
+
+impl<const N: usize> RealField for AdGrad<N> {
+    fn is_sign_positive(&self) -> bool {
+        self.value.is_sign_positive()
+    }
+
+    fn is_sign_negative(&self) -> bool {
+        self.value.is_sign_negative()
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        if self.value.is_sign_negative() == sign.value.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        AdGrad::max(&self, &other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        AdGrad::min(&self, &other)
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        AdGrad::clamp(&self, &min, &max)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        AdGrad::atan2(&self, &other)
+    }
+
+    fn min_value() -> Option<Self> {
+        Some(AdGrad::inactive_scalar(f64::MIN))
+    }
+
+    fn max_value() -> Option<Self> {
+        Some(AdGrad::inactive_scalar(f64::MAX))
+    }
+
+    fn pi() -> Self {
+        AdGrad::inactive_scalar(consts::PI)
+    }
+
+    fn two_pi() -> Self {
+        AdGrad::inactive_scalar(consts::TAU)
+    }
+
+    fn frac_pi_2() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_PI_2)
+    }
+
+    fn frac_pi_3() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_PI_3)
+    }
+
+    fn frac_pi_4() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_PI_4)
+    }
+
+    fn frac_pi_6() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_PI_6)
+    }
+
+    fn frac_pi_8() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_PI_8)
+    }
+
+    fn frac_1_pi() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_1_PI)
+    }
+
+    fn frac_2_pi() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_2_PI)
+    }
+
+    fn frac_2_sqrt_pi() -> Self {
+        AdGrad::inactive_scalar(consts::FRAC_2_SQRT_PI)
+    }
+
+    fn e() -> Self {
+        AdGrad::inactive_scalar(consts::E)
+    }
+
+    fn log2_e() -> Self {
+        AdGrad::inactive_scalar(consts::LOG2_E)
+    }
+
+    fn log10_e() -> Self {
+        AdGrad::inactive_scalar(consts::LOG10_E)
+    }
+
+    fn ln_2() -> Self {
+        AdGrad::inactive_scalar(consts::LN_2)
+    }
+
+    fn ln_10() -> Self {
+        AdGrad::inactive_scalar(consts::LN_10)
+    }
+}
+
+// ################################################
+// ################### Examined ###################
+// ################################################
+
+impl<const N: usize> ComplexField for AdGrad<N> {
+    type RealField = AdGrad<N>;
+
+    #[doc = r" Builds a pure-real complex number from the given value."]
+    fn from_real(re: Self::RealField) -> Self {
+        re
+    }
+
+    #[doc = r" The real part of this complex number."]
+    fn real(self) -> Self::RealField {
+        self
+    }
+
+    #[doc = r" The imaginary part of this complex number."]
+    fn imaginary(self) -> Self::RealField {
+        unimplemented!("This is a real type");
+    }
+
+    #[doc = r" The modulus of this complex number."]
+    fn modulus(self) -> Self::RealField {
+        AdGrad::abs(&self)
+    }
+
+    #[doc = r" The squared modulus of this complex number."]
+    fn modulus_squared(self) -> Self::RealField {
+        AdGrad::square(&self)
+    }
+
+    #[doc = r" The argument of this complex number."]
+    /// This should be zero with no grad w.r.t. self, but the use of this method is itself a bug.
+    fn argument(self) -> Self::RealField {
+        unimplemented!("This should not be used");
+    }
+
+    #[doc = r" The sum of the absolute value of this complex number's real and imaginary part."]
+    fn norm1(self) -> Self::RealField {
+        AdGrad::abs(&self)
+    }
+
+    #[doc = r" Multiplies this complex number by `factor`."]
+    fn scale(self, factor: Self::RealField) -> Self {
+        factor * self
+    }
+
+    #[doc = r" Divides this complex number by `factor`."]
+    fn unscale(self, factor: Self::RealField) -> Self {
+        self / factor
+    }
+
+    fn floor(self) -> Self {
+        unimplemented!("Floor is not differentiable!");
+    }
+
+    fn ceil(self) -> Self {
+        unimplemented!("Ceil is not differentiable!");
+    }
+
+    fn round(self) -> Self {
+        unimplemented!("Round is not differentiable!");
+    }
+
+    fn trunc(self) -> Self {
+        unimplemented!("Trunc is not differentiable!");
+    }
+
+    fn fract(self) -> Self {
+        unimplemented!("Fract is not differentiable!");
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        a * self + b
+    }
+
+    #[doc = r" The absolute value of this complex number: `self / self.signum()`."]
+    #[doc = r""]
+    #[doc = r" This is equivalent to `self.modulus()`."]
+    fn abs(self) -> Self::RealField {
+        AdGrad::abs(&self)
+    }
+
+    #[doc = r" Computes (self.conjugate() * self + other.conjugate() * other).sqrt()"]
+    fn hypot(self, other: Self) -> Self::RealField {
+        AdGrad::hypot(&self, &other)
+    }
+
+    fn recip(self) -> Self {
+        AdGrad::recip(&self)
+    }
+
+    /// Real number has itself as conjugate
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn sin(self) -> Self {
+        AdGrad::sin(&self)
+    }
+
+    fn cos(self) -> Self {
+        AdGrad::cos(&self)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (AdGrad::sin(&self), AdGrad::cos(&self))
+    }
+
+    fn tan(self) -> Self {
+        AdGrad::tan(&self)
+    }
+
+    fn asin(self) -> Self {
+        AdGrad::asin(&self)
+    }
+
+    fn acos(self) -> Self {
+        AdGrad::acos(&self)
+    }
+
+    #[allow(deprecated)]
+    fn atan(self) -> Self {
+        AdGrad::atan(&self)
+    }
+
+    fn sinh(self) -> Self {
+        AdGrad::sinh(&self)
+    }
+
+    fn cosh(self) -> Self {
+        AdGrad::cosh(&self)
+    }
+
+    fn tanh(self) -> Self {
+        AdGrad::tanh(&self)
+    }
+
+    fn asinh(self) -> Self {
+        AdGrad::asinh(&self)
+    }
+
+    fn acosh(self) -> Self {
+        AdGrad::acosh(&self)
+    }
+
+    fn atanh(self) -> Self {
+        AdGrad::atanh(&self)
+    }
+
+    fn log(self, base: Self::RealField) -> Self {
+        AdGrad::log_base(&self, &base)
+    }
+
+    fn log2(self) -> Self {
+        AdGrad::log2(&self)
+    }
+
+    fn log10(self) -> Self {
+        AdGrad::log10(&self)
+    }
+
+    fn ln(self) -> Self {
+        AdGrad::ln(&self)
+    }
+
+    fn ln_1p(self) -> Self {
+        AdGrad::ln(&self.add_value(1.0))
+    }
+
+    fn sqrt(self) -> Self {
+        AdGrad::sqrt(&self)
+    }
+
+    fn exp(self) -> Self {
+        AdGrad::exp(&self)
+    }
+
+    fn exp2(self) -> Self {
+        let exp_val = self.value.exp2();
+
+        Self::chain(exp_val, exp_val * LN_2, &self)
+    }
+
+    fn exp_m1(self) -> Self {
+        AdGrad::exp(&self).sub_value(1.0)
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        AdGrad::powi(&self, exponent)
+    }
+
+    fn powf(self, n: Self::RealField) -> Self {
+        AdGrad::pow(&self, &n)
+    }
+
+    /// This is a real type, so the "complex" power is `powf`
+    fn powc(self, n: Self) -> Self {
+        AdGrad::pow(&self, &n)
+    }
+
+    fn cbrt(self) -> Self {
+        let f = self.value.cbrt();
+
+        let d = 1.0 / (3.0 * f * f);
+
+        Self::chain(f, d, &self)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.value.is_finite() && self.grad.iter().all(|x| x.is_finite())
+    }
+
+    fn try_sqrt(self) -> Option<Self> {
+        if self.value < -0.0 {
+            None
+        } else {
+            Some(AdGrad::sqrt(&self))
+        }
+    }
+}
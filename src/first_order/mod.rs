@@ -0,0 +1,238 @@
+/// Implementations of operators on `&AdGrad<N>`
+pub mod borrow_operator_traits_impl;
+/// Implementations of nalgebra field traits on `AdGrad<N>`.
+pub mod field_impl;
+pub mod num_traits_impl;
+pub mod operator_traits_impl;
+pub mod scalar_matrix_mul;
+
+use crate::types::vec;
+use na::SVector;
+
+// ################################### Data Structure ###################################
+
+/// Automatic differentiation value tracking first derivatives only
+///
+/// A cheaper companion of [`Ad<N>`](crate::Ad) for objectives that only need gradients
+/// (line search, gradient descent, quasi-Newton methods): every operation costs O(N)
+/// instead of the O(N²) Hessian update.
+///
+/// # Value getters:
+/// - `value() -> f64`: Returns the current numerical value
+/// - `grad() -> SVector<f64, N>`: Returns the gradient vector
+///
+/// # Type Parameters
+/// * `N` - The dimension of the input space (number of variables)
+///
+/// # Fields (private)
+/// * `value` - The current value of the function
+/// * `grad` - The gradient (first derivatives) as a vector
+#[derive(Debug, Clone)]
+pub struct AdGrad<const N: usize> {
+    pub(crate) value: f64,
+    pub(crate) grad: vec<N>,
+}
+
+// ################################### Accessors ###################################
+
+impl<const N: usize> AdGrad<N> {
+    /// Returns the current value of the AD variable
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the gradient (first derivatives) of the AD variable
+    ///
+    /// # Returns
+    /// The gradient (A vector containing the partial derivatives with respect to each input variable).
+    pub fn grad(&self) -> vec<N> {
+        self.grad
+    }
+}
+
+// ################################### Public Constructors ###################################
+
+impl AdGrad<1> {
+    /// Creates an AD scalar with explicitly specified value and gradient
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    /// * `grad` - The gradient (first derivative)
+    ///
+    /// # Returns
+    /// A new AdGrad<1> instance with the specified properties
+    pub fn given_scalar(value: f64, grad: f64) -> Self {
+        Self {
+            value,
+            grad: vec::from_row_slice(&[grad]),
+        }
+    }
+
+    /// Creates an active scalar AD value with unit gradient
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    ///
+    /// # Returns
+    /// A new AdGrad<1> instance that is active (gradient = 1.0)
+    pub fn active_scalar(value: f64) -> Self {
+        let mut res = Self::_zeroed();
+
+        res.value = value;
+        res.grad[0] = 1.0;
+
+        res
+    }
+}
+
+impl<const N: usize> AdGrad<N> {
+    /// Creates an inactive scalar AD value with zero gradient
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    ///
+    /// # Returns
+    /// A new `AdGrad<N>` instance that is inactive (gradient = 0)
+    pub fn inactive_scalar(value: f64) -> Self {
+        let mut res = Self::_zeroed();
+        res.value = value;
+        res
+    }
+
+    /// Creates a vector of inactive AD values from a vector of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Input vector of numerical values
+    ///
+    /// # Type Parameters
+    /// * `L` - Length of the output vector
+    ///
+    /// # Returns
+    /// A vector of inactive AD values
+    pub fn inactive_vector<const L: usize>(values: &SVector<f64, L>) -> SVector<Self, L> {
+        SVector::from_fn(|i, _| Self::inactive_scalar(values[i]))
+    }
+
+    /// Creates a vector of inactive AD values from a slice of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    ///
+    /// # Type Parameters
+    /// * `L` - Length of the output vector
+    ///
+    /// # Returns
+    /// A vector of inactive AD values
+    ///
+    /// # Panics
+    /// If the slice length doesn't match the vector length L
+    pub fn inactive_from_slice<const L: usize>(values: &[f64]) -> SVector<Self, L> {
+        assert_eq!(
+            values.len(),
+            L,
+            "Slice length mismatch: expected {}, got {}",
+            L,
+            values.len()
+        );
+        Self::inactive_vector(&SVector::from_column_slice(values))
+    }
+
+    /// Creates an AD value with explicitly specified value and gradient
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    /// * `grad` - The gradient vector
+    ///
+    /// # Returns
+    /// A new `AdGrad<N>` instance with the specified properties
+    pub fn given_vector(value: f64, grad: &vec<N>) -> Self {
+        Self { value, grad: *grad }
+    }
+
+    /// Creates a vector of active AD values from a vector of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Input vector of numerical values
+    ///
+    /// # Returns
+    /// A vector of active AD values where each element has unit gradient
+    /// in its corresponding dimension
+    pub fn active_vector(vector: &SVector<f64, N>) -> SVector<Self, N> {
+        SVector::from_fn(|i, _| Self::_active_scalar_with_index(vector[i], i))
+    }
+
+    /// Creates a vector of active AD values from a slice of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    ///
+    /// # Returns
+    /// A vector of active AD values
+    ///
+    /// # Panics
+    /// If the slice length doesn't match the input dimension N
+    pub fn active_from_slice(values: &[f64]) -> SVector<Self, N> {
+        assert_eq!(
+            values.len(),
+            N,
+            "Slice length mismatch: expected {}, got {}",
+            N,
+            values.len()
+        );
+        Self::active_vector(&SVector::from_column_slice(values))
+    }
+}
+
+// ################################### Private Constructors ###################################
+
+impl<const N: usize> AdGrad<N> {
+    fn _active_scalar_with_index(value: f64, index: usize) -> Self {
+        let mut res = Self::_zeroed();
+
+        res.value = value;
+        res.grad[index] = 1.0;
+
+        res
+    }
+
+    fn _zeroed() -> Self {
+        Self {
+            value: 0.0,
+            grad: vec::zeros(),
+        }
+    }
+}
+
+// ################################### Utils ###################################
+
+impl<const N: usize> AdGrad<N> {
+    fn chain(
+        value: f64, // f
+        d: f64,     // df/da
+        a: &Self,
+    ) -> Self {
+        Self {
+            value,
+            grad: d * a.grad,
+        }
+    }
+
+    /// Binary counterpart of `chain`
+    fn chain2(
+        value: f64, // f
+        da: f64,    // df/da
+        db: f64,    // df/db
+        a: &Self,
+        b: &Self,
+    ) -> Self {
+        Self {
+            value,
+            grad: da * a.grad + db * b.grad,
+        }
+    }
+
+    /// Whether this value has a zero gradient, i.e. is a constant
+    fn is_constant(&self) -> bool {
+        self.grad.iter().all(|&g| g == 0.0)
+    }
+}
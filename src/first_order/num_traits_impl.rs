@@ -0,0 +1,56 @@
+#![allow(unused)]
+use crate::AdGrad;
+use num_traits::{Num, One, Signed, Zero};
+use std::fmt::Display;
+
+impl<const N: usize> Display for AdGrad<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AdGrad[{}]", self.value)
+    }
+}
+
+impl<const N: usize> Zero for AdGrad<N> {
+    fn zero() -> Self {
+        AdGrad::_zeroed()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.abs() == 0.0 && self.grad.iter().all(|&x| x.abs() == 0.0)
+    }
+}
+
+impl<const N: usize> One for AdGrad<N> {
+    fn one() -> Self {
+        AdGrad::inactive_scalar(1.0)
+    }
+}
+
+impl<const N: usize> Num for AdGrad<N> {
+    type FromStrRadixErr = ();
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        unimplemented!()
+    }
+}
+
+impl<const N: usize> Signed for AdGrad<N> {
+    fn abs(&self) -> Self {
+        AdGrad::abs(self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        unimplemented!()
+    }
+
+    fn signum(&self) -> Self {
+        unimplemented!()
+    }
+
+    fn is_positive(&self) -> bool {
+        self.value > 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.value < -0.0
+    }
+}
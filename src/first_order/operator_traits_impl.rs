@@ -0,0 +1,375 @@
+/*
+
+This code is generated by meta/operators.py at 10:57:00 @ 2026.10.18
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdGrad;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+impl<const N: usize> Neg for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn neg(self) -> AdGrad<N> {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = -self.value;
+        res.grad = -self.grad;
+
+        res
+    }
+}
+
+impl<const N: usize> Neg for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn neg(self) -> AdGrad<N> {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = -self.value;
+        res.grad = -self.grad;
+
+        res
+    }
+}
+
+// &T + &T
+impl<const N: usize> Add<&AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn add(self, rhs: &AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+
+        res
+    }
+}
+
+// &T + T
+impl<const N: usize> Add<AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn add(self, rhs: AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+
+        res
+    }
+}
+
+// T + &T
+impl<const N: usize> Add<&AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn add(self, rhs: &AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+
+        res
+    }
+}
+
+// T + T
+impl<const N: usize> Add<AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn add(self, rhs: AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+
+        res
+    }
+}
+
+// &T - &T
+impl<const N: usize> Sub<&AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn sub(self, rhs: &AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+
+        res
+    }
+}
+
+// &T - T
+impl<const N: usize> Sub<AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn sub(self, rhs: AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+
+        res
+    }
+}
+
+// T - &T
+impl<const N: usize> Sub<&AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn sub(self, rhs: &AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+
+        res
+    }
+}
+
+// T - T
+impl<const N: usize> Sub<AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn sub(self, rhs: AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+
+        res
+    }
+}
+
+// &T * &T
+impl<const N: usize> Mul<&AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn mul(self, rhs: &AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+
+        res.value = self.value * rhs.value;
+        res.grad = self.grad * rhs.value + self.value * rhs.grad;
+
+        res
+    }
+}
+
+// &T * T
+impl<const N: usize> Mul<AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn mul(self, rhs: AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+
+        res.value = self.value * rhs.value;
+        res.grad = self.grad * rhs.value + self.value * rhs.grad;
+
+        res
+    }
+}
+
+// T * &T
+impl<const N: usize> Mul<&AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn mul(self, rhs: &AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+
+        res.value = self.value * rhs.value;
+        res.grad = self.grad * rhs.value + self.value * rhs.grad;
+
+        res
+    }
+}
+
+// T * T
+impl<const N: usize> Mul<AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn mul(self, rhs: AdGrad<N>) -> Self::Output {
+        let mut res = AdGrad::<N>::_zeroed();
+
+        res.value = self.value * rhs.value;
+        res.grad = self.grad * rhs.value + self.value * rhs.grad;
+
+        res
+    }
+}
+
+// &T / &T
+impl<const N: usize> Div<&AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn div(self, rhs: &AdGrad<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value / rhs.value;
+        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+
+        res
+    }
+}
+
+// &T / T
+impl<const N: usize> Div<AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn div(self, rhs: AdGrad<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value / rhs.value;
+        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+
+        res
+    }
+}
+
+// T / &T
+impl<const N: usize> Div<&AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn div(self, rhs: &AdGrad<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value / rhs.value;
+        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+
+        res
+    }
+}
+
+// T / T
+impl<const N: usize> Div<AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn div(self, rhs: AdGrad<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let mut res = AdGrad::<N>::_zeroed();
+        res.value = self.value / rhs.value;
+        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+
+        res
+    }
+}
+
+// &T % &T
+impl<const N: usize> Rem<&AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn rem(self, rhs: &AdGrad<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// &T % T
+impl<const N: usize> Rem<AdGrad<N>> for &AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn rem(self, rhs: AdGrad<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % &T
+impl<const N: usize> Rem<&AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn rem(self, rhs: &AdGrad<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % T
+impl<const N: usize> Rem<AdGrad<N>> for AdGrad<N> {
+    type Output = AdGrad<N>;
+
+    fn rem(self, rhs: AdGrad<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T += &T
+impl<const N: usize> AddAssign<&AdGrad<N>> for AdGrad<N> {
+    fn add_assign(&mut self, rhs: &AdGrad<N>) {
+        *self = &*self + rhs;
+    }
+}
+
+// T += T
+impl<const N: usize> AddAssign<AdGrad<N>> for AdGrad<N> {
+    fn add_assign(&mut self, rhs: AdGrad<N>) {
+        *self = &*self + rhs;
+    }
+}
+
+// T -= &T
+impl<const N: usize> SubAssign<&AdGrad<N>> for AdGrad<N> {
+    fn sub_assign(&mut self, rhs: &AdGrad<N>) {
+        *self = &*self - rhs;
+    }
+}
+
+// T -= T
+impl<const N: usize> SubAssign<AdGrad<N>> for AdGrad<N> {
+    fn sub_assign(&mut self, rhs: AdGrad<N>) {
+        *self = &*self - rhs;
+    }
+}
+
+// T *= &T
+impl<const N: usize> MulAssign<&AdGrad<N>> for AdGrad<N> {
+    fn mul_assign(&mut self, rhs: &AdGrad<N>) {
+        *self = &*self * rhs;
+    }
+}
+
+// T *= T
+impl<const N: usize> MulAssign<AdGrad<N>> for AdGrad<N> {
+    fn mul_assign(&mut self, rhs: AdGrad<N>) {
+        *self = &*self * rhs;
+    }
+}
+
+// T /= &T
+impl<const N: usize> DivAssign<&AdGrad<N>> for AdGrad<N> {
+    fn div_assign(&mut self, rhs: &AdGrad<N>) {
+        *self = &*self / rhs;
+    }
+}
+
+// T /= T
+impl<const N: usize> DivAssign<AdGrad<N>> for AdGrad<N> {
+    fn div_assign(&mut self, rhs: AdGrad<N>) {
+        *self = &*self / rhs;
+    }
+}
+
+// T %= &T
+impl<const N: usize> RemAssign<&AdGrad<N>> for AdGrad<N> {
+    fn rem_assign(&mut self, rhs: &AdGrad<N>) {
+        unimplemented!();
+    }
+}
+
+// T %= T
+impl<const N: usize> RemAssign<AdGrad<N>> for AdGrad<N> {
+    fn rem_assign(&mut self, rhs: AdGrad<N>) {
+        unimplemented!();
+    }
+}
@@ -0,0 +1,41 @@
+/*
+
+This code is generated by meta/scalar_matrix_mul.py at 10:57:00 @ 2026.10.18
+Do not modify it directly.
+
+*/
+use crate::AdGrad;
+use na::SMatrix;
+use std::ops::Mul;
+
+impl<const N: usize, const R: usize, const C: usize> Mul<SMatrix<AdGrad<N>, R, C>> for AdGrad<N> {
+    type Output = SMatrix<AdGrad<N>, R, C>;
+
+    fn mul(self, rhs: SMatrix<AdGrad<N>, R, C>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const N: usize, const R: usize, const C: usize> Mul<SMatrix<AdGrad<N>, R, C>> for &AdGrad<N> {
+    type Output = SMatrix<AdGrad<N>, R, C>;
+
+    fn mul(self, rhs: SMatrix<AdGrad<N>, R, C>) -> Self::Output {
+        rhs * self.clone()
+    }
+}
+
+impl<const N: usize, const R: usize, const C: usize> Mul<&SMatrix<AdGrad<N>, R, C>> for AdGrad<N> {
+    type Output = SMatrix<AdGrad<N>, R, C>;
+
+    fn mul(self, rhs: &SMatrix<AdGrad<N>, R, C>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const N: usize, const R: usize, const C: usize> Mul<&SMatrix<AdGrad<N>, R, C>> for &AdGrad<N> {
+    type Output = SMatrix<AdGrad<N>, R, C>;
+
+    fn mul(self, rhs: &SMatrix<AdGrad<N>, R, C>) -> Self::Output {
+        rhs * self.clone()
+    }
+}
@@ -1,23 +1,56 @@
 #![doc = include_str!("../README.md")]
 extern crate nalgebra as na;
 
+/// Finite difference verification of derivatives.
+pub mod check;
+
 /// Comparison operations and utilities for AD values.
 pub mod compare;
 
+/// Heap-allocated AD values whose dimension is chosen at runtime.
+pub mod dynamic;
+
+/// Ready-made elastic energies as sparse objectives.
+pub mod energy;
+
+/// First-order AD values, tracking gradients without Hessians.
+pub mod first_order;
+
+/// Differentiable decompositions of small AD matrices.
+pub mod linalg;
+
 /// Factory functions for creating AD values and vectors.
 pub mod make;
 
+/// Mesh I/O and expansion of mesh connectivity into objective DOF indices.
+pub mod mesh;
+
 /// Miscellaneous utilities and experimental features.
 mod misc;
 
+/// Positive (semi-)definite projection of Hessians.
+pub mod projection;
+
+/// Reverse-mode AD on a tape, for gradients of scalars w.r.t. many variables.
+pub mod reverse;
+
 /// Scalar operations, operator traits, and field implementations.
 /// Please Note that all `unimplemented!` methods are not intended for use.
 /// If any operation encountered these, please raise an issue.
 pub mod scalar;
 
+/// Solvers minimizing sparse objectives.
+pub mod solve;
+
 /// Sparse matrix differentiation functionalities.
 pub mod sparse;
 
+/// AD values with sparse gradients and Hessians, for intermediates depending on few variables.
+pub mod sparse_ad;
+
+/// Third-order AD values, tracking third derivatives in addition to Hessians.
+pub mod third_order;
+
 /// Unit tests and validation code.
 #[cfg(test)]
 mod test;
@@ -29,7 +62,14 @@ pub mod types;
 // ################################### Implementation ###################################
 // ######################################################################################
 
-use na::{SMatrix, SVector};
+pub use dynamic::AdDyn;
+pub use first_order::AdGrad;
+pub use reverse::{AdRev, Tape};
+pub use sparse_ad::AdSparse;
+pub use third_order::AdThird;
+
+use na::{DMatrix, DVector, RealField, SMatrix, SVector};
+pub use types::Real;
 use types::{mat, vec};
 
 // ################################### Data Structure ###################################
@@ -37,29 +77,30 @@ use types::{mat, vec};
 /// Automatic differentiation value tracking first and second derivatives
 ///
 /// # Value getters:
-/// - `value() -> f64`: Returns the current numerical value
-/// - `grad() -> SVector<f64, N>`: Returns the gradient vector
-/// - `hess() -> SMatrix<f64, N, N>`: Returns the Hessian matrix
+/// - `value() -> T`: Returns the current numerical value
+/// - `grad() -> SVector<T, N>`: Returns the gradient vector
+/// - `hess() -> SMatrix<T, N, N>`: Returns the Hessian matrix
 ///
 /// # Type Parameters
 /// * `N` - The dimension of the input space (number of variables)
+/// * `T` - The underlying [`Real`] type, `f64` by default
 ///
 /// # Fields (private)
 /// * `value` - The current value of the function
 /// * `grad` - The gradient (first derivatives) as a vector
 /// * `hess` - The Hessian matrix (second derivatives)
 #[derive(Debug, Clone)]
-pub struct Ad<const N: usize> {
-    pub(crate) value: f64,
-    pub(crate) grad: vec<N>,
-    pub(crate) hess: mat<N>,
+pub struct Ad<const N: usize, T = f64> {
+    pub(crate) value: T,
+    pub(crate) grad: vec<N, T>,
+    pub(crate) hess: mat<N, T>,
 }
 
 // ################################### Accessors ###################################
 
-impl<const N: usize> Ad<N> {
+impl<const N: usize, T: Real> Ad<N, T> {
     /// Returns the current value of the AD variable
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> T {
         self.value
     }
 
@@ -67,16 +108,16 @@ impl<const N: usize> Ad<N> {
     ///
     /// # Returns
     /// The gradient (A vector containing the partial derivatives with respect to each input variable).
-    pub fn grad(&self) -> vec<N> {
-        self.grad.clone()
+    pub fn grad(&self) -> vec<N, T> {
+        self.grad
     }
 
     /// Returns the Hessian matrix (second derivatives) of the AD variable
     ///
     /// # Returns
     /// The [hessian](https://en.wikipedia.org/wiki/Hessian_matrix).
-    pub fn hess(&self) -> mat<N> {
-        self.hess.clone()
+    pub fn hess(&self) -> mat<N, T> {
+        self.hess
     }
 }
 
@@ -93,8 +134,10 @@ pub trait GetValue<const R: usize, const C: usize> {
     fn value(&self) -> Self::Value;
 }
 
-impl<const N: usize, const R: usize, const C: usize> GetValue<R, C> for SMatrix<Ad<N>, R, C> {
-    type Value = SMatrix<f64, R, C>;
+impl<const N: usize, const R: usize, const C: usize, T: Real> GetValue<R, C>
+    for SMatrix<Ad<N, T>, R, C>
+{
+    type Value = SMatrix<T, R, C>;
     fn value(&self) -> Self::Value {
         let mut val = Self::Value::zeros();
         for r in 0..R {
@@ -106,9 +149,262 @@ impl<const N: usize, const R: usize, const C: usize> GetValue<R, C> for SMatrix<
     }
 }
 
+impl<const N: usize, const R: usize, const C: usize> GetValue<R, C> for SMatrix<AdGrad<N>, R, C> {
+    type Value = SMatrix<f64, R, C>;
+    fn value(&self) -> Self::Value {
+        self.map(|x| x.value)
+    }
+}
+
+impl<const N: usize, const R: usize, const C: usize> GetValue<R, C> for SMatrix<AdThird<N>, R, C> {
+    type Value = SMatrix<f64, R, C>;
+    fn value(&self) -> Self::Value {
+        self.map(|x| x.value)
+    }
+}
+
+/// Marker used as both const parameters of [`GetValue`] for dynamically sized matrices
+pub const DYN: usize = usize::MAX;
+
+impl GetValue<DYN, DYN> for DMatrix<AdDyn> {
+    type Value = DMatrix<f64>;
+    fn value(&self) -> Self::Value {
+        self.map(|x| x.value)
+    }
+}
+
+impl GetValue<DYN, 1> for DVector<AdDyn> {
+    type Value = DVector<f64>;
+    fn value(&self) -> Self::Value {
+        self.map(|x| x.value)
+    }
+}
+
+/// Trait for extracting the Jacobian from AD vectors and matrices
+///
+/// Matrix entries are stacked in column-major order, the order of `as_slice()`,
+/// so the Jacobian of an `R x C` matrix has `L = R * C` rows. Stable Rust cannot
+/// compute `R * C` in a type, so matrices are supported up to `4 x 4`, while
+/// vectors of any length are.
+///
+/// # Type Parameters
+/// * `N` - The dimension of the input space (number of variables)
+/// * `L` - The number of entries
+pub trait GetGrad<const N: usize, const L: usize> {
+    /// The type of the extracted Jacobian
+    type Grad;
+
+    /// Extracts the Jacobian, whose `i`-th row is the gradient of the `i`-th component
+    fn grad(&self) -> Self::Grad;
+}
+
+/// Trait for extracting the Hessians of all entries of AD vectors and matrices,
+/// in the same order as [`GetGrad`]
+///
+/// # Type Parameters
+/// * `N` - The dimension of the input space (number of variables)
+/// * `L` - The number of entries
+pub trait GetHess<const N: usize, const L: usize> {
+    /// The type of the extracted Hessian stack
+    type Hess;
+
+    /// Extracts the Hessians, the `i`-th being the Hessian of the `i`-th component
+    fn hess(&self) -> Self::Hess;
+}
+
+impl<const N: usize, const L: usize, T: Real> GetGrad<N, L> for SVector<Ad<N, T>, L> {
+    type Grad = SMatrix<T, L, N>;
+    fn grad(&self) -> Self::Grad {
+        Self::Grad::from_fn(|i, j| self[i].grad[j])
+    }
+}
+
+impl<const N: usize, const L: usize, T: Real> GetHess<N, L> for SVector<Ad<N, T>, L> {
+    type Hess = [mat<N, T>; L];
+    fn hess(&self) -> Self::Hess {
+        std::array::from_fn(|i| self[i].hess)
+    }
+}
+
+impl<const N: usize, const L: usize> GetGrad<N, L> for SVector<AdGrad<N>, L> {
+    type Grad = SMatrix<f64, L, N>;
+    fn grad(&self) -> Self::Grad {
+        Self::Grad::from_fn(|i, j| self[i].grad[j])
+    }
+}
+
+impl<const N: usize, const L: usize> GetGrad<N, L> for SVector<AdThird<N>, L> {
+    type Grad = SMatrix<f64, L, N>;
+    fn grad(&self) -> Self::Grad {
+        Self::Grad::from_fn(|i, j| self[i].grad[j])
+    }
+}
+
+impl<const N: usize, const L: usize> GetHess<N, L> for SVector<AdThird<N>, L> {
+    type Hess = [mat<N>; L];
+    fn hess(&self) -> Self::Hess {
+        std::array::from_fn(|i| self[i].hess)
+    }
+}
+
+/// Implements [`GetGrad`] and [`GetHess`] for `R x C` matrices with `L = R * C` entries.
+/// Vectors (`C = 1`) have their own impls.
+macro_rules! impl_get_derivatives_matrix {
+    ($(($r:literal, $c:literal, $l:literal)),* $(,)?) => {$(
+        impl<const N: usize, T: Real> GetGrad<N, $l> for SMatrix<Ad<N, T>, $r, $c> {
+            type Grad = SMatrix<T, $l, N>;
+            fn grad(&self) -> Self::Grad {
+                Self::Grad::from_fn(|i, j| self.as_slice()[i].grad[j])
+            }
+        }
+
+        impl<const N: usize, T: Real> GetHess<N, $l> for SMatrix<Ad<N, T>, $r, $c> {
+            type Hess = [mat<N, T>; $l];
+            fn hess(&self) -> Self::Hess {
+                std::array::from_fn(|i| self.as_slice()[i].hess)
+            }
+        }
+
+        impl<const N: usize> GetGrad<N, $l> for SMatrix<AdGrad<N>, $r, $c> {
+            type Grad = SMatrix<f64, $l, N>;
+            fn grad(&self) -> Self::Grad {
+                Self::Grad::from_fn(|i, j| self.as_slice()[i].grad[j])
+            }
+        }
+
+        impl<const N: usize> GetGrad<N, $l> for SMatrix<AdThird<N>, $r, $c> {
+            type Grad = SMatrix<f64, $l, N>;
+            fn grad(&self) -> Self::Grad {
+                Self::Grad::from_fn(|i, j| self.as_slice()[i].grad[j])
+            }
+        }
+
+        impl<const N: usize> GetHess<N, $l> for SMatrix<AdThird<N>, $r, $c> {
+            type Hess = [mat<N>; $l];
+            fn hess(&self) -> Self::Hess {
+                std::array::from_fn(|i| self.as_slice()[i].hess)
+            }
+        }
+    )*};
+}
+
+impl_get_derivatives_matrix!(
+    (1, 2, 2),
+    (1, 3, 3),
+    (1, 4, 4),
+    (2, 2, 4),
+    (2, 3, 6),
+    (2, 4, 8),
+    (3, 2, 6),
+    (3, 3, 9),
+    (3, 4, 12),
+    (4, 2, 8),
+    (4, 3, 12),
+    (4, 4, 16),
+);
+
+/// Number of variables of dynamic AD values, constants having none
+fn dyn_dim(values: &DVector<AdDyn>) -> usize {
+    values.iter().map(|x| x.grad.len()).max().unwrap_or(0)
+}
+
+impl GetGrad<DYN, DYN> for DVector<AdDyn> {
+    type Grad = DMatrix<f64>;
+    fn grad(&self) -> Self::Grad {
+        DMatrix::from_fn(self.len(), dyn_dim(self), |i, j| {
+            self[i].grad.get(j).copied().unwrap_or(0.0)
+        })
+    }
+}
+
+impl GetHess<DYN, DYN> for DVector<AdDyn> {
+    type Hess = Vec<DMatrix<f64>>;
+    fn hess(&self) -> Self::Hess {
+        let n = dyn_dim(self);
+        self.iter()
+            .map(|x| {
+                if x.hess.nrows() == n {
+                    x.hess.clone()
+                } else {
+                    DMatrix::zeros(n, n)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Common interface of [`Ad<N>`] and [`AdGrad<N>`]
+///
+/// Objective code written generically over this trait can be evaluated with Hessians
+/// (`Ad<N>`) or, more cheaply, with gradients only (`AdGrad<N>`).
+///
+/// # Example
+/// ```
+/// use nalgebra::SVector;
+/// use raddy::{make::var, AdGrad, AdScalar};
+///
+/// fn energy<T: AdScalar<2>>(x: &SVector<T, 2>) -> T {
+///     let k = T::inactive_scalar(0.5);
+///     k * (x[0].clone() * x[0].clone() + x[1].clone() * x[1].clone())
+/// }
+///
+/// let full = energy(&var::vector_from_slice(&[1.0, 2.0]));
+/// let cheap: AdGrad<2> = energy(&var::grad_vector_from_slice(&[1.0, 2.0]));
+/// assert_eq!(full.grad(), cheap.grad());
+/// ```
+pub trait AdScalar<const N: usize>: RealField {
+    /// Creates an inactive scalar AD value with zero derivatives
+    fn inactive_scalar(value: f64) -> Self;
+
+    /// Creates a vector of active AD values, each with unit gradient in its own dimension
+    fn active_vector(vector: &SVector<f64, N>) -> SVector<Self, N>;
+
+    /// Returns the current value of the AD variable
+    fn value(&self) -> f64;
+
+    /// Returns the gradient (first derivatives) of the AD variable
+    fn grad(&self) -> vec<N>;
+}
+
+impl<const N: usize> AdScalar<N> for Ad<N> {
+    fn inactive_scalar(value: f64) -> Self {
+        Ad::inactive_scalar(value)
+    }
+
+    fn active_vector(vector: &SVector<f64, N>) -> SVector<Self, N> {
+        Ad::active_vector(vector)
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn grad(&self) -> vec<N> {
+        self.grad
+    }
+}
+
+impl<const N: usize> AdScalar<N> for AdGrad<N> {
+    fn inactive_scalar(value: f64) -> Self {
+        AdGrad::inactive_scalar(value)
+    }
+
+    fn active_vector(vector: &SVector<f64, N>) -> SVector<Self, N> {
+        AdGrad::active_vector(vector)
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn grad(&self) -> vec<N> {
+        self.grad
+    }
+}
+
 // ################################### Public Constructors ###################################
 
-impl Ad<1> {
+impl<T: Real> Ad<1, T> {
     /// Creates an AD scalar with explicitly specified value, gradient and Hessian
     ///
     /// # Arguments
@@ -118,7 +414,7 @@ impl Ad<1> {
     ///
     /// # Returns
     /// A new Ad<1> instance with the specified properties
-    pub fn given_scalar(value: f64, grad: f64, hess: f64) -> Self {
+    pub fn given_scalar(value: T, grad: T, hess: T) -> Self {
         Self {
             value,
             grad: vec::from_row_slice(&[grad]),
@@ -133,17 +429,17 @@ impl Ad<1> {
     ///
     /// # Returns
     /// A new Ad<1> instance that is active (gradient = 1.0)
-    pub fn active_scalar(value: f64) -> Self {
+    pub fn active_scalar(value: T) -> Self {
         let mut res = Self::_zeroed();
 
         res.value = value;
-        res.grad[0] = 1.0;
+        res.grad[0] = T::one();
 
         res
     }
 }
 
-impl<const N: usize> Ad<N> {
+impl<const N: usize, T: Real> Ad<N, T> {
     /// Creates an inactive scalar AD value with zero gradient and Hessian
     ///
     /// # Arguments
@@ -151,13 +447,13 @@ impl<const N: usize> Ad<N> {
     ///
     /// # Returns
     /// A new `Ad<N>` instance that is inactive (gradient = 0)
-    pub fn inactive_scalar(value: f64) -> Self {
+    pub fn inactive_scalar(value: T) -> Self {
         let mut res = Self::_zeroed();
         res.value = value;
         res
     }
 
-    /// Creates a vector of inactive AD values from a vector of f64 values
+    /// Creates a vector of inactive AD values from a vector of real values
     ///
     /// # Arguments
     /// * `values` - Input vector of numerical values
@@ -167,17 +463,11 @@ impl<const N: usize> Ad<N> {
     ///
     /// # Returns
     /// A vector of inactive AD values
-    pub fn inactive_vector<const L: usize>(values: &SVector<f64, N>) -> SVector<Self, L> {
-        let mut scalars = Vec::new();
-        for i in 0..N {
-            let scalar = Self::inactive_scalar(values[i]);
-            scalars.push(scalar);
-        }
-
-        SVector::from_column_slice(&scalars)
+    pub fn inactive_vector<const L: usize>(values: &SVector<T, N>) -> SVector<Self, L> {
+        SVector::from_iterator((0..L).map(|i| Self::inactive_scalar(values[i])))
     }
 
-    /// Creates a vector of inactive AD values from a slice of f64 values
+    /// Creates a vector of inactive AD values from a slice of real values
     ///
     /// # Arguments
     /// * `values` - Slice of numerical values
@@ -190,7 +480,7 @@ impl<const N: usize> Ad<N> {
     ///
     /// # Panics
     /// If the slice length doesn't match the input dimension N
-    pub fn inactive_from_slice<const L: usize>(values: &[f64]) -> SVector<Self, L> {
+    pub fn inactive_from_slice<const L: usize>(values: &[T]) -> SVector<Self, L> {
         assert_eq!(
             values.len(),
             N,
@@ -210,15 +500,15 @@ impl<const N: usize> Ad<N> {
     ///
     /// # Returns
     /// A new `Ad<N>` instance with the specified properties
-    pub fn given_vector(value: f64, grad: &vec<N>, hess: &mat<N>) -> Self {
+    pub fn given_vector(value: T, grad: &vec<N, T>, hess: &mat<N, T>) -> Self {
         Self {
             value,
-            grad: grad.clone(),
-            hess: hess.clone(),
+            grad: *grad,
+            hess: *hess,
         }
     }
 
-    /// Creates a vector of active AD values from a vector of f64 values
+    /// Creates a vector of active AD values from a vector of real values
     ///
     /// # Arguments
     /// * `values` - Input vector of numerical values
@@ -226,17 +516,24 @@ impl<const N: usize> Ad<N> {
     /// # Returns
     /// A vector of active AD values where each element has unit gradient
     /// in its corresponding dimension
-    pub fn active_vector(vector: &SVector<f64, N>) -> SVector<Self, N> {
-        let mut scalars = Vec::new();
-        for i in 0..N {
-            let scalar = Self::_active_scalar_with_index(vector[i], i);
-            scalars.push(scalar);
-        }
+    pub fn active_vector(vector: &SVector<T, N>) -> SVector<Self, N> {
+        Self::active_from_fn(|i| vector[i])
+    }
 
-        SVector::from_column_slice(&scalars)
+    /// Creates a vector of active AD values, the `i`-th with value `f(i)`,
+    /// built in place without heap allocation
+    ///
+    /// # Arguments
+    /// * `f` - Returns the value of each element from its index
+    ///
+    /// # Returns
+    /// A vector of active AD values where each element has unit gradient
+    /// in its corresponding dimension
+    pub fn active_from_fn(mut f: impl FnMut(usize) -> T) -> SVector<Self, N> {
+        SVector::from_iterator((0..N).map(|i| Self::_active_scalar_with_index(f(i), i)))
     }
 
-    /// Creates a vector of active AD values from a slice of f64 values
+    /// Creates a vector of active AD values from a slice of real values
     ///
     /// # Arguments
     /// * `values` - Slice of numerical values
@@ -246,7 +543,7 @@ impl<const N: usize> Ad<N> {
     ///
     /// # Panics
     /// If the slice length doesn't match the input dimension N
-    pub fn active_from_slice(values: &[f64]) -> SVector<Self, N> {
+    pub fn active_from_slice(values: &[T]) -> SVector<Self, N> {
         assert_eq!(
             values.len(),
             N,
@@ -254,25 +551,25 @@ impl<const N: usize> Ad<N> {
             N,
             values.len()
         );
-        Self::active_vector(&SVector::from_column_slice(values))
+        Self::active_from_fn(|i| values[i])
     }
 }
 
 // ################################### Private Constructors ###################################
 
-impl<const N: usize> Ad<N> {
-    fn _active_scalar_with_index(value: f64, index: usize) -> Self {
+impl<const N: usize, T: Real> Ad<N, T> {
+    fn _active_scalar_with_index(value: T, index: usize) -> Self {
         let mut res = Self::_zeroed();
 
         res.value = value;
-        res.grad[index] = 1.0;
+        res.grad[index] = T::one();
 
         res
     }
 
     fn _zeroed() -> Self {
         Self {
-            value: 0.0,
+            value: T::zero(),
             grad: vec::zeros(),
             hess: mat::zeros(),
         }
@@ -281,19 +578,50 @@ impl<const N: usize> Ad<N> {
 
 // ################################### Utils ###################################
 
-impl<const N: usize> Ad<N> {
+impl<const N: usize, T: Real> Ad<N, T> {
     fn chain(
-        value: f64, // f
-        d: f64,     // df/da
-        d2: f64,    // ddf/daa
+        value: T, // f
+        d: T,     // df/da
+        d2: T,    // ddf/daa
         a: &Self,
     ) -> Self {
         let mut res = Self::_zeroed();
 
         res.value = value;
-        res.grad = d * &a.grad;
-        res.hess = d2 * &a.grad * &a.grad.transpose() + d * a.hess;
+        res.grad = a.grad * d;
+        res.hess = a.grad * a.grad.transpose() * d2 + a.hess * d;
 
         res
     }
+
+    #[allow(clippy::too_many_arguments)]
+    fn chain2(
+        value: T, // f
+        da: T,    // df/da
+        db: T,    // df/db
+        daa: T,   // ddf/daa
+        dab: T,   // ddf/dadb
+        dbb: T,   // ddf/dbb
+        a: &Self,
+        b: &Self,
+    ) -> Self {
+        let mut res = Self::_zeroed();
+
+        let ab = a.grad * b.grad.transpose();
+
+        res.value = value;
+        res.grad = a.grad * da + b.grad * db;
+        res.hess = a.hess * da
+            + b.hess * db
+            + a.grad * a.grad.transpose() * daa
+            + (ab + ab.transpose()) * dab
+            + b.grad * b.grad.transpose() * dbb;
+
+        res
+    }
+
+    /// Whether this value has zero gradient and Hessian, i.e. is a constant
+    fn is_constant(&self) -> bool {
+        self.grad.iter().all(|g| g.is_zero()) && self.hess.iter().all(|h| h.is_zero())
+    }
 }
@@ -0,0 +1,316 @@
+use crate::{
+    types::{admat, advec, mat},
+    Ad, GetValue,
+};
+use na::{DMatrix, SMatrix};
+
+/// Relative gap below which two singular values (or eigenvalues) are treated as equal
+pub const DEGENERATE_GAP: f64 = 1e-10;
+
+/// Singular value decomposition `A = U * diag(σ) * Vᵀ`
+///
+/// This is the rotation variant SVD: `U` and `V` are rotations (determinant `+1`),
+/// so the singular value of smallest magnitude is negative if `det(A) < 0`.
+///
+/// ## Fields
+/// - `u`: The left rotation `U`
+/// - `singular_values`: The signed singular values `σ`
+/// - `v`: The right rotation `V`
+#[derive(Debug, Clone)]
+pub struct Svd<const N: usize, const D: usize> {
+    pub u: admat<N, D, D>,
+    pub singular_values: advec<N, D>,
+    pub v: admat<N, D, D>,
+}
+
+/// Polar decomposition `A = R * S`
+///
+/// ## Fields
+/// - `r`: The rotation `R` (determinant `+1`)
+/// - `s`: The symmetric stretch `S`, indefinite if `det(A) < 0`
+#[derive(Debug, Clone)]
+pub struct Polar<const N: usize, const D: usize> {
+    pub r: admat<N, D, D>,
+    pub s: admat<N, D, D>,
+}
+
+/// Eigen-decomposition `A = Q * diag(λ) * Qᵀ` of a symmetric matrix
+///
+/// ## Fields
+/// - `eigenvalues`: The eigenvalues `λ`, in ascending order
+/// - `eigenvectors`: The rotation `Q`, whose columns are the eigenvectors
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<const N: usize, const D: usize> {
+    pub eigenvalues: advec<N, D>,
+    pub eigenvectors: admat<N, D, D>,
+}
+
+/// Determinant of a 2x2 or 3x3 matrix by the closed-form formula
+///
+/// # Panics
+/// If `D` is not 2 or 3
+pub fn determinant<const N: usize, const D: usize>(m: &admat<N, D, D>) -> Ad<N> {
+    match D {
+        2 => &m[(0, 0)] * &m[(1, 1)] - &m[(0, 1)] * &m[(1, 0)],
+        3 => (0..3).fold(Ad::inactive_scalar(0.0), |acc, j| {
+            let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+            acc + &m[(0, j)] * (&m[(1, j1)] * &m[(2, j2)] - &m[(1, j2)] * &m[(2, j1)])
+        }),
+        _ => panic!("Closed-form determinant is only implemented for 2x2 and 3x3 matrices!"),
+    }
+}
+
+/// Inverse of a 2x2 or 3x3 matrix by the closed-form adjugate formula,
+/// so that its derivatives are exact.
+///
+/// # Returns
+/// `None` if the matrix is singular
+///
+/// # Panics
+/// If `D` is not 2 or 3
+pub fn inverse<const N: usize, const D: usize>(m: &admat<N, D, D>) -> Option<admat<N, D, D>> {
+    let cofactor = |i: usize, j: usize| -> Ad<N> {
+        match D {
+            2 => {
+                let minor = &m[(1 - i, 1 - j)];
+                if (i + j).is_multiple_of(2) {
+                    minor.clone()
+                } else {
+                    -minor
+                }
+            }
+            3 => {
+                let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                &m[(i1, j1)] * &m[(i2, j2)] - &m[(i1, j2)] * &m[(i2, j1)]
+            }
+            _ => panic!("Closed-form inverse is only implemented for 2x2 and 3x3 matrices!"),
+        }
+    };
+
+    let cofactors = admat::<N, D, D>::from_fn(cofactor);
+    let det = (0..D).fold(Ad::inactive_scalar(0.0), |acc, j| {
+        acc + &m[(0, j)] * &cofactors[(0, j)]
+    });
+
+    if det.value == 0.0 {
+        return None;
+    }
+
+    Some(cofactors.transpose().map(|c| &c / &det))
+}
+
+/// Rotation variant singular value decomposition of a small square matrix.
+///
+/// The decomposition is computed on values and then refined by Newton steps in AD
+/// arithmetic, giving exact gradients and Hessians of all outputs.
+///
+/// ## Degeneracies
+/// If two singular values are equal (relative gap below [`DEGENERATE_GAP`]),
+/// the singular vectors within their subspace are not unique, and neither are their
+/// derivatives. One of them is returned, so derivatives of `U` and `V` are then arbitrary.
+/// The equal singular values are not twice differentiable individually, only their sum is.
+/// Use [`polar`] for the rotation `U * Vᵀ`, which stays smooth.
+///
+/// # Panics
+/// If `σ_i + σ_j = 0` for two singular values, i.e. the rank of `A` is below `D - 1`
+/// (or it is below `D` with `det(A)` of ambiguous sign)
+pub fn svd<const N: usize, const D: usize>(m: &admat<N, D, D>) -> Svd<N, D> {
+    let (u, sigma, v) = value_svd(m);
+    let (u, v, reduced) = refine(m, &u, &v, &sigma, false);
+
+    Svd {
+        u,
+        singular_values: advec::<N, D>::from_fn(|i, _| reduced[(i, i)].clone()),
+        v,
+    }
+}
+
+/// Polar decomposition of a small square matrix into a rotation and a symmetric stretch,
+/// with exact gradients and Hessians.
+///
+/// The rotation is refined directly from the condition that `Rᵀ A` is symmetric,
+/// so it stays smooth where singular values coincide.
+///
+/// # Panics
+/// If `σ_i + σ_j = 0` for two signed singular values, see [`svd`]
+pub fn polar<const N: usize, const D: usize>(m: &admat<N, D, D>) -> Polar<N, D> {
+    let (u, sigma, v) = value_svd(m);
+    let scale = sigma.iter().fold(1.0_f64, |acc, s| acc.max(s.abs()));
+
+    let mut r: admat<N, D, D> = (u * v.transpose()).map(Ad::inactive_scalar);
+    for _ in 0..2 {
+        // The skew part of `Rᵀ A` in the basis of `V`, where `S` is diagonal on values
+        let f =
+            v.transpose().map(Ad::inactive_scalar) * r.transpose() * m * v.map(Ad::inactive_scalar);
+        let mut w = admat::<N, D, D>::zeros();
+
+        for i in 0..D {
+            for j in i + 1..D {
+                let (si, sj) = (sigma[i], sigma[j]);
+                if (si + sj).abs() <= DEGENERATE_GAP * scale {
+                    // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+                    panic!("Rotation of a matrix with rank < D - 1 is not unique!");
+                }
+                set_angle(&mut w, i, j, -(&f[(i, j)] - &f[(j, i)]).div_value(si + sj));
+            }
+        }
+
+        let basis = v.map(Ad::inactive_scalar);
+        r = &r * (&basis * rotation(&w) * basis.transpose());
+    }
+
+    let s = r.transpose() * m;
+    let s = (&s + s.transpose()).map(|x| x.mul_value(0.5));
+
+    Polar { r, s }
+}
+
+/// Eigen-decomposition of a small symmetric matrix, with exact gradients and Hessians.
+/// Only the symmetric part `(A + Aᵀ) / 2` of the input is used.
+///
+/// ## Degeneracies
+/// If two eigenvalues are equal (relative gap below [`DEGENERATE_GAP`]),
+/// the eigenvectors within their subspace are not unique, and neither are their
+/// derivatives. One of them is returned, so derivatives of the eigenvectors are then
+/// arbitrary. The equal eigenvalues are not twice differentiable individually, only their
+/// sum is.
+pub fn symmetric_eigen<const N: usize, const D: usize>(m: &admat<N, D, D>) -> SymmetricEigen<N, D> {
+    let sym = (m + m.transpose()).map(|x| x.mul_value(0.5));
+
+    let eigen = DMatrix::from_column_slice(D, D, sym.value().as_slice()).symmetric_eigen();
+    let mut order: Vec<usize> = (0..D).collect();
+    order.sort_by(|&a, &b| eigen.eigenvalues[a].total_cmp(&eigen.eigenvalues[b]));
+
+    let lambda: Vec<f64> = order.iter().map(|&i| eigen.eigenvalues[i]).collect();
+    let mut q = DMatrix::from_fn(D, D, |i, j| eigen.eigenvectors[(i, order[j])]);
+    if q.determinant() < 0.0 {
+        q.column_mut(0).neg_mut();
+    }
+    let q = mat::<D>::from_column_slice(q.as_slice());
+
+    let (q, _, reduced) = refine(&sym, &q, &q, &lambda, true);
+
+    SymmetricEigen {
+        eigenvalues: advec::<N, D>::from_fn(|i, _| reduced[(i, i)].clone()),
+        eigenvectors: q,
+    }
+}
+
+/// Eigenvalues of a small symmetric matrix in ascending order, see [`symmetric_eigen`]
+pub fn symmetric_eigenvalues<const N: usize, const D: usize>(m: &admat<N, D, D>) -> advec<N, D> {
+    symmetric_eigen(m).eigenvalues
+}
+
+/// Rotation variant SVD of the values, see [`svd`]
+///
+/// ## Returns
+/// `(U, σ, V)`
+fn value_svd<const N: usize, const D: usize>(m: &admat<N, D, D>) -> (mat<D>, Vec<f64>, mat<D>) {
+    let svd = DMatrix::from_column_slice(D, D, m.value().as_slice()).svd(true, true);
+    let mut u = svd.u.unwrap();
+    let mut v_t = svd.v_t.unwrap();
+    let mut sigma = svd.singular_values;
+
+    // Rotation variant: move reflections to the singular value of smallest magnitude
+    let k = sigma.imin();
+    if u.determinant() < 0.0 {
+        u.column_mut(k).neg_mut();
+        sigma[k] = -sigma[k];
+    }
+    if v_t.determinant() < 0.0 {
+        v_t.row_mut(k).neg_mut();
+        sigma[k] = -sigma[k];
+    }
+
+    (
+        mat::<D>::from_column_slice(u.as_slice()),
+        sigma.as_slice().to_vec(),
+        mat::<D>::from_column_slice(v_t.transpose().as_slice()),
+    )
+}
+
+/// Refines rotations `U`, `V` diagonalizing `Uᵀ A V` to carry derivatives.
+///
+/// `u` and `v` are exact on values. Each Newton step, with the Jacobian frozen at
+/// the values, makes one more order of derivatives exact, so two steps give
+/// exact gradients and Hessians.
+///
+/// ## Returns
+/// `(U, V, Uᵀ A V)`
+fn refine<const N: usize, const D: usize>(
+    a: &admat<N, D, D>,
+    u: &mat<D>,
+    v: &mat<D>,
+    sigma: &[f64],
+    symmetric: bool,
+) -> (admat<N, D, D>, admat<N, D, D>, admat<N, D, D>) {
+    let scale = sigma.iter().fold(1.0_f64, |acc, s| acc.max(s.abs()));
+    let tol = DEGENERATE_GAP * scale;
+
+    let mut u: admat<N, D, D> = u.map(Ad::inactive_scalar);
+    let mut v: admat<N, D, D> = v.map(Ad::inactive_scalar);
+
+    for _ in 0..2 {
+        let reduced = u.transpose() * a * &v;
+        let mut wu = admat::<N, D, D>::zeros();
+        let mut wv = admat::<N, D, D>::zeros();
+
+        for i in 0..D {
+            for j in i + 1..D {
+                let (si, sj) = (sigma[i], sigma[j]);
+                let degenerate = (sj - si).abs() <= tol;
+                let (fij, fji) = (&reduced[(i, j)], &reduced[(j, i)]);
+
+                if symmetric {
+                    if !degenerate {
+                        set_angle(&mut wu, i, j, -fij.div_value(sj - si));
+                    }
+                    continue;
+                }
+
+                if (si + sj).abs() <= tol {
+                    // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+                    panic!("Rotation of a matrix with rank < D - 1 is not unique!");
+                }
+                // The difference of the angles rotates U Vᵀ, the sum only the singular vectors
+                let diff = -(fij - fji).div_value(si + sj);
+                let sum = if degenerate {
+                    Ad::inactive_scalar(0.0)
+                } else {
+                    -(fij + fji).div_value(sj - si)
+                };
+
+                set_angle(&mut wu, i, j, (&sum + &diff).mul_value(0.5));
+                set_angle(&mut wv, i, j, (&sum - &diff).mul_value(0.5));
+            }
+        }
+
+        u = &u * rotation(&wu);
+        v = if symmetric {
+            u.clone()
+        } else {
+            &v * rotation(&wv)
+        };
+    }
+
+    let reduced = u.transpose() * a * &v;
+    (u, v, reduced)
+}
+
+/// Sets the angle in the `(i, j)` plane of a skew matrix
+fn set_angle<const N: usize, const D: usize>(
+    w: &mut admat<N, D, D>,
+    i: usize,
+    j: usize,
+    angle: Ad<N>,
+) {
+    w[(i, j)] = -&angle;
+    w[(j, i)] = angle;
+}
+
+/// `exp(W)` of a skew matrix `W` with small values, exact up to second order
+fn rotation<const N: usize, const D: usize>(w: &admat<N, D, D>) -> admat<N, D, D> {
+    let w2 = w * w;
+    SMatrix::identity() + w + w2.map(|x| x.mul_value(0.5))
+}
@@ -1,6 +1,9 @@
-use crate::{types::admat, Ad};
+use crate::{
+    types::{admat, Real},
+    Ad, AdDyn, AdGrad, AdSparse, AdThird,
+};
 use itertools::Itertools;
-use na::{SMatrix, SVector};
+use na::{DMatrix, DVector, SMatrix, SVector};
 
 #[inline]
 /// Creates an inactive scalar Ad value with specified inputd dimensions
@@ -14,6 +17,19 @@ use na::{SMatrix, SVector};
 /// # Returns
 /// An `Ad<N>` instance representing an inactive scalar value
 pub fn scalar<const N: usize>(value: f64) -> Ad<N> {
+    scalar_of(value)
+}
+
+#[inline]
+/// Creates an inactive scalar Ad value of real type `T` with specified input dimensions
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+/// * `T` - The real type, e.g. `f32`
+///
+/// # Returns
+/// An `Ad<N, T>` instance representing an inactive scalar value
+pub fn scalar_of<const N: usize, T: Real>(value: T) -> Ad<N, T> {
     Ad::inactive_scalar(value)
 }
 
@@ -30,6 +46,22 @@ pub fn scalar<const N: usize>(value: f64) -> Ad<N> {
 /// # Returns
 /// An SVector of `Ad<N>` values where each element is inactive
 pub fn vector_from_slice<const N: usize, const L: usize>(values: &[f64]) -> SVector<Ad<N>, L> {
+    vector_from_slice_of(values)
+}
+
+#[inline]
+/// Creates a vector of inactive Ad values of real type `T`
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+/// * `L` - The length of the vector
+/// * `T` - The real type, e.g. `f32`
+///
+/// # Returns
+/// An SVector of `Ad<N, T>` values where each element is inactive
+pub fn vector_from_slice_of<const N: usize, const L: usize, T: Real>(
+    values: &[T],
+) -> SVector<Ad<N, T>, L> {
     Ad::inactive_from_slice(values)
 }
 
@@ -66,6 +98,22 @@ pub fn vectpr<const N: usize, const L: usize>(values: &[f64]) -> SVector<Ad<N>,
 pub fn matrix_from_row_slice<const N: usize, const R: usize, const C: usize>(
     values: &[f64],
 ) -> SMatrix<Ad<N>, R, C> {
+    matrix_from_row_slice_of(values)
+}
+
+/// Creates a matrix of inactive Ad values of real type `T` from a row-major slice
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+/// * `R` - The number of rows
+/// * `C` - The number of columns
+/// * `T` - The real type, e.g. `f32`
+///
+/// # Panics
+/// Panics if the length of values does not equal R * C
+pub fn matrix_from_row_slice_of<const N: usize, const R: usize, const C: usize, T: Real>(
+    values: &[T],
+) -> SMatrix<Ad<N, T>, R, C> {
     assert_eq!(
         values.len(),
         R * C,
@@ -75,7 +123,7 @@ pub fn matrix_from_row_slice<const N: usize, const R: usize, const C: usize>(
         R,
         C
     );
-    let mut res: admat<N, R, C> = SMatrix::zeros();
+    let mut res: admat<N, R, C, T> = SMatrix::zeros();
 
     for (r, c) in (0..R).cartesian_product(0..C) {
         res[(r, c)].value = values[r * C + c];
@@ -102,6 +150,22 @@ pub fn matrix_from_row_slice<const N: usize, const R: usize, const C: usize>(
 pub fn matrix_from_column_slice<const N: usize, const R: usize, const C: usize>(
     values: &[f64],
 ) -> SMatrix<Ad<N>, R, C> {
+    matrix_from_column_slice_of(values)
+}
+
+/// Creates a matrix of inactive Ad values of real type `T` from a column-major slice
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+/// * `R` - The number of rows
+/// * `C` - The number of columns
+/// * `T` - The real type, e.g. `f32`
+///
+/// # Panics
+/// Panics if the length of values does not equal R * C
+pub fn matrix_from_column_slice_of<const N: usize, const R: usize, const C: usize, T: Real>(
+    values: &[T],
+) -> SMatrix<Ad<N, T>, R, C> {
     assert_eq!(
         values.len(),
         R * C,
@@ -111,7 +175,7 @@ pub fn matrix_from_column_slice<const N: usize, const R: usize, const C: usize>(
         R,
         C
     );
-    let mut res: admat<N, R, C> = SMatrix::zeros();
+    let mut res: admat<N, R, C, T> = SMatrix::zeros();
 
     for (r, c) in (0..R).cartesian_product(0..C) {
         res[(r, c)].value = values[c * R + r];
@@ -135,7 +199,20 @@ pub fn matrix_from_column_slice<const N: usize, const R: usize, const C: usize>(
 pub fn valmat<const N: usize, const R: usize, const C: usize>(
     matrix: SMatrix<f64, R, C>,
 ) -> SMatrix<Ad<N>, R, C> {
-    let mut res: admat<N, R, C> = SMatrix::zeros();
+    valmat_of(matrix)
+}
+
+/// Converts a matrix of real values of type `T` to a matrix of inactive Ad values
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+/// * `R` - The number of rows
+/// * `C` - The number of columns
+/// * `T` - The real type, e.g. `f32`
+pub fn valmat_of<const N: usize, const R: usize, const C: usize, T: Real>(
+    matrix: SMatrix<T, R, C>,
+) -> SMatrix<Ad<N, T>, R, C> {
+    let mut res: admat<N, R, C, T> = SMatrix::zeros();
 
     for (r, c) in (0..R).cartesian_product(0..C) {
         res[(r, c)].value = matrix[(r, c)];
@@ -143,3 +220,115 @@ pub fn valmat<const N: usize, const R: usize, const C: usize>(
 
     res
 }
+
+#[inline]
+/// Creates an inactive heap-allocated Ad value, compatible with any dimension
+///
+/// # Arguments
+/// * `value` - The scalar value to wrap in an AdDyn type
+///
+/// # Returns
+/// An `AdDyn` instance representing a constant
+pub fn dscalar(value: f64) -> AdDyn {
+    AdDyn::inactive_scalar(value)
+}
+
+/// Creates a vector of inactive heap-allocated Ad values
+///
+/// # Arguments
+/// * `values` - Slice of f64 values to convert to inactive Ad values
+///
+/// # Returns
+/// A DVector of `AdDyn` values where each element is a constant
+pub fn dvector_from_slice(values: &[f64]) -> DVector<AdDyn> {
+    AdDyn::inactive_from_slice(values)
+}
+
+/// Converts a matrix of f64 values to a matrix of inactive heap-allocated Ad values
+///
+/// # Arguments
+/// * `matrix` - The matrix of f64 values to convert
+///
+/// # Returns
+/// A DMatrix of `AdDyn` values where each element is a constant
+pub fn dvalmat(matrix: &DMatrix<f64>) -> DMatrix<AdDyn> {
+    matrix.map(AdDyn::inactive_scalar)
+}
+
+#[inline]
+/// Creates an inactive first-order scalar with specified input dimensions
+///
+/// # Arguments
+/// * `value` - The scalar value to wrap in an AdGrad type
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+///
+/// # Returns
+/// An `AdGrad<N>` instance representing an inactive scalar value
+pub fn grad_scalar<const N: usize>(value: f64) -> AdGrad<N> {
+    AdGrad::inactive_scalar(value)
+}
+
+#[inline]
+/// Creates a vector of inactive first-order values with separate input and vector dimensions
+///
+/// # Arguments
+/// * `values` - Slice of f64 values to convert to inactive AdGrad values
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+/// * `L` - The length of the vector
+///
+/// # Returns
+/// An SVector of `AdGrad<N>` values where each element is inactive
+pub fn grad_vector_from_slice<const N: usize, const L: usize>(
+    values: &[f64],
+) -> SVector<AdGrad<N>, L> {
+    AdGrad::inactive_from_slice(values)
+}
+
+#[inline]
+/// Creates an inactive third-order scalar with specified input dimensions
+///
+/// # Arguments
+/// * `value` - The scalar value to wrap in an AdThird type
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+///
+/// # Returns
+/// An `AdThird<N>` instance representing an inactive scalar value
+pub fn third_scalar<const N: usize>(value: f64) -> AdThird<N> {
+    AdThird::inactive_scalar(value)
+}
+
+#[inline]
+/// Creates a vector of inactive third-order values with separate input and vector dimensions
+///
+/// # Arguments
+/// * `values` - Slice of f64 values to convert to inactive AdThird values
+///
+/// # Type Parameters
+/// * `N` - The input dimension (for derivatives)
+/// * `L` - The length of the vector
+///
+/// # Returns
+/// An SVector of `AdThird<N>` values where each element is inactive
+pub fn third_vector_from_slice<const N: usize, const L: usize>(
+    values: &[f64],
+) -> SVector<AdThird<N>, L> {
+    AdThird::inactive_from_slice(values)
+}
+
+#[inline]
+/// Creates an inactive (constant) sparse-derivative value
+///
+/// # Arguments
+/// * `value` - The scalar value to wrap in an AdSparse type
+///
+/// # Returns
+/// An `AdSparse` instance representing a constant
+pub fn sparse_scalar(value: f64) -> AdSparse {
+    AdSparse::inactive_scalar(value)
+}
@@ -1,5 +1,5 @@
-use crate::Ad;
-use na::SVector;
+use crate::{types::Real, Ad, AdDyn, AdGrad, AdSparse, AdThird};
+use na::{DVector, SVector};
 
 #[inline]
 /// Creates an active scalar Ad value with single input dimension
@@ -10,6 +10,15 @@ use na::SVector;
 /// # Returns
 /// An Ad<1> instance representing an active scalar value with single input dimension
 pub fn scalar(value: f64) -> Ad<1> {
+    scalar_of(value)
+}
+
+#[inline]
+/// Creates an active scalar Ad value of real type `T` with single input dimension
+///
+/// # Returns
+/// An `Ad<1, T>` instance representing an active scalar value with single input dimension
+pub fn scalar_of<T: Real>(value: T) -> Ad<1, T> {
     Ad::active_scalar(value)
 }
 
@@ -25,6 +34,19 @@ pub fn scalar(value: f64) -> Ad<1> {
 /// # Returns
 /// An SVector of `Ad<L>` values where each element is active
 pub fn vector_from_slice<const L: usize>(values: &[f64]) -> SVector<Ad<L>, L> {
+    vector_from_slice_of(values)
+}
+
+#[inline]
+/// Creates a vector of active Ad values of real type `T`
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for gradients) and vector length
+/// * `T` - The real type, e.g. `f32`
+///
+/// # Returns
+/// An SVector of `Ad<L, T>` values where each element is active
+pub fn vector_from_slice_of<const L: usize, T: Real>(values: &[T]) -> SVector<Ad<L, T>, L> {
     Ad::active_from_slice(values)
 }
 
@@ -40,5 +62,172 @@ pub fn vector_from_slice<const L: usize>(values: &[f64]) -> SVector<Ad<L>, L> {
 /// # Returns
 /// An SVector of `Ad<L>` values where each element is active
 pub fn vector<const L: usize>(vector: &SVector<f64, L>) -> SVector<Ad<L>, L> {
+    vector_of(vector)
+}
+
+#[inline]
+/// Creates a vector of active Ad values from a vector of real type `T`
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for gradients) and vector length
+/// * `T` - The real type, e.g. `f32`
+///
+/// # Returns
+/// An SVector of `Ad<L, T>` values where each element is active
+pub fn vector_of<const L: usize, T: Real>(vector: &SVector<T, L>) -> SVector<Ad<L, T>, L> {
     Ad::active_vector(vector)
 }
+
+#[inline]
+/// Creates a vector of active Ad values from a function of the index,
+/// without heap allocation
+///
+/// # Arguments
+/// * `f` - Returns the value of each element from its index
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for gradients) and vector length
+///
+/// # Returns
+/// An SVector of `Ad<L>` values where each element is active
+pub fn vector_from_fn<const L: usize>(f: impl FnMut(usize) -> f64) -> SVector<Ad<L>, L> {
+    vector_from_fn_of(f)
+}
+
+#[inline]
+/// Creates a vector of active Ad values of real type `T` from a function of the index,
+/// without heap allocation
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for gradients) and vector length
+/// * `T` - The real type, e.g. `f32`
+///
+/// # Returns
+/// An SVector of `Ad<L, T>` values where each element is active
+pub fn vector_from_fn_of<const L: usize, T: Real>(
+    f: impl FnMut(usize) -> T,
+) -> SVector<Ad<L, T>, L> {
+    Ad::active_from_fn(f)
+}
+
+#[inline]
+/// Creates a vector of active heap-allocated Ad values from a slice
+///
+/// # Arguments
+/// * `values` - Slice of f64 values to convert to active Ad values
+///
+/// # Returns
+/// A DVector of `AdDyn` values where each element is active,
+/// whose dimension is the length of the slice
+pub fn dvector_from_slice(values: &[f64]) -> DVector<AdDyn> {
+    AdDyn::active_from_slice(values)
+}
+
+#[inline]
+/// Creates a vector of active heap-allocated Ad values from a vector
+///
+/// # Arguments
+/// * `vector` - Vector of f64 values to convert to active Ad values
+///
+/// # Returns
+/// A DVector of `AdDyn` values where each element is active,
+/// whose dimension is the length of the vector
+pub fn dvector(vector: &DVector<f64>) -> DVector<AdDyn> {
+    AdDyn::active_vector(vector)
+}
+
+#[inline]
+/// Creates an active first-order scalar with single input dimension
+///
+/// # Arguments
+/// * `value` - The scalar value to wrap in an AdGrad type
+///
+/// # Returns
+/// An AdGrad<1> instance representing an active scalar value with single input dimension
+pub fn grad_scalar(value: f64) -> AdGrad<1> {
+    AdGrad::active_scalar(value)
+}
+
+#[inline]
+/// Creates a vector of active first-order values with specified input and vector dimensions
+///
+/// # Arguments
+/// * `values` - Slice of f64 values to convert to active AdGrad values
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for gradients) and vector length
+///
+/// # Returns
+/// An SVector of `AdGrad<L>` values where each element is active
+pub fn grad_vector_from_slice<const L: usize>(values: &[f64]) -> SVector<AdGrad<L>, L> {
+    AdGrad::active_from_slice(values)
+}
+
+#[inline]
+/// Creates a vector of active first-order values with specified input and vector dimensions
+///
+/// # Arguments
+/// * `vector` - Vector of f64 values to convert to active AdGrad values
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for gradients) and vector length
+///
+/// # Returns
+/// An SVector of `AdGrad<L>` values where each element is active
+pub fn grad_vector<const L: usize>(vector: &SVector<f64, L>) -> SVector<AdGrad<L>, L> {
+    AdGrad::active_vector(vector)
+}
+
+#[inline]
+/// Creates an active third-order scalar with single input dimension
+///
+/// # Arguments
+/// * `value` - The scalar value to wrap in an AdThird type
+///
+/// # Returns
+/// An AdThird<1> instance representing an active scalar value with single input dimension
+pub fn third_scalar(value: f64) -> AdThird<1> {
+    AdThird::active_scalar(value)
+}
+
+#[inline]
+/// Creates a vector of active third-order values with specified input and vector dimensions
+///
+/// # Arguments
+/// * `values` - Slice of f64 values to convert to active AdThird values
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for derivatives) and vector length
+///
+/// # Returns
+/// An SVector of `AdThird<L>` values where each element is active
+pub fn third_vector_from_slice<const L: usize>(values: &[f64]) -> SVector<AdThird<L>, L> {
+    AdThird::active_from_slice(values)
+}
+
+#[inline]
+/// Creates a vector of active third-order values with specified input and vector dimensions
+///
+/// # Arguments
+/// * `vector` - Vector of f64 values to convert to active AdThird values
+///
+/// # Type Parameters
+/// * `L` - Both the input dimension (for derivatives) and vector length
+///
+/// # Returns
+/// An SVector of `AdThird<L>` values where each element is active
+pub fn third_vector<const L: usize>(vector: &SVector<f64, L>) -> SVector<AdThird<L>, L> {
+    AdThird::active_vector(vector)
+}
+
+#[inline]
+/// Creates active sparse-derivative values from a slice
+///
+/// # Arguments
+/// * `values` - Slice of f64 values to convert to active AdSparse values
+///
+/// # Returns
+/// A Vec of `AdSparse` values where the `i`-th element is the `i`-th variable
+pub fn sparse_vector_from_slice(values: &[f64]) -> Vec<AdSparse> {
+    AdSparse::active_from_slice(values)
+}
@@ -0,0 +1,346 @@
+use faer::Col;
+use itertools::Itertools;
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+/// Wavefront OBJ triangle meshes.
+pub mod obj;
+/// OFF triangle meshes.
+pub mod off;
+/// ASCII PLY triangle and tetrahedral meshes.
+pub mod ply;
+
+/// A triangle and/or tetrahedral mesh
+///
+/// ## Fields
+/// - `dim`: The spatial dimension, 2 or 3
+/// - `positions`: The vertex positions, `[x0, y0, (z0,) x1, y1, ...]`, as used as
+///   variables of objectives
+/// - `triangles`: The vertex indices of each triangle
+/// - `tets`: The vertex indices of each tetrahedron
+///
+/// ## Example
+/// ```ignore
+/// let mesh = Mesh::load("bunny.obj")?;
+/// let springs: Vec<[usize; 6]> = mesh.dofs(&mesh.edges());
+/// let result = NewtonSolver::default().solve(&ObjectiveTerm::new(&obj, &springs, &1.0), &mesh.positions);
+/// mesh.with_positions(&result.x).save("bunny_out.obj")?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mesh {
+    pub dim: usize,
+    pub positions: Col<f64>,
+    pub triangles: Vec<[usize; 3]>,
+    pub tets: Vec<[usize; 4]>,
+}
+
+/// Errors reading or writing meshes
+#[derive(Debug)]
+pub enum MeshError {
+    /// Failure of the underlying reader or writer
+    Io(io::Error),
+    /// Malformed content, at a 1-based line number
+    Parse { line: usize, message: String },
+    /// An element refers to a vertex that does not exist
+    IndexOutOfBounds { index: usize, n_vertices: usize },
+    /// Valid content this module does not handle, e.g. binary PLY
+    Unsupported(String),
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshError::Io(err) => write!(f, "I/O error: {}", err),
+            MeshError::Parse { line, message } => {
+                write!(f, "Parse error at line {}: {}", line, message)
+            }
+            MeshError::IndexOutOfBounds { index, n_vertices } => write!(
+                f,
+                "Vertex index {} out of bounds for {} vertices",
+                index, n_vertices
+            ),
+            MeshError::Unsupported(message) => write!(f, "Unsupported: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for MeshError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MeshError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MeshError {
+    fn from(err: io::Error) -> Self {
+        MeshError::Io(err)
+    }
+}
+
+impl Mesh {
+    /// Creates a mesh from 3D vertex positions and connectivity
+    ///
+    /// # Panics
+    /// If an element refers to a vertex that does not exist
+    pub fn new(vertices: &[[f64; 3]], triangles: Vec<[usize; 3]>, tets: Vec<[usize; 4]>) -> Self {
+        checked(vertices, triangles, tets).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Returns the number of vertices
+    pub fn n_vertices(&self) -> usize {
+        self.positions.nrows() / self.dim
+    }
+
+    /// Returns the position of vertex `i`, with `z = 0` for 2D meshes
+    pub fn vertex(&self, i: usize) -> [f64; 3] {
+        std::array::from_fn(|k| {
+            if k < self.dim {
+                self.positions[i * self.dim + k]
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// Returns the mesh with positions in `dim` dimensions, dropping `z` for 2D
+    ///
+    /// # Panics
+    /// If `dim` is not 2 or 3
+    pub fn with_dim(&self, dim: usize) -> Self {
+        assert!(
+            dim == 2 || dim == 3,
+            "Mesh dimension must be 2 or 3, got {}",
+            dim
+        );
+        let vertices = (0..self.n_vertices()).map(|i| self.vertex(i)).collect_vec();
+
+        Self {
+            dim,
+            positions: Col::from_fn(vertices.len() * dim, |i| vertices[i / dim][i % dim]),
+            triangles: self.triangles.clone(),
+            tets: self.tets.clone(),
+        }
+    }
+
+    /// Returns the mesh with new positions, e.g. the result of a solver
+    ///
+    /// # Panics
+    /// If `positions` differs in length from the current positions
+    pub fn with_positions(&self, positions: &Col<f64>) -> Self {
+        assert_eq!(
+            positions.nrows(),
+            self.positions.nrows(),
+            "Position count mismatch: mesh has {}, got {}",
+            self.positions.nrows(),
+            positions.nrows()
+        );
+
+        Self {
+            positions: positions.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the unique edges of all triangles and tetrahedra, sorted,
+    /// each with the smaller vertex index first
+    pub fn edges(&self) -> Vec<[usize; 2]> {
+        let triangle_edges = self
+            .triangles
+            .iter()
+            .flat_map(|t| [[t[0], t[1]], [t[1], t[2]], [t[2], t[0]]]);
+        let tet_edges = self.tets.iter().flat_map(|t| {
+            (0..4)
+                .tuple_combinations()
+                .map(|(a, b)| [t[a], t[b]])
+                .collect_vec()
+        });
+
+        triangle_edges
+            .chain(tet_edges)
+            .map(|[a, b]| [a.min(b), a.max(b)])
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    /// Returns the hinges of the triangles, `[x0, x1, x2, x3]` for each edge `x0 x1`
+    /// shared by exactly two triangles with opposite vertices `x2` and `x3`,
+    /// as used by [`crate::energy::DiscreteShell`].
+    /// `x0 x1` follows the orientation of the triangle with `x2`.
+    pub fn hinges(&self) -> Vec<[usize; 4]> {
+        self.triangles
+            .iter()
+            .flat_map(|t| (0..3).map(move |k| (t[k], t[(k + 1) % 3], t[(k + 2) % 3])))
+            .into_group_map_by(|&(a, b, _)| (a.min(b), a.max(b)))
+            .into_iter()
+            .sorted_by_key(|(edge, _)| *edge)
+            .filter_map(|(_, sides)| match sides[..] {
+                [(a, b, c), (_, _, d)] => Some([a, b, c, d]),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Expands elements of vertex indices into `operand_indices` of an objective,
+    /// with the `dim` coordinates of each vertex in order
+    ///
+    /// ## Type Parameters
+    /// - `V`: The number of vertices per element
+    /// - `N`: The problem size of a single objective, `V * dim`
+    ///
+    /// # Panics
+    /// If `N` is not `V * dim`
+    pub fn dofs<const V: usize, const N: usize>(&self, elements: &[[usize; V]]) -> Vec<[usize; N]> {
+        dofs(elements, self.dim)
+    }
+
+    /// Reads a mesh, with the format given by the extension: `obj`, `off` or `ply`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, MeshError> {
+        let path = path.as_ref();
+        let reader = BufReader::new(File::open(path)?);
+        match extension(path)?.as_str() {
+            "obj" => obj::read(reader),
+            "off" => off::read(reader),
+            _ => ply::read(reader),
+        }
+    }
+
+    /// Writes the mesh, with the format given by the extension: `obj`, `off` or `ply`
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), MeshError> {
+        let path = path.as_ref();
+        let format = extension(path)?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        match format.as_str() {
+            "obj" => obj::write(self, &mut writer)?,
+            "off" => off::write(self, &mut writer)?,
+            _ => ply::write(self, &mut writer)?,
+        }
+        Ok(writer.flush()?)
+    }
+
+    /// Largest vertex index of all elements
+    fn max_index(&self) -> Option<usize> {
+        let triangles = self.triangles.iter().flatten();
+        let tets = self.tets.iter().flatten();
+        triangles.chain(tets).copied().max()
+    }
+}
+
+/// Expands elements of vertex indices into `operand_indices` of an objective,
+/// with the `dim` coordinates of each vertex in order
+///
+/// ## Type Parameters
+/// - `V`: The number of vertices per element
+/// - `N`: The problem size of a single objective, `V * dim`
+///
+/// ## Example
+/// ```ignore
+/// // [[0, 1, 2]] -> [[0, 1, 2, 3, 4, 5]]
+/// let operand_indices: Vec<[usize; 6]> = dofs(&triangles, 2);
+/// ```
+///
+/// # Panics
+/// If `N` is not `V * dim`
+pub fn dofs<const V: usize, const N: usize>(
+    elements: &[[usize; V]],
+    dim: usize,
+) -> Vec<[usize; N]> {
+    assert_eq!(
+        N,
+        V * dim,
+        "{} vertices in {} dimensions have {} DOFs, not {}",
+        V,
+        dim,
+        V * dim,
+        N
+    );
+
+    elements
+        .iter()
+        .map(|element| std::array::from_fn(|i| element[i / dim] * dim + i % dim))
+        .collect()
+}
+
+/// Lowercase file extension of a mesh path
+fn extension(path: &Path) -> Result<String, MeshError> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "obj" | "off" | "ply" => Ok(extension),
+        _ => Err(MeshError::Unsupported(format!(
+            "mesh file extension of {}",
+            path.display()
+        ))),
+    }
+}
+
+/// Non-empty, trimmed lines with `#` comments removed, numbered from 1
+fn content_lines(reader: impl BufRead) -> impl Iterator<Item = Result<(usize, String), MeshError>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(move |(i, line)| {
+            let line = line?;
+            let content = line.split('#').next().unwrap_or("").trim().to_string();
+            Ok((i + 1, content))
+        })
+        .filter(|line| !matches!(line, Ok((_, content)) if content.is_empty()))
+}
+
+/// Parses a token, with errors at `line`
+fn parse<T: std::str::FromStr>(
+    token: Option<&str>,
+    line: usize,
+    what: &str,
+) -> Result<T, MeshError> {
+    let token = token.ok_or_else(|| MeshError::Parse {
+        line,
+        message: format!("missing {}", what),
+    })?;
+    token.parse().map_err(|_| MeshError::Parse {
+        line,
+        message: format!("invalid {} `{}`", what, token),
+    })
+}
+
+/// Builds a 3D mesh, checking that all vertex indices are in bounds
+fn checked(
+    vertices: &[[f64; 3]],
+    triangles: Vec<[usize; 3]>,
+    tets: Vec<[usize; 4]>,
+) -> Result<Mesh, MeshError> {
+    let mesh = Mesh {
+        dim: 3,
+        positions: Col::from_fn(vertices.len() * 3, |i| vertices[i / 3][i % 3]),
+        triangles,
+        tets,
+    };
+    match mesh.max_index() {
+        Some(index) if index >= vertices.len() => Err(MeshError::IndexOutOfBounds {
+            index,
+            n_vertices: vertices.len(),
+        }),
+        _ => Ok(mesh),
+    }
+}
+
+/// Fan triangulation of a polygon
+fn triangulate(polygon: &[usize]) -> impl Iterator<Item = [usize; 3]> + '_ {
+    (1..polygon.len().saturating_sub(1)).map(|i| [polygon[0], polygon[i], polygon[i + 1]])
+}
+
+/// Writes a vertex position, separated by spaces
+fn write_vertex(writer: &mut impl Write, mesh: &Mesh, i: usize) -> io::Result<()> {
+    let [x, y, z] = mesh.vertex(i);
+    write!(writer, "{} {} {}", x, y, z)
+}
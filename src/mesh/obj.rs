@@ -0,0 +1,77 @@
+use crate::mesh::{checked, content_lines, parse, triangulate, write_vertex, Mesh, MeshError};
+use std::io::{BufRead, Write};
+
+/// Reads the vertices and faces of an OBJ file, triangulating polygons as fans.
+/// Texture coordinates, normals, groups and materials are ignored.
+pub fn read(reader: impl BufRead) -> Result<Mesh, MeshError> {
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    for line in content_lines(reader) {
+        let (line, content) = line?;
+        let mut tokens = content.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => {
+                let x = parse(tokens.next(), line, "x coordinate")?;
+                let y = parse(tokens.next(), line, "y coordinate")?;
+                let z = match tokens.next() {
+                    Some(token) => parse(Some(token), line, "z coordinate")?,
+                    None => 0.0,
+                };
+                vertices.push([x, y, z]);
+            }
+            Some("f") => {
+                let polygon = tokens
+                    .map(|token| {
+                        // `v/vt/vn`, 1-based or negative relative to the last vertex
+                        let index: isize = parse(token.split('/').next(), line, "vertex index")?;
+                        let index = if index < 0 {
+                            vertices.len() as isize + index
+                        } else {
+                            index - 1
+                        };
+                        usize::try_from(index).map_err(|_| MeshError::Parse {
+                            line,
+                            message: format!("invalid vertex index `{}`", token),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if polygon.len() < 3 {
+                    return Err(MeshError::Parse {
+                        line,
+                        message: format!("face with {} vertices", polygon.len()),
+                    });
+                }
+                triangles.extend(triangulate(&polygon));
+            }
+            _ => {}
+        }
+    }
+
+    checked(&vertices, triangles, Vec::new())
+}
+
+/// Writes the vertices and triangles as an OBJ file
+///
+/// ## Returns
+/// An error if the mesh has tetrahedra, which OBJ does not store
+pub fn write(mesh: &Mesh, writer: &mut impl Write) -> Result<(), MeshError> {
+    if !mesh.tets.is_empty() {
+        return Err(MeshError::Unsupported(
+            "tetrahedra in OBJ files".to_string(),
+        ));
+    }
+
+    for i in 0..mesh.n_vertices() {
+        write!(writer, "v ")?;
+        write_vertex(writer, mesh, i)?;
+        writeln!(writer)?;
+    }
+    for [a, b, c] in &mesh.triangles {
+        writeln!(writer, "f {} {} {}", a + 1, b + 1, c + 1)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use crate::mesh::{checked, content_lines, parse, triangulate, write_vertex, Mesh, MeshError};
+use std::io::{BufRead, Write};
+
+/// Reads an OFF file, triangulating polygons as fans. Colors are ignored.
+pub fn read(reader: impl BufRead) -> Result<Mesh, MeshError> {
+    let mut lines = content_lines(reader);
+    let mut last = 0;
+    let mut next_line = |what: &str| -> Result<(usize, String), MeshError> {
+        let (line, content) = lines.next().unwrap_or_else(|| {
+            Err(MeshError::Parse {
+                line: last + 1,
+                message: format!("unexpected end of file, expected {}", what),
+            })
+        })?;
+        last = line;
+        Ok((line, content))
+    };
+
+    let (line, header) = next_line("header")?;
+    let mut tokens = header.split_whitespace();
+    match tokens.next() {
+        Some("OFF") => {}
+        Some(keyword) if keyword.ends_with("OFF") => {
+            return Err(MeshError::Unsupported(format!("{} files", keyword)));
+        }
+        _ => {
+            return Err(MeshError::Parse {
+                line,
+                message: "missing `OFF` header".to_string(),
+            });
+        }
+    }
+
+    // The counts may follow the keyword on the same line
+    let rest = tokens.collect::<Vec<_>>().join(" ");
+    let (line, counts) = if rest.is_empty() {
+        next_line("counts")?
+    } else {
+        (line, rest)
+    };
+    let mut tokens = counts.split_whitespace();
+    let n_vertices: usize = parse(tokens.next(), line, "vertex count")?;
+    let n_faces: usize = parse(tokens.next(), line, "face count")?;
+
+    let mut vertices = Vec::with_capacity(n_vertices);
+    for _ in 0..n_vertices {
+        let (line, content) = next_line("vertex")?;
+        let mut tokens = content.split_whitespace();
+        vertices.push([
+            parse(tokens.next(), line, "x coordinate")?,
+            parse(tokens.next(), line, "y coordinate")?,
+            parse(tokens.next(), line, "z coordinate")?,
+        ]);
+    }
+
+    let mut triangles = Vec::with_capacity(n_faces);
+    for _ in 0..n_faces {
+        let (line, content) = next_line("face")?;
+        let mut tokens = content.split_whitespace();
+        let n: usize = parse(tokens.next(), line, "face vertex count")?;
+        if n < 3 {
+            return Err(MeshError::Parse {
+                line,
+                message: format!("face with {} vertices", n),
+            });
+        }
+        let polygon = (0..n)
+            .map(|_| parse(tokens.next(), line, "vertex index"))
+            .collect::<Result<Vec<usize>, _>>()?;
+        triangles.extend(triangulate(&polygon));
+    }
+
+    checked(&vertices, triangles, Vec::new())
+}
+
+/// Writes the vertices and triangles as an OFF file
+///
+/// ## Returns
+/// An error if the mesh has tetrahedra, which OFF does not store
+pub fn write(mesh: &Mesh, writer: &mut impl Write) -> Result<(), MeshError> {
+    if !mesh.tets.is_empty() {
+        return Err(MeshError::Unsupported(
+            "tetrahedra in OFF files".to_string(),
+        ));
+    }
+
+    writeln!(writer, "OFF")?;
+    writeln!(writer, "{} {} 0", mesh.n_vertices(), mesh.triangles.len())?;
+    for i in 0..mesh.n_vertices() {
+        write_vertex(writer, mesh, i)?;
+        writeln!(writer)?;
+    }
+    for [a, b, c] in &mesh.triangles {
+        writeln!(writer, "3 {} {} {}", a, b, c)?;
+    }
+
+    Ok(())
+}
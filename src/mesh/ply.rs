@@ -0,0 +1,162 @@
+use crate::mesh::{checked, parse, write_vertex, Mesh, MeshError};
+use std::io::{BufRead, Write};
+
+/// A property of a PLY element, a scalar or a list with its values inline
+enum Property {
+    Scalar(String),
+    List(String),
+}
+
+/// An element declared in a PLY header
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+/// Reads an ASCII PLY file: the `x`, `y`, `z` properties of `vertex` elements,
+/// the `vertex_indices` (or `vertex_index`) lists of `face` elements, triangulated as fans,
+/// and of `tetra` (or `tet`) elements. Other elements and properties are ignored.
+pub fn read(reader: impl BufRead) -> Result<Mesh, MeshError> {
+    let mut lines = reader
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.map(|l| (i + 1, l)));
+    let mut last = 0;
+    let mut next_line = |what: &str| -> Result<(usize, String), MeshError> {
+        let (line, content) = lines.next().transpose()?.ok_or_else(|| MeshError::Parse {
+            line: last + 1,
+            message: format!("unexpected end of file, expected {}", what),
+        })?;
+        last = line;
+        Ok((line, content.trim().to_string()))
+    };
+
+    let (line, magic) = next_line("header")?;
+    if magic != "ply" {
+        return Err(MeshError::Parse {
+            line,
+            message: "missing `ply` header".to_string(),
+        });
+    }
+
+    let mut elements: Vec<Element> = Vec::new();
+    loop {
+        let (line, content) = next_line("`end_header`")?;
+        let tokens: Vec<&str> = content.split_whitespace().collect();
+
+        match tokens[..] {
+            ["end_header"] => break,
+            ["format", "ascii", _] => {}
+            ["format", format, _] => {
+                return Err(MeshError::Unsupported(format!("PLY format `{}`", format)));
+            }
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: parse(Some(count), line, "element count")?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", _, _, name] | ["property", _, name] => {
+                let element = elements.last_mut().ok_or_else(|| MeshError::Parse {
+                    line,
+                    message: "property before any element".to_string(),
+                })?;
+                element.properties.push(match tokens[1] {
+                    "list" => Property::List(name.to_string()),
+                    _ => Property::Scalar(name.to_string()),
+                });
+            }
+            ["comment", ..] | ["obj_info", ..] | [] => {}
+            _ => {
+                return Err(MeshError::Parse {
+                    line,
+                    message: format!("invalid header line `{}`", content),
+                });
+            }
+        }
+    }
+
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+    let mut tets = Vec::new();
+
+    for element in &elements {
+        for _ in 0..element.count {
+            let (line, content) = next_line(&element.name)?;
+            let mut tokens = content.split_whitespace();
+
+            let mut position = [0.0; 3];
+            let mut indices = Vec::new();
+            for property in &element.properties {
+                match property {
+                    Property::Scalar(name) => {
+                        let value: f64 = parse(tokens.next(), line, name)?;
+                        if let Some(k) = ["x", "y", "z"].iter().position(|axis| axis == name) {
+                            position[k] = value;
+                        }
+                    }
+                    Property::List(name) => {
+                        let n: usize = parse(tokens.next(), line, "list length")?;
+                        let values = (0..n)
+                            .map(|_| parse(tokens.next(), line, name))
+                            .collect::<Result<Vec<usize>, _>>()?;
+                        if name == "vertex_indices" || name == "vertex_index" {
+                            indices = values;
+                        }
+                    }
+                }
+            }
+
+            match element.name.as_str() {
+                "vertex" => vertices.push(position),
+                "face" if indices.len() >= 3 => {
+                    triangles.extend(super::triangulate(&indices));
+                }
+                "tetra" | "tet" if indices.len() == 4 => {
+                    tets.push([indices[0], indices[1], indices[2], indices[3]]);
+                }
+                "face" | "tetra" | "tet" => {
+                    return Err(MeshError::Parse {
+                        line,
+                        message: format!("{} with {} vertices", element.name, indices.len()),
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    checked(&vertices, triangles, tets)
+}
+
+/// Writes the vertices, triangles and tetrahedra as an ASCII PLY file
+pub fn write(mesh: &Mesh, writer: &mut impl Write) -> Result<(), MeshError> {
+    writeln!(writer, "ply")?;
+    writeln!(writer, "format ascii 1.0")?;
+    writeln!(writer, "element vertex {}", mesh.n_vertices())?;
+    for axis in ["x", "y", "z"] {
+        writeln!(writer, "property double {}", axis)?;
+    }
+    if !mesh.triangles.is_empty() {
+        writeln!(writer, "element face {}", mesh.triangles.len())?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+    }
+    if !mesh.tets.is_empty() {
+        writeln!(writer, "element tetra {}", mesh.tets.len())?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+    }
+    writeln!(writer, "end_header")?;
+
+    for i in 0..mesh.n_vertices() {
+        write_vertex(writer, mesh, i)?;
+        writeln!(writer)?;
+    }
+    for [a, b, c] in &mesh.triangles {
+        writeln!(writer, "3 {} {} {}", a, b, c)?;
+    }
+    for [a, b, c, d] in &mesh.tets {
+        writeln!(writer, "4 {} {} {} {}", a, b, c, d)?;
+    }
+
+    Ok(())
+}
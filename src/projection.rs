@@ -0,0 +1,97 @@
+use crate::{
+    types::{lit, mat, Real},
+    Ad,
+};
+use na::DMatrix;
+
+/// How negative (or too small) eigenvalues are treated by [`PsdProjection`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionMode {
+    /// `λ -> max(λ, floor)`: negative curvature is discarded
+    Clamp,
+    /// `λ -> max(|λ|, floor)`: negative curvature is flipped
+    Abs,
+}
+
+/// Settings for projecting a symmetric matrix to be positive (semi-)definite
+/// by modifying its eigenvalues, as used on local Hessians of nonconvex energies.
+///
+/// ## Fields
+/// - `mode`: How eigenvalues below the floor are modified
+/// - `eigenvalue_floor`: Minimum eigenvalue after projection;
+///   `0.0` gives a positive semi-definite result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PsdProjection {
+    pub mode: ProjectionMode,
+    pub eigenvalue_floor: f64,
+}
+
+impl Default for PsdProjection {
+    fn default() -> Self {
+        Self {
+            mode: ProjectionMode::Clamp,
+            eigenvalue_floor: 0.0,
+        }
+    }
+}
+
+impl PsdProjection {
+    /// Creates a projection with the given mode and eigenvalue floor
+    ///
+    /// # Panics
+    /// If `eigenvalue_floor` is negative
+    pub fn new(mode: ProjectionMode, eigenvalue_floor: f64) -> Self {
+        assert!(
+            eigenvalue_floor >= 0.0,
+            "Eigenvalue floor must be non-negative, got {}",
+            eigenvalue_floor
+        );
+        Self {
+            mode,
+            eigenvalue_floor,
+        }
+    }
+
+    fn project_eigenvalue<T: Real>(&self, eigenvalue: T) -> T {
+        let floor = lit(self.eigenvalue_floor);
+        match self.mode {
+            ProjectionMode::Clamp => eigenvalue.max(floor),
+            ProjectionMode::Abs => eigenvalue.abs().max(floor),
+        }
+    }
+
+    /// Projects a symmetric matrix.
+    /// Matrices whose eigenvalues are all above the floor are returned unchanged.
+    ///
+    /// ## Arguments
+    /// - `matrix`: The symmetric matrix to project. Only its lower triangle is read.
+    ///
+    /// ## Returns
+    /// `Q * diag(f(λ)) * Qᵀ`, where `matrix = Q * diag(λ) * Qᵀ`
+    pub fn project<const N: usize, T: Real>(&self, matrix: &mat<N, T>) -> mat<N, T> {
+        let eigen = DMatrix::from_column_slice(N, N, matrix.as_slice()).symmetric_eigen();
+        let floor = lit(self.eigenvalue_floor);
+
+        if eigen.eigenvalues.iter().all(|&ev| ev >= floor) {
+            return *matrix;
+        }
+
+        let eigenvalues = eigen.eigenvalues.map(|ev| self.project_eigenvalue(ev));
+        let q = &eigen.eigenvectors;
+        let projected = q * DMatrix::from_diagonal(&eigenvalues) * q.transpose();
+
+        mat::from_column_slice(projected.as_slice())
+    }
+}
+
+impl<const N: usize, T: Real> Ad<N, T> {
+    /// Returns a copy of this value whose Hessian is projected by `projection`.
+    /// Value and gradient are unchanged.
+    pub fn projected(&self, projection: &PsdProjection) -> Self {
+        Self {
+            value: self.value,
+            grad: self.grad,
+            hess: projection.project(&self.hess),
+        }
+    }
+}
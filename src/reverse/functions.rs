@@ -0,0 +1,346 @@
+use crate::reverse::AdRev;
+use std::f64::consts::{LN_10, LN_2};
+
+// ################################### Unary Operators ###################################
+
+impl AdRev<'_> {
+    pub fn sqrt(&self) -> Self {
+        if self.value < -0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Sqrt on negative value!");
+        }
+        let f = self.value.sqrt();
+
+        self.chain(f, 0.5 / f, -0.25 / (f * self.value))
+    }
+
+    pub fn square(&self) -> Self {
+        self.chain(self.value * self.value, 2.0 * self.value, 2.0)
+    }
+
+    pub fn powi(&self, exponent: i32) -> Self {
+        if self.value.abs() == 0.0 && exponent == 0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("0.pow(0) is undefined!");
+        }
+        let d = if exponent == 0 {
+            0.0
+        } else {
+            exponent as f64 * self.value.powi(exponent - 1)
+        };
+        let dd = if exponent == 0 || exponent == 1 {
+            0.0
+        } else {
+            (exponent * (exponent - 1)) as f64 * self.value.powi(exponent - 2)
+        };
+
+        self.chain(self.value.powi(exponent), d, dd)
+    }
+
+    pub fn powf(&self, exponent: f64) -> Self {
+        if self.value.abs() == 0.0 && exponent.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("0.pow(0) is undefined!");
+        }
+        let d = if exponent == 0.0 {
+            0.0
+        } else {
+            exponent * self.value.powf(exponent - 1.0)
+        };
+        let dd = if exponent == 0.0 || exponent == 1.0 {
+            0.0
+        } else {
+            exponent * (exponent - 1.0) * self.value.powf(exponent - 2.0)
+        };
+
+        self.chain(self.value.powf(exponent), d, dd)
+    }
+
+    pub fn abs(&self) -> Self {
+        let sign = if self.value >= 0.0 { 1.0 } else { -1.0 };
+
+        self.chain(self.value.abs(), sign, 0.0)
+    }
+
+    pub fn exp(&self) -> Self {
+        let exp_val = self.value.exp();
+
+        self.chain(exp_val, exp_val, exp_val)
+    }
+
+    pub fn exp2(&self) -> Self {
+        let exp_val = self.value.exp2();
+
+        self.chain(exp_val, exp_val * LN_2, exp_val * LN_2 * LN_2)
+    }
+
+    pub fn ln(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Ln on non-positive value!");
+        }
+
+        let inv = 1.0 / self.value;
+
+        self.chain(self.value.ln(), inv, -inv * inv)
+    }
+
+    pub fn log(&self, base: f64) -> Self {
+        if base <= 0.0 {
+            panic!("Base must be positive!");
+        }
+        if self.value <= 0.0 {
+            panic!("Log on non-positive value!");
+        }
+
+        let d = 1.0 / (self.value * base.ln());
+
+        self.chain(self.value.log(base), d, -d / self.value)
+    }
+
+    pub fn log2(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Log2 on non-positive value!");
+        }
+
+        let d = 1.0 / (self.value * LN_2);
+
+        self.chain(self.value.log2(), d, -d / self.value)
+    }
+
+    pub fn log10(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Log10 on non-positive value!");
+        }
+
+        let d = 1.0 / (self.value * LN_10);
+
+        self.chain(self.value.log10(), d, -d / self.value)
+    }
+
+    pub fn sin(&self) -> Self {
+        let s = self.value.sin();
+
+        self.chain(s, self.value.cos(), -s)
+    }
+
+    pub fn cos(&self) -> Self {
+        let c = self.value.cos();
+
+        self.chain(c, -self.value.sin(), -c)
+    }
+
+    pub fn tan(&self) -> Self {
+        let t = self.value.tan();
+
+        let d = 1.0 + t * t;
+
+        self.chain(t, d, 2.0 * t * d)
+    }
+
+    pub fn asin(&self) -> Self {
+        if self.value < -1.0 || self.value > 1.0 {
+            panic!("Asin out of domain!");
+        }
+
+        let r = 1.0 - self.value * self.value;
+        let d = 1.0 / r.sqrt();
+
+        self.chain(self.value.asin(), d, self.value * d / r)
+    }
+
+    pub fn acos(&self) -> Self {
+        if self.value < -1.0 || self.value > 1.0 {
+            panic!("Acos out of domain!");
+        }
+
+        let r = 1.0 - self.value * self.value;
+        let d = -1.0 / r.sqrt();
+
+        self.chain(self.value.acos(), d, self.value * d / r)
+    }
+
+    #[deprecated = "Please use atan2 instead."]
+    pub fn atan(&self) -> Self {
+        let d = 1.0 / (self.value * self.value + 1.0);
+
+        self.chain(self.value.atan(), d, -2.0 * self.value * d * d)
+    }
+
+    pub fn sinh(&self) -> Self {
+        let s = self.value.sinh();
+
+        self.chain(s, self.value.cosh(), s)
+    }
+
+    pub fn cosh(&self) -> Self {
+        let c = self.value.cosh();
+
+        self.chain(c, self.value.sinh(), c)
+    }
+
+    pub fn tanh(&self) -> Self {
+        let t = self.value.tanh();
+
+        let d = 1.0 - t * t;
+
+        self.chain(t, d, -2.0 * t * d)
+    }
+
+    pub fn asinh(&self) -> Self {
+        let r = self.value * self.value + 1.0;
+        let d = 1.0 / r.sqrt();
+
+        self.chain(self.value.asinh(), d, -self.value * d / r)
+    }
+
+    pub fn acosh(&self) -> Self {
+        if self.value < 1.0 {
+            panic!("Acosh out of domain!");
+        }
+
+        let r = self.value * self.value - 1.0;
+        let d = 1.0 / r.sqrt();
+
+        self.chain(self.value.acosh(), d, -self.value * d / r)
+    }
+
+    pub fn atanh(&self) -> Self {
+        if self.value <= -1.0 || self.value >= 1.0 {
+            panic!("Atanh out of domain!");
+        }
+
+        let d = 1.0 / (1.0 - self.value * self.value);
+
+        self.chain(self.value.atanh(), d, 2.0 * self.value * d * d)
+    }
+
+    pub fn cbrt(&self) -> Self {
+        let f = self.value.cbrt();
+
+        let d = 1.0 / (3.0 * f * f);
+
+        self.chain(f, d, -2.0 * d / (3.0 * self.value))
+    }
+}
+
+// ################################### Binary Operators ###################################
+
+impl AdRev<'_> {
+    pub fn add_value(&self, other: f64) -> Self {
+        self.chain(self.value + other, 1.0, 0.0)
+    }
+
+    pub fn sub_value(&self, other: f64) -> Self {
+        self.chain(self.value - other, 1.0, 0.0)
+    }
+
+    pub fn mul_value(&self, other: f64) -> Self {
+        self.chain(self.value * other, other, 0.0)
+    }
+
+    pub fn div_value(&self, other: f64) -> Self {
+        if other.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        self.chain(self.value / other, 1.0 / other, 0.0)
+    }
+
+    pub fn recip(&self) -> Self {
+        if self.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+        let inv = 1.0 / self.value;
+
+        self.chain(inv, -inv * inv, 2.0 * inv * inv * inv)
+    }
+
+    /// ## self is y
+    pub fn atan2(&self, x: &Self) -> Self {
+        if self.value == 0.0 && x.value == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Atan2 at origin!");
+        }
+        let r = x.value * x.value + self.value * self.value;
+        let rr = r * r;
+
+        self.chain2(
+            x,
+            self.value.atan2(x.value),
+            x.value / r,
+            -self.value / r,
+            -2.0 * x.value * self.value / rr,
+            (self.value * self.value - x.value * x.value) / rr,
+            2.0 * x.value * self.value / rr,
+        )
+    }
+
+    /// `self^exponent`, differentiated w.r.t. both base and exponent.
+    /// The base must be positive.
+    pub fn pow(&self, exponent: &Self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Pow of non-positive base with active exponent!");
+        }
+        let f = self.value.powf(exponent.value);
+        let ln = self.value.ln();
+        let b = exponent.value;
+
+        self.chain2(
+            exponent,
+            f,
+            b * f / self.value,
+            f * ln,
+            b * (b - 1.0) * f / (self.value * self.value),
+            f * (1.0 + b * ln) / self.value,
+            f * ln * ln,
+        )
+    }
+
+    /// The derivatives are those of the selected operand, a valid one-sided derivative.
+    /// On ties, `other` is selected.
+    pub fn min(&self, other: &Self) -> Self {
+        if self.value < other.value {
+            *self
+        } else {
+            *other
+        }
+    }
+
+    /// The derivatives are those of the selected operand, a valid one-sided derivative.
+    /// On ties, `other` is selected.
+    pub fn max(&self, other: &Self) -> Self {
+        if self.value > other.value {
+            *self
+        } else {
+            *other
+        }
+    }
+
+    /// The derivatives are those of the selected one of `self`, `low` and `high`.
+    pub fn clamp(&self, low: &Self, high: &Self) -> Self {
+        self.max(low).min(high)
+    }
+
+    // Computes hypot(self, b) = sqrt(self^2 + b^2)
+    pub fn hypot(&self, other: &Self) -> Self {
+        let f = self.value.hypot(other.value);
+        if f == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Hypot at origin!");
+        }
+
+        let f3 = f * f * f;
+
+        self.chain2(
+            other,
+            f,
+            self.value / f,
+            other.value / f,
+            other.value * other.value / f3,
+            -self.value * other.value / f3,
+            self.value * self.value / f3,
+        )
+    }
+}
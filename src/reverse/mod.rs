@@ -0,0 +1,445 @@
+/// Elementary functions on `AdRev`.
+pub mod functions;
+pub mod operator_traits_impl;
+
+use crate::{
+    make::var,
+    sparse::objective::{ElementArgs, Objective},
+    types::advec,
+    Ad,
+};
+use faer::Col;
+use std::{cell::RefCell, fmt};
+
+// ################################### Data Structure ###################################
+
+/// Records the operations on [`AdRev`] values, to compute gradients in a backward pass
+///
+/// Every operation appends a node holding the first and second partial derivatives of its result
+/// w.r.t. its operands, so the gradient of one scalar w.r.t. any number of variables
+/// costs a single sweep over the tape, independent of the number of variables,
+/// and a Hessian-vector product costs one forward and one backward sweep.
+///
+/// Forward-mode values can be embedded as single nodes with [`Tape::ad`] and [`Tape::objective`],
+/// which keeps the tape short for objectives built from small stencils.
+/// Their local Hessian blocks are recorded as the second partials of the node.
+/// Constants are not recorded at all.
+///
+/// # Example
+/// ```ignore
+/// let tape = Tape::new();
+/// let x = tape.vars(&[1.0, 2.0, 3.0]);
+/// let loss = tape.sum(&x.iter().map(|xi| xi.sin() * xi).collect::<Vec<_>>());
+/// let grad = loss.grad(&x);
+/// ```
+#[derive(Debug, Default)]
+pub struct Tape {
+    nodes: RefCell<Nodes>,
+}
+
+/// Nodes of a tape, with the operands of node `i` in `partials[starts[i]..starts[i + 1]]`
+/// and the nonzero second partials w.r.t. pairs of operands in
+/// `seconds[second_starts[i]..second_starts[i + 1]]`, both orders of a pair included
+#[derive(Debug)]
+struct Nodes {
+    starts: Vec<usize>,
+    partials: Vec<(usize, f64)>,
+    second_starts: Vec<usize>,
+    seconds: Vec<(usize, usize, f64)>,
+}
+
+impl Default for Nodes {
+    fn default() -> Self {
+        Self {
+            starts: vec![0],
+            partials: Vec::new(),
+            second_starts: vec![0],
+            seconds: Vec::new(),
+        }
+    }
+}
+
+/// Reverse-mode automatic differentiation value, recorded on a [`Tape`]
+///
+/// Cheap to copy: it only refers to its node on the tape, or to none for constants.
+///
+/// # Value getters:
+/// - `value() -> f64`: Returns the current numerical value
+/// - `backward() -> Adjoints`: Returns the derivatives of this value w.r.t. all nodes of the tape
+/// - `hess_vec(vars, v) -> Col<f64>`: Returns the Hessian-vector product w.r.t. the variables
+#[derive(Clone, Copy)]
+pub struct AdRev<'t> {
+    pub(crate) tape: &'t Tape,
+    pub(crate) index: Option<usize>,
+    pub(crate) value: f64,
+}
+
+impl fmt::Debug for AdRev<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdRev")
+            .field("value", &self.value)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+/// Derivatives of one [`AdRev`] value w.r.t. every node of its tape,
+/// as computed by [`AdRev::backward`]
+#[derive(Debug, Clone)]
+pub struct Adjoints {
+    adjoints: Vec<f64>,
+}
+
+// ################################### Tape ###################################
+
+impl Tape {
+    /// Creates an empty tape
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of recorded nodes
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().starts.len() - 1
+    }
+
+    /// Returns whether no node has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all nodes, keeping the allocated memory for the next recording
+    pub fn clear(&mut self) {
+        let nodes = self.nodes.get_mut();
+        nodes.starts.truncate(1);
+        nodes.partials.clear();
+        nodes.second_starts.truncate(1);
+        nodes.seconds.clear();
+    }
+
+    /// Creates an independent variable
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    pub fn var(&self, value: f64) -> AdRev<'_> {
+        self.push(value, [], [])
+    }
+
+    /// Creates independent variables from a slice of values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    pub fn vars(&self, values: &[f64]) -> Vec<AdRev<'_>> {
+        values.iter().map(|&v| self.var(v)).collect()
+    }
+
+    /// Creates a constant, which records no node
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    pub fn constant(&self, value: f64) -> AdRev<'_> {
+        AdRev {
+            tape: self,
+            index: None,
+            value,
+        }
+    }
+
+    /// Records a forward-mode value computed from `inputs` as a single node
+    ///
+    /// # Arguments
+    /// * `inputs` - The values `ad` has been evaluated at, in the order of its variables
+    /// * `ad` - The forward-mode value, whose gradient and Hessian give the first and second
+    ///   partial derivatives
+    ///
+    /// # Panics
+    /// If the inputs are recorded on another tape
+    pub fn ad<const N: usize>(&self, inputs: &[AdRev<'_>; N], ad: &Ad<N>) -> AdRev<'_> {
+        inputs.iter().for_each(|input| self.check(input));
+        if inputs.iter().all(|input| input.index.is_none()) {
+            return self.constant(ad.value);
+        }
+
+        let partials = inputs
+            .iter()
+            .zip(ad.grad.iter())
+            .filter_map(|(input, &d)| Some((input.index?, d)));
+        let seconds = (0..N).flat_map(|i| {
+            (0..N).filter_map(move |j| {
+                let d = ad.hess[(i, j)];
+                Some((inputs[i].index?, inputs[j].index?, d)).filter(|_| d != 0.0)
+            })
+        });
+
+        self.push(ad.value, partials, seconds)
+    }
+
+    /// Evaluates a function of `N` inputs in forward mode and records it as a single node
+    ///
+    /// # Arguments
+    /// * `inputs` - The inputs of the function
+    /// * `f` - The function, evaluated on active forward-mode variables
+    ///
+    /// # Panics
+    /// If the inputs are recorded on another tape
+    pub fn eval<const N: usize>(
+        &self,
+        inputs: &[AdRev<'_>; N],
+        f: impl FnOnce(&advec<N, N>) -> Ad<N>,
+    ) -> AdRev<'_> {
+        let vars = var::vector_from_fn(|i| inputs[i].value);
+        self.ad(inputs, &f(&vars))
+    }
+
+    /// Records a sparse objective, every element of which is a single node
+    ///
+    /// # Arguments
+    /// * `objective` - The objective
+    /// * `x` - The full variable vector, recorded on this tape
+    /// * `operand_indices` - Slice of indices of variables to evaluate
+    /// * `args` - Additional evaluation arguments, shared or per element
+    ///
+    /// # Returns
+    /// The sum of all elements
+    ///
+    /// # Panics
+    /// If `x` is recorded on another tape
+    pub fn objective<const N: usize, O, A>(
+        &self,
+        objective: &O,
+        x: &[AdRev<'_>],
+        operand_indices: &[[usize; N]],
+        args: &A,
+    ) -> AdRev<'_>
+    where
+        O: Objective<N> + ?Sized,
+        A: ElementArgs<O::EvalArgs> + ?Sized,
+    {
+        args.check_elements(operand_indices.len());
+
+        let elements = operand_indices
+            .iter()
+            .enumerate()
+            .map(|(element, ind)| {
+                let inputs = ind.map(|i| x[i]);
+                self.eval(&inputs, |vars| objective.eval(vars, args.element(element)))
+            })
+            .collect::<Vec<_>>();
+
+        self.sum(&elements)
+    }
+
+    /// Sums values as a single node, rather than a chain of additions
+    ///
+    /// # Panics
+    /// If the values are recorded on another tape
+    pub fn sum(&self, values: &[AdRev<'_>]) -> AdRev<'_> {
+        values.iter().for_each(|v| self.check(v));
+        let value = values.iter().map(|v| v.value).sum();
+        if values.iter().all(|v| v.index.is_none()) {
+            return self.constant(value);
+        }
+
+        self.push(
+            value,
+            values.iter().filter_map(|v| Some((v.index?, 1.0))),
+            [],
+        )
+    }
+
+    /// Appends a node, given the first and nonzero second partial derivatives w.r.t. its operands
+    pub(crate) fn push(
+        &self,
+        value: f64,
+        partials: impl IntoIterator<Item = (usize, f64)>,
+        seconds: impl IntoIterator<Item = (usize, usize, f64)>,
+    ) -> AdRev<'_> {
+        let mut nodes = self.nodes.borrow_mut();
+
+        nodes.partials.extend(partials);
+        let end = nodes.partials.len();
+        nodes.starts.push(end);
+
+        nodes.seconds.extend(seconds);
+        let end = nodes.seconds.len();
+        nodes.second_starts.push(end);
+
+        AdRev {
+            tape: self,
+            index: Some(nodes.starts.len() - 2),
+            value,
+        }
+    }
+
+    fn check(&self, value: &AdRev<'_>) {
+        if !std::ptr::eq(self, value.tape) {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Values recorded on different tapes!");
+        }
+    }
+}
+
+// ################################### AdRev ###################################
+
+impl<'t> AdRev<'t> {
+    /// Returns the current value
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the tape this value is recorded on
+    pub fn tape(&self) -> &'t Tape {
+        self.tape
+    }
+
+    /// Computes the derivatives of this value w.r.t. all nodes recorded before it,
+    /// in one backward pass over the tape
+    pub fn backward(&self) -> Adjoints {
+        let nodes = self.tape.nodes.borrow();
+
+        let mut adjoints = vec![0.0; nodes.starts.len() - 1];
+        let Some(index) = self.index else {
+            return Adjoints { adjoints };
+        };
+        adjoints[index] = 1.0;
+
+        for i in (0..=index).rev() {
+            let adjoint = adjoints[i];
+            if adjoint == 0.0 {
+                continue;
+            }
+            for &(operand, partial) in &nodes.partials[nodes.starts[i]..nodes.starts[i + 1]] {
+                adjoints[operand] += adjoint * partial;
+            }
+        }
+
+        Adjoints { adjoints }
+    }
+
+    /// Computes the gradient w.r.t. the given variables, in one backward pass
+    ///
+    /// # Arguments
+    /// * `vars` - The variables, recorded on the same tape
+    pub fn grad(&self, vars: &[AdRev<'_>]) -> Col<f64> {
+        self.backward().wrt_all(vars)
+    }
+
+    /// Computes the Hessian-vector product w.r.t. the given variables,
+    /// in one forward pass for the directional derivatives of all nodes
+    /// and one backward pass for the adjoints and their directional derivatives
+    ///
+    /// The Hessian itself is never formed, only the local second partials of every node are used.
+    ///
+    /// # Arguments
+    /// * `vars` - The independent variables, recorded on the same tape with [`Tape::var`]
+    /// * `v` - The direction, with one entry per variable
+    ///
+    /// # Panics
+    /// If the variables are recorded on another tape or the direction has the wrong size
+    pub fn hess_vec(&self, vars: &[AdRev<'_>], v: &Col<f64>) -> Col<f64> {
+        if vars.len() != v.nrows() {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Direction size does not match the number of variables!");
+        }
+        vars.iter().for_each(|var| self.tape.check(var));
+
+        let nodes = self.tape.nodes.borrow();
+        let n_nodes = nodes.starts.len() - 1;
+        let Some(index) = self.index else {
+            return Col::zeros(vars.len());
+        };
+
+        let mut tangents = vec![0.0; n_nodes];
+        for (var, &vi) in vars.iter().zip(v.iter()) {
+            if let Some(i) = var.index {
+                tangents[i] += vi;
+            }
+        }
+        for i in 0..=index {
+            for &(operand, partial) in &nodes.partials[nodes.starts[i]..nodes.starts[i + 1]] {
+                tangents[i] += partial * tangents[operand];
+            }
+        }
+
+        let mut adjoints = vec![0.0; n_nodes];
+        let mut second_adjoints = vec![0.0; n_nodes];
+        adjoints[index] = 1.0;
+
+        for i in (0..=index).rev() {
+            let (adjoint, second_adjoint) = (adjoints[i], second_adjoints[i]);
+            if adjoint == 0.0 && second_adjoint == 0.0 {
+                continue;
+            }
+            for &(operand, partial) in &nodes.partials[nodes.starts[i]..nodes.starts[i + 1]] {
+                adjoints[operand] += adjoint * partial;
+                second_adjoints[operand] += second_adjoint * partial;
+            }
+            for &(a, b, second) in
+                &nodes.seconds[nodes.second_starts[i]..nodes.second_starts[i + 1]]
+            {
+                second_adjoints[a] += adjoint * second * tangents[b];
+            }
+        }
+
+        Col::from_fn(vars.len(), |i| {
+            vars[i].index.map_or(0.0, |index| second_adjoints[index])
+        })
+    }
+
+    /// Records a unary function, given its value, first and second derivative
+    pub(crate) fn chain(&self, value: f64, d: f64, dd: f64) -> Self {
+        let Some(index) = self.index else {
+            return self.tape.constant(value);
+        };
+        let seconds = Some((index, index, dd)).filter(|_| dd != 0.0);
+
+        self.tape.push(value, [(index, d)], seconds)
+    }
+
+    /// Records a binary function, given its value, first and second partial derivatives
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn chain2(
+        &self,
+        other: &AdRev<'_>,
+        value: f64,
+        da: f64,
+        db: f64,
+        daa: f64,
+        dab: f64,
+        dbb: f64,
+    ) -> Self {
+        self.tape.check(other);
+        let (a, b) = (self.index, other.index);
+        if a.is_none() && b.is_none() {
+            return self.tape.constant(value);
+        }
+
+        let partials = [(a, da), (b, db)]
+            .into_iter()
+            .filter_map(|(i, d)| Some((i?, d)));
+        let seconds = [(a, a, daa), (a, b, dab), (b, a, dab), (b, b, dbb)]
+            .into_iter()
+            .filter_map(|(i, j, d)| Some((i?, j?, d)).filter(|_| d != 0.0));
+
+        self.tape.push(value, partials, seconds)
+    }
+}
+
+// ################################### Adjoints ###################################
+
+impl Adjoints {
+    /// Returns the derivative w.r.t. a value
+    ///
+    /// Values recorded after the differentiated one and constants have zero derivatives.
+    ///
+    /// # Panics
+    /// If the value has been recorded after the backward pass
+    pub fn wrt(&self, value: &AdRev<'_>) -> f64 {
+        value.index.map_or(0.0, |index| self.adjoints[index])
+    }
+
+    /// Returns the derivatives w.r.t. several values
+    pub fn wrt_all(&self, values: &[AdRev<'_>]) -> Col<f64> {
+        Col::from_fn(values.len(), |i| self.wrt(&values[i]))
+    }
+}
@@ -0,0 +1,161 @@
+use crate::reverse::AdRev;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<'t> Neg for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn neg(self) -> AdRev<'t> {
+        self.chain(-self.value, -1.0, 0.0)
+    }
+}
+
+// ################################### T op T ###################################
+
+impl<'t> Add for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.chain2(&rhs, self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0)
+    }
+}
+
+impl<'t> Sub for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.chain2(&rhs, self.value - rhs.value, 1.0, -1.0, 0.0, 0.0, 0.0)
+    }
+}
+
+impl<'t> Mul for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.chain2(
+            &rhs,
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+        )
+    }
+}
+
+impl<'t> Div for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        self.chain2(
+            &rhs,
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+        )
+    }
+}
+
+// ################################### T op f64 ###################################
+
+impl<'t> Add<f64> for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn add(self, rhs: f64) -> Self::Output {
+        self.add_value(rhs)
+    }
+}
+
+impl<'t> Sub<f64> for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn sub(self, rhs: f64) -> Self::Output {
+        self.sub_value(rhs)
+    }
+}
+
+impl<'t> Mul<f64> for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.mul_value(rhs)
+    }
+}
+
+impl<'t> Div<f64> for AdRev<'t> {
+    type Output = AdRev<'t>;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.div_value(rhs)
+    }
+}
+
+// ################################### f64 op T ###################################
+
+impl<'t> Add<AdRev<'t>> for f64 {
+    type Output = AdRev<'t>;
+
+    fn add(self, rhs: AdRev<'t>) -> Self::Output {
+        rhs.add_value(self)
+    }
+}
+
+impl<'t> Sub<AdRev<'t>> for f64 {
+    type Output = AdRev<'t>;
+
+    fn sub(self, rhs: AdRev<'t>) -> Self::Output {
+        rhs.chain(self - rhs.value, -1.0, 0.0)
+    }
+}
+
+impl<'t> Mul<AdRev<'t>> for f64 {
+    type Output = AdRev<'t>;
+
+    fn mul(self, rhs: AdRev<'t>) -> Self::Output {
+        rhs.mul_value(self)
+    }
+}
+
+impl<'t> Div<AdRev<'t>> for f64 {
+    type Output = AdRev<'t>;
+
+    fn div(self, rhs: AdRev<'t>) -> Self::Output {
+        rhs.recip().mul_value(self)
+    }
+}
+
+// ################################### T op= T ###################################
+
+impl AddAssign for AdRev<'_> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for AdRev<'_> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for AdRev<'_> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for AdRev<'_> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
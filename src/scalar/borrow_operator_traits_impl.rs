@@ -1,9 +1,12 @@
-use crate::Ad;
+use crate::{
+    types::{lit, Real},
+    Ad,
+};
 use std::ops::{Add, Mul};
 
 // ################################### Unary Operators ###################################
 
-impl<const N: usize> Ad<N> {
+impl<const N: usize, T: Real> Ad<N, T> {
     pub fn neg(&self) -> Self {
         let mut res = Self::_zeroed();
         res.value = -self.value;
@@ -14,26 +17,32 @@ impl<const N: usize> Ad<N> {
     }
 
     pub fn sqrt(&self) -> Self {
-        if self.value < -0.0 {
+        if self.value < T::zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Sqrt on negative value!");
         }
         let f = self.value.sqrt();
 
-        Self::chain(f, 0.5 / f, -0.25 / (f * self.value), self)
+        Self::chain(
+            f,
+            lit::<T>(0.5) / f,
+            lit::<T>(-0.25) / (f * self.value),
+            self,
+        )
     }
 
     pub fn square(&self) -> Self {
         let mut res = Self::_zeroed();
         res.value = self.value * self.value;
-        res.grad = 2.0 * self.value * self.grad;
-        res.hess = 2.0 * (self.value * self.hess + self.grad * self.grad.transpose());
+        let two = lit::<T>(2.0);
+        res.grad = self.grad * (two * self.value);
+        res.hess = (self.hess * self.value + self.grad * self.grad.transpose()) * two;
 
         res
     }
 
     pub fn powi(&self, exponent: i32) -> Self {
-        if self.value.abs() == 0.0 && exponent == 0 {
+        if self.value.is_zero() && exponent == 0 {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("0.pow(0) is undefined!");
         }
@@ -43,32 +52,41 @@ impl<const N: usize> Ad<N> {
         let f = f1 * self.value;
 
         // exponent in float
-        let ef = exponent as f64;
+        let ef = lit::<T>(exponent as f64);
 
-        Self::chain(f, ef * f1, ef * (ef - 1.0) * f2, self)
+        Self::chain(f, ef * f1, ef * (ef - T::one()) * f2, self)
     }
 
-    pub fn powf(&self, exponent: f64) -> Self {
-        if self.value.abs() == 0.0 && exponent.abs() == 0.0 {
+    pub fn powf(&self, exponent: T) -> Self {
+        if self.value.is_zero() && exponent.is_zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("0.pow(0) is undefined!");
         }
 
-        let f2 = self.value.powf(exponent - 2.0);
+        let f2 = self.value.powf(exponent - lit(2.0));
         let f1 = f2 * self.value;
         let f = f1 * self.value;
 
         // exponent in float
 
-        Self::chain(f, exponent * f1, exponent * (exponent - 1.0) * f2, self)
+        Self::chain(
+            f,
+            exponent * f1,
+            exponent * (exponent - T::one()) * f2,
+            self,
+        )
     }
 
     pub fn abs(&self) -> Self {
         let mut res = Self::_zeroed();
         res.value = self.value.abs();
-        let sign = if self.value >= 0.0 { 1.0 } else { -1.0 };
-        res.grad = sign * self.grad;
-        res.hess = sign * self.hess;
+        let sign = if self.value >= T::zero() {
+            T::one()
+        } else {
+            -T::one()
+        };
+        res.grad = self.grad * sign;
+        res.hess = self.hess * sign;
 
         res
     }
@@ -80,41 +98,41 @@ impl<const N: usize> Ad<N> {
     }
 
     pub fn ln(&self) -> Self {
-        if self.value <= 0.0 {
+        if self.value <= T::zero() {
             panic!("Ln on non-positive value!");
         }
-        let inv = 1.0 / self.value;
+        let inv = self.value.recip();
 
         Self::chain(self.value.ln(), inv, -inv * inv, self)
     }
 
-    pub fn log(&self, base: f64) -> Self {
-        if self.value <= 0.0 {
+    pub fn log(&self, base: T) -> Self {
+        if self.value <= T::zero() {
             panic!("Log2 on non-positive value!");
         }
-        if base <= 0.0 {
+        if base <= T::zero() {
             panic!("Base must be positive!");
         }
 
-        let inv = 1.0 / self.value / base.ln();
+        let inv = self.value.recip() / base.ln();
 
         Self::chain(self.value.log(base), inv, -inv / self.value, self)
     }
 
     pub fn log2(&self) -> Self {
-        if self.value <= 0.0 {
+        if self.value <= T::zero() {
             panic!("Log2 on non-positive value!");
         }
-        let inv = 1.0 / self.value / std::f64::consts::LN_2;
+        let inv = self.value.recip() / T::ln_2();
 
         Self::chain(self.value.log2(), inv, -inv / self.value, self)
     }
 
     pub fn log10(&self) -> Self {
-        if self.value <= 0.0 {
+        if self.value <= T::zero() {
             panic!("Log10 on non-positive value!");
         }
-        let inv = 1.0 / self.value / std::f64::consts::LN_10;
+        let inv = self.value.recip() / T::ln_10();
 
         Self::chain(self.value.log10(), inv, -inv / self.value, self)
     }
@@ -139,37 +157,37 @@ impl<const N: usize> Ad<N> {
 
         Self::chain(
             self.value.tan(),
-            1.0 / cos_sq,
-            2.0 * self.value.sin() / (cos_sq * cos_val),
+            cos_sq.recip(),
+            lit::<T>(2.0) * self.value.sin() / (cos_sq * cos_val),
             self,
         )
     }
 
     pub fn asin(&self) -> Self {
-        if self.value < -1.0 || self.value > 1.0 {
+        if self.value < -T::one() || self.value > T::one() {
             panic!("Asin out of domain!");
         }
-        let s = 1.0 - self.value * self.value;
+        let s = T::one() - self.value * self.value;
         let s_sqrt = s.sqrt();
 
         Self::chain(
             self.value.asin(),
-            1.0 / s_sqrt,
+            s_sqrt.recip(),
             self.value / (s * s_sqrt),
             self,
         )
     }
 
     pub fn acos(&self) -> Self {
-        if self.value < -1.0 || self.value > 1.0 {
+        if self.value < -T::one() || self.value > T::one() {
             panic!("Acos out of domain!");
         }
-        let s = 1.0 - self.value * self.value;
+        let s = T::one() - self.value * self.value;
         let s_sqrt = s.sqrt();
 
         Self::chain(
             self.value.acos(),
-            -1.0 / s_sqrt,
+            -s_sqrt.recip(),
             -self.value / (s * s_sqrt),
             self,
         )
@@ -177,12 +195,12 @@ impl<const N: usize> Ad<N> {
 
     #[deprecated = "Please use atan2 instead."]
     pub fn atan(&self) -> Self {
-        let s = self.value * self.value + 1.0;
+        let s = self.value * self.value + T::one();
 
         Self::chain(
             self.value.atan(),
-            1.0 / s,
-            -2.0 * self.value / (s * s),
+            s.recip(),
+            lit::<T>(-2.0) * self.value / (s * s),
             self,
         )
     }
@@ -207,50 +225,50 @@ impl<const N: usize> Ad<N> {
 
         Self::chain(
             self.value.tanh(),
-            1.0 / cosh_sq,
-            -2.0 * self.value.sinh() / (cosh_sq * cosh_val),
+            cosh_sq.recip(),
+            lit::<T>(-2.0) * self.value.sinh() / (cosh_sq * cosh_val),
             self,
         )
     }
 
     pub fn asinh(&self) -> Self {
-        let s = self.value * self.value + 1.0;
+        let s = self.value * self.value + T::one();
         let s_sqrt = s.sqrt();
 
         Self::chain(
             self.value.asinh(),
-            1.0 / s_sqrt,
+            s_sqrt.recip(),
             -self.value / (s * s_sqrt),
             self,
         )
     }
 
     pub fn acosh(&self) -> Self {
-        if self.value < 1.0 {
+        if self.value < T::one() {
             panic!("Acosh out of domain!");
         }
-        let sm = self.value - 1.0;
-        let sp = self.value + 1.0;
+        let sm = self.value - T::one();
+        let sp = self.value + T::one();
         let prod = (sm * sp).sqrt();
 
         Self::chain(
             self.value.acosh(),
-            1.0 / prod,
+            prod.recip(),
             -self.value / (prod * sm * sp),
             self,
         )
     }
 
     pub fn atanh(&self) -> Self {
-        if self.value <= -1.0 || self.value >= 1.0 {
+        if self.value <= -T::one() || self.value >= T::one() {
             panic!("Atanh out of domain!");
         }
-        let s = 1.0 - self.value * self.value;
+        let s = T::one() - self.value * self.value;
 
         Self::chain(
             self.value.atanh(),
-            1.0 / s,
-            2.0 * self.value / (s * s),
+            s.recip(),
+            lit::<T>(2.0) * self.value / (s * s),
             self,
         )
     }
@@ -258,20 +276,20 @@ impl<const N: usize> Ad<N> {
 
 // ################################### Binary Operators ###################################
 
-impl<const N: usize> Ad<N> {
-    pub fn add_value(&self, other: f64) -> Self {
+impl<const N: usize, T: Real> Ad<N, T> {
+    pub fn add_value(&self, other: T) -> Self {
         let mut res = Self::_zeroed();
         res.value = self.value + other;
         res
     }
 
-    pub fn sub_value(&self, other: f64) -> Self {
+    pub fn sub_value(&self, other: T) -> Self {
         let mut res = Self::_zeroed();
         res.value = self.value - other;
         res
     }
 
-    pub fn mul_value(&self, other: f64) -> Self {
+    pub fn mul_value(&self, other: T) -> Self {
         let mut res = Self::_zeroed();
         res.value = self.value * other;
         res.grad = self.grad * other;
@@ -282,11 +300,11 @@ impl<const N: usize> Ad<N> {
 
     pub fn recip(&self) -> Self {
         // todo!("resolve codegen problem")
-        Ad::inactive_scalar(1.0) / self
+        Ad::inactive_scalar(T::one()) / self
     }
 
-    pub fn div_value(&self, other: f64) -> Self {
-        if other.abs() == 0.0 {
+    pub fn div_value(&self, other: T) -> Self {
+        if other.is_zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Division By Zero!");
         }
@@ -301,25 +319,89 @@ impl<const N: usize> Ad<N> {
 
     /// ## self is y
     pub fn atan2(&self, x: &Self) -> Self {
+        if self.value.is_zero() && x.value.is_zero() {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Atan2 at origin!");
+        }
+
         let mut res = Self::_zeroed();
 
         // Compute scalar value of atan2
         res.value = self.value.atan2(x.value);
 
         // Gradient computation
-        let u = x.value * &self.grad - self.value * &x.grad;
+        let u = self.grad * x.value - x.grad * self.value;
         let v = x.value * x.value + self.value * self.value;
         res.grad = &u / v;
 
         // Hessian computation (if enabled)
-        let du = x.value * &self.hess - self.value * &x.hess + &self.grad * x.grad.transpose()
-            - &x.grad * self.grad.transpose();
-        let dv = 2.0 * (x.value * &x.grad + self.value * &self.grad);
-        res.hess = (&du - &res.grad * dv.transpose()) / v;
+        let du = self.hess * x.value - x.hess * self.value + self.grad * x.grad.transpose()
+            - x.grad * self.grad.transpose();
+        let dv = (x.grad * x.value + self.grad * self.value) * lit::<T>(2.0);
+        res.hess = (du - res.grad * dv.transpose()) / v;
 
         res
     }
 
+    /// `self^exponent`, differentiated w.r.t. both base and exponent.
+    /// For a constant exponent this is `powf`, otherwise the base must be positive.
+    pub fn pow(&self, exponent: &Self) -> Self {
+        if exponent.is_constant() {
+            return self.powf(exponent.value);
+        }
+        if self.value <= T::zero() {
+            panic!("Pow of non-positive base with active exponent!");
+        }
+
+        let (a, b) = (self.value, exponent.value);
+        let ln_a = a.ln();
+        let f = a.powf(b);
+        let f_a = f / a;
+
+        Self::chain2(
+            f,
+            b * f_a,
+            f * ln_a,
+            b * (b - T::one()) * f_a / a,
+            f_a * (T::one() + b * ln_a),
+            f * ln_a * ln_a,
+            self,
+            exponent,
+        )
+    }
+
+    /// Logarithm of `self` to `base`, differentiated w.r.t. both.
+    /// For a constant base this is `log`.
+    pub fn log_base(&self, base: &Self) -> Self {
+        if base.is_constant() {
+            return self.log(base.value);
+        }
+        if self.value <= T::zero() {
+            panic!("Log on non-positive value!");
+        }
+        if base.value <= T::zero() || base.value.is_one() {
+            panic!("Base must be positive and not 1!");
+        }
+
+        let (a, b) = (self.value, base.value);
+        let ln_a = a.ln();
+        let ln_b = b.ln();
+        let f = ln_a / ln_b;
+
+        Self::chain2(
+            f,
+            (a * ln_b).recip(),
+            -f / (b * ln_b),
+            -(a * a * ln_b).recip(),
+            -(a * b * ln_b * ln_b).recip(),
+            f * (ln_b + lit(2.0)) / (b * b * ln_b * ln_b),
+            self,
+            base,
+        )
+    }
+
+    /// The derivatives are those of the selected operand, a valid one-sided derivative.
+    /// On ties, `other` is selected.
     pub fn min(&self, other: &Self) -> Self {
         if self < other {
             self.clone()
@@ -328,6 +410,8 @@ impl<const N: usize> Ad<N> {
         }
     }
 
+    /// The derivatives are those of the selected operand, a valid one-sided derivative.
+    /// On ties, `other` is selected.
     pub fn max(&self, other: &Self) -> Self {
         if self > other {
             self.clone()
@@ -336,6 +420,7 @@ impl<const N: usize> Ad<N> {
         }
     }
 
+    /// The derivatives are those of the selected one of `self`, `low` and `high`.
     pub fn clamp(&self, low: &Self, high: &Self) -> Self {
         self.max(low).min(high)
     }
@@ -1,41 +1,46 @@
 #![allow(unused)]
 
-use crate::Ad;
+use crate::{
+    types::{lit, Real},
+    Ad,
+};
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use na::{ComplexField, Field, RealField, SimdValue};
-use num_traits::FromPrimitive;
-use simba::scalar::SubsetOf;
-use std::f64::consts::LN_2;
+use num_traits::{FromPrimitive, One, Zero};
+use simba::scalar::{SubsetOf, SupersetOf};
 
 // ################################################
-// ################## Unexamined ##################
+// ################# Value-based ##################
 // ################################################
 
-impl<const N: usize> AbsDiffEq for Ad<N> {
+// Comparisons, constants and conversions only look at (or produce) values;
+// derivatives are either ignored or zero.
+
+impl<const N: usize, T: Real> AbsDiffEq for Ad<N, T> {
     type Epsilon = Self;
 
     fn default_epsilon() -> Self::Epsilon {
-        todo!()
+        Ad::inactive_scalar(T::default_epsilon())
     }
 
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        todo!()
+        self.value.abs_diff_eq(&other.value, epsilon.value)
     }
 }
 
-impl<const N: usize> UlpsEq for Ad<N> {
+impl<const N: usize, T: Real> UlpsEq for Ad<N, T> {
     fn default_max_ulps() -> u32 {
-        todo!()
+        T::default_max_ulps()
     }
 
     fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
-        todo!()
+        self.value.ulps_eq(&other.value, epsilon.value, max_ulps)
     }
 }
 
-impl<const N: usize> RelativeEq for Ad<N> {
+impl<const N: usize, T: Real> RelativeEq for Ad<N, T> {
     fn default_max_relative() -> Self::Epsilon {
-        todo!()
+        Ad::inactive_scalar(T::default_max_relative())
     }
 
     fn relative_eq(
@@ -44,13 +49,14 @@ impl<const N: usize> RelativeEq for Ad<N> {
         epsilon: Self::Epsilon,
         max_relative: Self::Epsilon,
     ) -> bool {
-        todo!()
+        self.value
+            .relative_eq(&other.value, epsilon.value, max_relative.value)
     }
 }
 
-impl<const N: usize> Field for Ad<N> {}
+impl<const N: usize, T: Real> Field for Ad<N, T> {}
 
-impl<const N: usize> SimdValue for Ad<N> {
+impl<const N: usize, T: Real> SimdValue for Ad<N, T> {
     const LANES: usize = 1;
 
     type Element = Self;
@@ -58,176 +64,189 @@ impl<const N: usize> SimdValue for Ad<N> {
     type SimdBool = bool;
 
     fn splat(val: Self::Element) -> Self {
-        todo!()
+        val
     }
 
     fn extract(&self, i: usize) -> Self::Element {
-        todo!()
+        self.clone()
     }
 
     unsafe fn extract_unchecked(&self, i: usize) -> Self::Element {
-        todo!()
+        self.clone()
     }
 
     fn replace(&mut self, i: usize, val: Self::Element) {
-        todo!()
+        *self = val
     }
 
     unsafe fn replace_unchecked(&mut self, i: usize, val: Self::Element) {
-        todo!()
+        *self = val
     }
 
     fn select(self, cond: Self::SimdBool, other: Self) -> Self {
-        todo!()
+        if cond {
+            self
+        } else {
+            other
+        }
     }
 }
 
-impl<const N: usize> FromPrimitive for Ad<N> {
+impl<const N: usize, T: Real> FromPrimitive for Ad<N, T> {
     fn from_i64(n: i64) -> Option<Self> {
-        todo!()
+        Some(Ad::inactive_scalar(lit(n as f64)))
     }
 
     fn from_u64(n: u64) -> Option<Self> {
-        todo!()
+        Some(Ad::inactive_scalar(lit(n as f64)))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Ad::inactive_scalar(lit(n)))
     }
 }
 
-impl<const N: usize> SubsetOf<Ad<N>> for Ad<N> {
-    fn to_superset(&self) -> Ad<N> {
-        todo!()
+impl<const N: usize, T: Real> SubsetOf<Ad<N, T>> for Ad<N, T> {
+    fn to_superset(&self) -> Ad<N, T> {
+        self.clone()
     }
 
-    fn from_superset_unchecked(element: &Ad<N>) -> Self {
-        todo!()
+    fn from_superset_unchecked(element: &Ad<N, T>) -> Self {
+        element.clone()
     }
 
-    fn is_in_subset(element: &Ad<N>) -> bool {
-        todo!()
+    fn is_in_subset(element: &Ad<N, T>) -> bool {
+        true
     }
 }
 
-impl<const N: usize> SubsetOf<Ad<N>> for f64 {
-    fn to_superset(&self) -> Ad<N> {
-        todo!()
+impl<const N: usize, T: Real> SubsetOf<Ad<N, T>> for f64 {
+    fn to_superset(&self) -> Ad<N, T> {
+        Ad::inactive_scalar(lit(*self))
     }
 
-    fn from_superset_unchecked(element: &Ad<N>) -> Self {
-        todo!()
+    fn from_superset_unchecked(element: &Ad<N, T>) -> Self {
+        element.value.to_subset_unchecked()
     }
 
-    fn is_in_subset(element: &Ad<N>) -> bool {
-        todo!()
+    fn is_in_subset(element: &Ad<N, T>) -> bool {
+        element.is_constant()
     }
 }
-impl<const N: usize> SubsetOf<Ad<N>> for f32 {
-    fn to_superset(&self) -> Ad<N> {
-        todo!()
+
+impl<const N: usize, T: Real> SubsetOf<Ad<N, T>> for f32 {
+    fn to_superset(&self) -> Ad<N, T> {
+        Ad::inactive_scalar(lit(*self as f64))
     }
 
-    fn from_superset_unchecked(element: &Ad<N>) -> Self {
-        todo!()
+    fn from_superset_unchecked(element: &Ad<N, T>) -> Self {
+        SupersetOf::<f64>::to_subset_unchecked(&element.value) as f32
     }
 
-    fn is_in_subset(element: &Ad<N>) -> bool {
-        todo!()
+    fn is_in_subset(element: &Ad<N, T>) -> bool {
+        element.is_constant()
     }
 }
 
-impl<const N: usize> RealField for Ad<N> {
+impl<const N: usize, T: Real> RealField for Ad<N, T> {
     fn is_sign_positive(&self) -> bool {
-        todo!()
+        self.value.is_sign_positive()
     }
 
     fn is_sign_negative(&self) -> bool {
-        todo!()
+        self.value.is_sign_negative()
     }
 
     fn copysign(self, sign: Self) -> Self {
-        todo!()
+        if self.value.is_sign_negative() == sign.value.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
     }
 
     fn max(self, other: Self) -> Self {
-        todo!()
+        Ad::max(&self, &other)
     }
 
     fn min(self, other: Self) -> Self {
-        todo!()
+        Ad::min(&self, &other)
     }
 
     fn clamp(self, min: Self, max: Self) -> Self {
-        todo!()
+        Ad::clamp(&self, &min, &max)
     }
 
     fn atan2(self, other: Self) -> Self {
-        todo!()
+        Ad::atan2(&self, &other)
     }
 
     fn min_value() -> Option<Self> {
-        todo!()
+        T::min_value().map(Ad::inactive_scalar)
     }
 
     fn max_value() -> Option<Self> {
-        todo!()
+        T::max_value().map(Ad::inactive_scalar)
     }
 
     fn pi() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::pi())
     }
 
     fn two_pi() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::two_pi())
     }
 
     fn frac_pi_2() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_pi_2())
     }
 
     fn frac_pi_3() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_pi_3())
     }
 
     fn frac_pi_4() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_pi_4())
     }
 
     fn frac_pi_6() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_pi_6())
     }
 
     fn frac_pi_8() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_pi_8())
     }
 
     fn frac_1_pi() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_1_pi())
     }
 
     fn frac_2_pi() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_2_pi())
     }
 
     fn frac_2_sqrt_pi() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::frac_2_sqrt_pi())
     }
 
     fn e() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::e())
     }
 
     fn log2_e() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::log2_e())
     }
 
     fn log10_e() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::log10_e())
     }
 
     fn ln_2() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::ln_2())
     }
 
     fn ln_10() -> Self {
-        todo!()
+        Ad::inactive_scalar(T::ln_10())
     }
 }
 
@@ -235,8 +254,8 @@ impl<const N: usize> RealField for Ad<N> {
 // ################### Examined ###################
 // ################################################
 
-impl<const N: usize> ComplexField for Ad<N> {
-    type RealField = Ad<N>;
+impl<const N: usize, T: Real> ComplexField for Ad<N, T> {
+    type RealField = Ad<N, T>;
 
     #[doc = r" Builds a pure-real complex number from the given value."]
     fn from_real(re: Self::RealField) -> Self {
@@ -314,9 +333,13 @@ impl<const N: usize> ComplexField for Ad<N> {
     fn abs(self) -> Self::RealField {
         let mut res = Self::_zeroed();
         res.value = self.value.abs();
-        let sign = if self.value >= 0.0 { 1.0 } else { -1.0 };
-        res.grad = sign * self.grad;
-        res.hess = sign * self.hess;
+        let sign = if self.value >= T::zero() {
+            T::one()
+        } else {
+            -T::one()
+        };
+        res.grad = self.grad * sign;
+        res.hess = self.hess * sign;
 
         res
     }
@@ -327,7 +350,7 @@ impl<const N: usize> ComplexField for Ad<N> {
     }
 
     fn recip(self) -> Self {
-        Ad::inactive_scalar(1.0) / self
+        Ad::inactive_scalar(T::one()) / self
     }
 
     /// Real number has itself as conjugate
@@ -350,8 +373,7 @@ impl<const N: usize> ComplexField for Ad<N> {
     }
 
     fn sin_cos(self) -> (Self, Self) {
-        // (self.sin(), self.cos())
-        todo!()
+        (Ad::sin(&self), Ad::cos(&self))
     }
 
     fn tan(self) -> Self {
@@ -360,49 +382,49 @@ impl<const N: usize> ComplexField for Ad<N> {
 
         Self::chain(
             self.value.tan(),
-            1.0 / cos_sq,
-            2.0 * self.value.sin() / (cos_sq * cos_val),
+            cos_sq.recip(),
+            lit::<T>(2.0) * self.value.sin() / (cos_sq * cos_val),
             &self,
         )
     }
 
     fn asin(self) -> Self {
-        if self.value < -1.0 || self.value > 1.0 {
+        if self.value < -T::one() || self.value > T::one() {
             panic!("Asin out of domain!");
         }
-        let s = 1.0 - self.value * self.value;
+        let s = T::one() - self.value * self.value;
         let s_sqrt = s.sqrt();
 
         Self::chain(
             self.value.asin(),
-            1.0 / s_sqrt,
+            s_sqrt.recip(),
             self.value / (s * s_sqrt),
             &self,
         )
     }
 
     fn acos(self) -> Self {
-        if self.value < -1.0 || self.value > 1.0 {
+        if self.value < -T::one() || self.value > T::one() {
             panic!("Acos out of domain!");
         }
-        let s = 1.0 - self.value * self.value;
+        let s = T::one() - self.value * self.value;
         let s_sqrt = s.sqrt();
 
         Self::chain(
             self.value.acos(),
-            -1.0 / s_sqrt,
+            -s_sqrt.recip(),
             -self.value / (s * s_sqrt),
             &self,
         )
     }
 
     fn atan(self) -> Self {
-        let s = self.value * self.value + 1.0;
+        let s = self.value * self.value + T::one();
 
         Self::chain(
             self.value.atan(),
-            1.0 / s,
-            -2.0 * self.value / (s * s),
+            s.recip(),
+            lit::<T>(-2.0) * self.value / (s * s),
             &self,
         )
     }
@@ -427,97 +449,102 @@ impl<const N: usize> ComplexField for Ad<N> {
 
         Self::chain(
             self.value.tanh(),
-            1.0 / cosh_sq,
-            -2.0 * self.value.sinh() / (cosh_sq * cosh_val),
+            cosh_sq.recip(),
+            lit::<T>(-2.0) * self.value.sinh() / (cosh_sq * cosh_val),
             &self,
         )
     }
 
     fn asinh(self) -> Self {
-        let s = self.value * self.value + 1.0;
+        let s = self.value * self.value + T::one();
         let s_sqrt = s.sqrt();
 
         Self::chain(
             self.value.asinh(),
-            1.0 / s_sqrt,
+            s_sqrt.recip(),
             -self.value / (s * s_sqrt),
             &self,
         )
     }
 
     fn acosh(self) -> Self {
-        if self.value < 1.0 {
+        if self.value < T::one() {
             panic!("Acosh out of domain!");
         }
-        let sm = self.value - 1.0;
-        let sp = self.value + 1.0;
+        let sm = self.value - T::one();
+        let sp = self.value + T::one();
         let prod = (sm * sp).sqrt();
 
         Self::chain(
             self.value.acosh(),
-            1.0 / prod,
+            prod.recip(),
             -self.value / (prod * sm * sp),
             &self,
         )
     }
 
     fn atanh(self) -> Self {
-        if self.value <= -1.0 || self.value >= 1.0 {
+        if self.value <= -T::one() || self.value >= T::one() {
             panic!("Atanh out of domain!");
         }
-        let s = 1.0 - self.value * self.value;
+        let s = T::one() - self.value * self.value;
 
         Self::chain(
             self.value.atanh(),
-            1.0 / s,
-            2.0 * self.value / (s * s),
+            s.recip(),
+            lit::<T>(2.0) * self.value / (s * s),
             &self,
         )
     }
 
     fn log(self, base: Self::RealField) -> Self {
-        unimplemented!("Differentiation w.r.t. base is not implemented...")
+        self.log_base(&base)
     }
 
     fn log2(self) -> Self {
-        if self.value <= 0.0 {
+        if self.value <= T::zero() {
             panic!("Log2 on non-positive value!");
         }
-        let inv = 1.0 / self.value / std::f64::consts::LN_2;
+        let inv = self.value.recip() / T::ln_2();
 
         Self::chain(self.value.log2(), inv, -inv / self.value, &self)
     }
 
     fn log10(self) -> Self {
-        if self.value <= 0.0 {
+        if self.value <= T::zero() {
             panic!("Log10 on non-positive value!");
         }
-        let inv = 1.0 / self.value / std::f64::consts::LN_10;
+        let inv = self.value.recip() / T::ln_10();
 
         Self::chain(self.value.log10(), inv, -inv / self.value, &self)
     }
 
     fn ln(self) -> Self {
-        if self.value <= 0.0 {
+        if self.value <= T::zero() {
             panic!("Ln on non-positive value!");
         }
-        let inv = 1.0 / self.value;
+        let inv = self.value.recip();
 
         Self::chain(self.value.ln(), inv, -inv * inv, &self)
     }
 
     fn ln_1p(self) -> Self {
-        (self + Self::inactive_scalar(1.0)).ln()
+        (self + Self::inactive_scalar(T::one())).ln()
     }
 
     fn sqrt(self) -> Self {
-        if self.value < -0.0 {
+        if self.value < T::zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Sqrt on negative value!");
         }
         let f = self.value.sqrt();
 
-        Self::chain(f, 0.5 / f, -0.25 / (f * self.value), &self)
+        Self::chain(
+            f,
+            lit::<T>(0.5) / f,
+            lit::<T>(-0.25) / (f * self.value),
+            &self,
+        )
     }
 
     fn exp(self) -> Self {
@@ -529,15 +556,17 @@ impl<const N: usize> ComplexField for Ad<N> {
     fn exp2(self) -> Self {
         let exp_val = self.value.exp2();
 
-        Self::chain(exp_val, exp_val * LN_2, exp_val * LN_2 * LN_2, &self)
+        let ln_2 = T::ln_2();
+
+        Self::chain(exp_val, exp_val * ln_2, exp_val * ln_2 * ln_2, &self)
     }
 
     fn exp_m1(self) -> Self {
-        (self - Self::inactive_scalar(1.0)).exp()
+        self.exp() - Self::inactive_scalar(T::one())
     }
 
     fn powi(self, exponent: i32) -> Self {
-        if self.value.abs() == 0.0 && exponent == 0 {
+        if self.value.is_zero() && exponent == 0 {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("0.pow(0) is undefined!");
         }
@@ -547,36 +576,37 @@ impl<const N: usize> ComplexField for Ad<N> {
         let f = f1 * self.value;
 
         // exponent in float
-        let ef = exponent as f64;
+        let ef = lit::<T>(exponent as f64);
 
-        Self::chain(f, ef * f1, ef * (ef - 1.0) * f2, &self)
+        Self::chain(f, ef * f1, ef * (ef - T::one()) * f2, &self)
     }
 
     fn powf(self, n: Self::RealField) -> Self {
-        unimplemented!("Differentiation w.r.t. power it not supported");
+        self.pow(&n)
     }
 
+    /// This is a real type, so the "complex" power is `powf`
     fn powc(self, n: Self) -> Self {
-        unimplemented!("Differentiation w.r.t. complex power it not supported");
+        self.pow(&n)
     }
 
     fn cbrt(self) -> Self {
         let f = self.value.cbrt();
 
-        let d = 1.0 / (3.0 * f * f);
-        let dd = -2.0 / (9.0 * f * f * f * self.value);
+        let d = (lit::<T>(3.0) * f * f).recip();
+        let dd = lit::<T>(-2.0) / (lit::<T>(9.0) * f * f * self.value);
 
         Self::chain(f, d, dd, &self)
     }
 
     fn is_finite(&self) -> bool {
         self.value.is_finite()
-            && self.grad.as_slice().into_iter().all(|x| x.is_finite())
-            && self.hess.as_slice().into_iter().all(|x| x.is_finite())
+            && self.grad.as_slice().iter().all(|x| x.is_finite())
+            && self.hess.as_slice().iter().all(|x| x.is_finite())
     }
 
     fn try_sqrt(self) -> Option<Self> {
-        if self.value < -0.0 {
+        if self.value < T::zero() {
             None
         } else {
             Some(self.sqrt())
@@ -1,5 +1,5 @@
 #![allow(unused)]
-use crate::Ad;
+use crate::{types::Real, Ad};
 use na::SMatrix;
 use num_traits::{Num, One, Signed, Zero};
 use std::{
@@ -8,33 +8,33 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 
-impl<const N: usize> Display for Ad<N> {
+impl<const N: usize, T: Real> Display for Ad<N, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Ad[{}]", self.value)
     }
 }
 
-impl<const N: usize> Zero for Ad<N> {
+impl<const N: usize, T: Real> Zero for Ad<N, T> {
     fn zero() -> Self {
         Ad::_zeroed()
     }
 
     fn is_zero(&self) -> bool {
-        self.value.abs() == 0.0
-            && self.grad.as_slice().into_iter().all(|&x| x.abs() == 0.0)
-            && self.hess.as_slice().into_iter().all(|&x| x.abs() == 0.0)
+        self.value.is_zero()
+            && self.grad.as_slice().iter().all(|x| x.is_zero())
+            && self.hess.as_slice().iter().all(|x| x.is_zero())
     }
 }
 
-impl<const N: usize> One for Ad<N> {
+impl<const N: usize, T: Real> One for Ad<N, T> {
     fn one() -> Self {
         let mut res = Ad::_zeroed();
-        res.value = 1.0;
+        res.value = T::one();
         res
     }
 }
 
-impl<const N: usize> Num for Ad<N> {
+impl<const N: usize, T: Real> Num for Ad<N, T> {
     type FromStrRadixErr = ();
 
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
@@ -42,13 +42,17 @@ impl<const N: usize> Num for Ad<N> {
     }
 }
 
-impl<const N: usize> Signed for Ad<N> {
+impl<const N: usize, T: Real> Signed for Ad<N, T> {
     fn abs(&self) -> Self {
         let mut res = Self::_zeroed();
         res.value = self.value.abs();
-        let sign = if self.value >= 0.0 { 1.0 } else { -1.0 };
-        res.grad = sign * self.grad;
-        res.hess = sign * self.hess;
+        let sign = if self.value >= T::zero() {
+            T::one()
+        } else {
+            -T::one()
+        };
+        res.grad = self.grad * sign;
+        res.hess = self.hess * sign;
 
         res
     }
@@ -62,11 +66,11 @@ impl<const N: usize> Signed for Ad<N> {
     }
 
     fn is_positive(&self) -> bool {
-        self.value > 0.0
+        self.value > T::zero()
     }
 
     fn is_negative(&self) -> bool {
-        self.value < -0.0
+        self.value < T::zero()
     }
 }
 
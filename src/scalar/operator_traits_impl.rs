@@ -1,22 +1,23 @@
 /*
 
-This code is generated by meta/operators.py at 12:19:50 @ 2025.01.08
+This code is generated by meta/operators.py at 12:07:05 @ 2026.10.18
 Do not modify it directly.
 
 */
 
 #![allow(unused)]
 
-use crate::Ad;
+use crate::{types::Real, Ad};
+use num_traits::Zero;
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
-impl<const N: usize> Neg for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Neg for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn neg(self) -> Ad<N> {
-        let mut res = Ad::<N>::_zeroed();
+    fn neg(self) -> Ad<N, T> {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = -self.value;
         res.grad = -self.grad;
         res.hess = -self.hess;
@@ -25,11 +26,11 @@ impl<const N: usize> Neg for &Ad<N> {
     }
 }
 
-impl<const N: usize> Neg for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Neg for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn neg(self) -> Ad<N> {
-        let mut res = Ad::<N>::_zeroed();
+    fn neg(self) -> Ad<N, T> {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = -self.value;
         res.grad = -self.grad;
         res.hess = -self.hess;
@@ -39,11 +40,11 @@ impl<const N: usize> Neg for Ad<N> {
 }
 
 // &T + &T
-impl<const N: usize> Add<&Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Add<&Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn add(self, rhs: &Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn add(self, rhs: &Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value + rhs.value;
         res.grad = self.grad + rhs.grad;
         res.hess = self.hess + rhs.hess;
@@ -53,11 +54,11 @@ impl<const N: usize> Add<&Ad<N>> for &Ad<N> {
 }
 
 // &T + T
-impl<const N: usize> Add<Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Add<Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn add(self, rhs: Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn add(self, rhs: Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value + rhs.value;
         res.grad = self.grad + rhs.grad;
         res.hess = self.hess + rhs.hess;
@@ -67,11 +68,11 @@ impl<const N: usize> Add<Ad<N>> for &Ad<N> {
 }
 
 // T + &T
-impl<const N: usize> Add<&Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Add<&Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn add(self, rhs: &Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn add(self, rhs: &Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value + rhs.value;
         res.grad = self.grad + rhs.grad;
         res.hess = self.hess + rhs.hess;
@@ -81,11 +82,11 @@ impl<const N: usize> Add<&Ad<N>> for Ad<N> {
 }
 
 // T + T
-impl<const N: usize> Add<Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Add<Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn add(self, rhs: Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn add(self, rhs: Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value + rhs.value;
         res.grad = self.grad + rhs.grad;
         res.hess = self.hess + rhs.hess;
@@ -95,11 +96,11 @@ impl<const N: usize> Add<Ad<N>> for Ad<N> {
 }
 
 // &T - &T
-impl<const N: usize> Sub<&Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Sub<&Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn sub(self, rhs: &Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn sub(self, rhs: &Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value - rhs.value;
         res.grad = self.grad - rhs.grad;
         res.hess = self.hess - rhs.hess;
@@ -109,11 +110,11 @@ impl<const N: usize> Sub<&Ad<N>> for &Ad<N> {
 }
 
 // &T - T
-impl<const N: usize> Sub<Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Sub<Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn sub(self, rhs: Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn sub(self, rhs: Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value - rhs.value;
         res.grad = self.grad - rhs.grad;
         res.hess = self.hess - rhs.hess;
@@ -123,11 +124,11 @@ impl<const N: usize> Sub<Ad<N>> for &Ad<N> {
 }
 
 // T - &T
-impl<const N: usize> Sub<&Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Sub<&Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn sub(self, rhs: &Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn sub(self, rhs: &Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value - rhs.value;
         res.grad = self.grad - rhs.grad;
         res.hess = self.hess - rhs.hess;
@@ -137,11 +138,11 @@ impl<const N: usize> Sub<&Ad<N>> for Ad<N> {
 }
 
 // T - T
-impl<const N: usize> Sub<Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Sub<Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn sub(self, rhs: Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn sub(self, rhs: Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value - rhs.value;
         res.grad = self.grad - rhs.grad;
         res.hess = self.hess - rhs.hess;
@@ -151,16 +152,16 @@ impl<const N: usize> Sub<Ad<N>> for Ad<N> {
 }
 
 // &T * &T
-impl<const N: usize> Mul<&Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Mul<&Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn mul(self, rhs: &Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn mul(self, rhs: &Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
 
         res.value = self.value * rhs.value;
-        res.grad = self.grad * rhs.value + self.value * rhs.grad;
-        res.hess = rhs.value * self.hess
-            + self.value * rhs.hess
+        res.grad = self.grad * rhs.value + rhs.grad * self.value;
+        res.hess = self.hess * rhs.value
+            + rhs.hess * self.value
             + self.grad * rhs.grad.transpose()
             + rhs.grad * self.grad.transpose();
 
@@ -169,16 +170,16 @@ impl<const N: usize> Mul<&Ad<N>> for &Ad<N> {
 }
 
 // &T * T
-impl<const N: usize> Mul<Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Mul<Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn mul(self, rhs: Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn mul(self, rhs: Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
 
         res.value = self.value * rhs.value;
-        res.grad = self.grad * rhs.value + self.value * rhs.grad;
-        res.hess = rhs.value * self.hess
-            + self.value * rhs.hess
+        res.grad = self.grad * rhs.value + rhs.grad * self.value;
+        res.hess = self.hess * rhs.value
+            + rhs.hess * self.value
             + self.grad * rhs.grad.transpose()
             + rhs.grad * self.grad.transpose();
 
@@ -187,16 +188,16 @@ impl<const N: usize> Mul<Ad<N>> for &Ad<N> {
 }
 
 // T * &T
-impl<const N: usize> Mul<&Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Mul<&Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn mul(self, rhs: &Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn mul(self, rhs: &Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
 
         res.value = self.value * rhs.value;
-        res.grad = self.grad * rhs.value + self.value * rhs.grad;
-        res.hess = rhs.value * self.hess
-            + self.value * rhs.hess
+        res.grad = self.grad * rhs.value + rhs.grad * self.value;
+        res.hess = self.hess * rhs.value
+            + rhs.hess * self.value
             + self.grad * rhs.grad.transpose()
             + rhs.grad * self.grad.transpose();
 
@@ -205,16 +206,16 @@ impl<const N: usize> Mul<&Ad<N>> for Ad<N> {
 }
 
 // T * T
-impl<const N: usize> Mul<Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Mul<Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn mul(self, rhs: Ad<N>) -> Self::Output {
-        let mut res = Ad::<N>::_zeroed();
+    fn mul(self, rhs: Ad<N, T>) -> Self::Output {
+        let mut res = Ad::<N, T>::_zeroed();
 
         res.value = self.value * rhs.value;
-        res.grad = self.grad * rhs.value + self.value * rhs.grad;
-        res.hess = rhs.value * self.hess
-            + self.value * rhs.hess
+        res.grad = self.grad * rhs.value + rhs.grad * self.value;
+        res.hess = self.hess * rhs.value
+            + rhs.hess * self.value
             + self.grad * rhs.grad.transpose()
             + rhs.grad * self.grad.transpose();
 
@@ -223,22 +224,22 @@ impl<const N: usize> Mul<Ad<N>> for Ad<N> {
 }
 
 // &T / &T
-impl<const N: usize> Div<&Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Div<&Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn div(self, rhs: &Ad<N>) -> Self::Output {
-        if rhs.value.abs() == 0.0 {
+    fn div(self, rhs: &Ad<N, T>) -> Self::Output {
+        if rhs.value.is_zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Division By Zero!");
         }
 
-        let mut res = Ad::<N>::_zeroed();
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value / rhs.value;
-        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+        res.grad = (self.grad * rhs.value - rhs.grad * self.value) / (rhs.value * rhs.value);
         res.hess = (self.hess
             - res.grad * rhs.grad.transpose()
             - rhs.grad * res.grad.transpose()
-            - res.value * rhs.hess)
+            - rhs.hess * res.value)
             / rhs.value;
 
         res
@@ -246,22 +247,22 @@ impl<const N: usize> Div<&Ad<N>> for &Ad<N> {
 }
 
 // &T / T
-impl<const N: usize> Div<Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Div<Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn div(self, rhs: Ad<N>) -> Self::Output {
-        if rhs.value.abs() == 0.0 {
+    fn div(self, rhs: Ad<N, T>) -> Self::Output {
+        if rhs.value.is_zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Division By Zero!");
         }
 
-        let mut res = Ad::<N>::_zeroed();
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value / rhs.value;
-        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+        res.grad = (self.grad * rhs.value - rhs.grad * self.value) / (rhs.value * rhs.value);
         res.hess = (self.hess
             - res.grad * rhs.grad.transpose()
             - rhs.grad * res.grad.transpose()
-            - res.value * rhs.hess)
+            - rhs.hess * res.value)
             / rhs.value;
 
         res
@@ -269,22 +270,22 @@ impl<const N: usize> Div<Ad<N>> for &Ad<N> {
 }
 
 // T / &T
-impl<const N: usize> Div<&Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Div<&Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn div(self, rhs: &Ad<N>) -> Self::Output {
-        if rhs.value.abs() == 0.0 {
+    fn div(self, rhs: &Ad<N, T>) -> Self::Output {
+        if rhs.value.is_zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Division By Zero!");
         }
 
-        let mut res = Ad::<N>::_zeroed();
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value / rhs.value;
-        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+        res.grad = (self.grad * rhs.value - rhs.grad * self.value) / (rhs.value * rhs.value);
         res.hess = (self.hess
             - res.grad * rhs.grad.transpose()
             - rhs.grad * res.grad.transpose()
-            - res.value * rhs.hess)
+            - rhs.hess * res.value)
             / rhs.value;
 
         res
@@ -292,22 +293,22 @@ impl<const N: usize> Div<&Ad<N>> for Ad<N> {
 }
 
 // T / T
-impl<const N: usize> Div<Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Div<Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn div(self, rhs: Ad<N>) -> Self::Output {
-        if rhs.value.abs() == 0.0 {
+    fn div(self, rhs: Ad<N, T>) -> Self::Output {
+        if rhs.value.is_zero() {
             // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
             panic!("Division By Zero!");
         }
 
-        let mut res = Ad::<N>::_zeroed();
+        let mut res = Ad::<N, T>::_zeroed();
         res.value = self.value / rhs.value;
-        res.grad = (rhs.value * self.grad - self.value * rhs.grad) / (rhs.value * rhs.value);
+        res.grad = (self.grad * rhs.value - rhs.grad * self.value) / (rhs.value * rhs.value);
         res.hess = (self.hess
             - res.grad * rhs.grad.transpose()
             - rhs.grad * res.grad.transpose()
-            - res.value * rhs.hess)
+            - rhs.hess * res.value)
             / rhs.value;
 
         res
@@ -315,107 +316,107 @@ impl<const N: usize> Div<Ad<N>> for Ad<N> {
 }
 
 // &T % &T
-impl<const N: usize> Rem<&Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Rem<&Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn rem(self, rhs: &Ad<N>) -> Self::Output {
+    fn rem(self, rhs: &Ad<N, T>) -> Self::Output {
         unimplemented!();
     }
 }
 
 // &T % T
-impl<const N: usize> Rem<Ad<N>> for &Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Rem<Ad<N, T>> for &Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn rem(self, rhs: Ad<N>) -> Self::Output {
+    fn rem(self, rhs: Ad<N, T>) -> Self::Output {
         unimplemented!();
     }
 }
 
 // T % &T
-impl<const N: usize> Rem<&Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Rem<&Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn rem(self, rhs: &Ad<N>) -> Self::Output {
+    fn rem(self, rhs: &Ad<N, T>) -> Self::Output {
         unimplemented!();
     }
 }
 
 // T % T
-impl<const N: usize> Rem<Ad<N>> for Ad<N> {
-    type Output = Ad<N>;
+impl<const N: usize, T: Real> Rem<Ad<N, T>> for Ad<N, T> {
+    type Output = Ad<N, T>;
 
-    fn rem(self, rhs: Ad<N>) -> Self::Output {
+    fn rem(self, rhs: Ad<N, T>) -> Self::Output {
         unimplemented!();
     }
 }
 
 // T += &T
-impl<const N: usize> AddAssign<&Ad<N>> for Ad<N> {
-    fn add_assign(&mut self, rhs: &Ad<N>) {
+impl<const N: usize, T: Real> AddAssign<&Ad<N, T>> for Ad<N, T> {
+    fn add_assign(&mut self, rhs: &Ad<N, T>) {
         *self = &*self + rhs;
     }
 }
 
 // T += T
-impl<const N: usize> AddAssign<Ad<N>> for Ad<N> {
-    fn add_assign(&mut self, rhs: Ad<N>) {
+impl<const N: usize, T: Real> AddAssign<Ad<N, T>> for Ad<N, T> {
+    fn add_assign(&mut self, rhs: Ad<N, T>) {
         *self = &*self + rhs;
     }
 }
 
 // T -= &T
-impl<const N: usize> SubAssign<&Ad<N>> for Ad<N> {
-    fn sub_assign(&mut self, rhs: &Ad<N>) {
+impl<const N: usize, T: Real> SubAssign<&Ad<N, T>> for Ad<N, T> {
+    fn sub_assign(&mut self, rhs: &Ad<N, T>) {
         *self = &*self - rhs;
     }
 }
 
 // T -= T
-impl<const N: usize> SubAssign<Ad<N>> for Ad<N> {
-    fn sub_assign(&mut self, rhs: Ad<N>) {
+impl<const N: usize, T: Real> SubAssign<Ad<N, T>> for Ad<N, T> {
+    fn sub_assign(&mut self, rhs: Ad<N, T>) {
         *self = &*self - rhs;
     }
 }
 
 // T *= &T
-impl<const N: usize> MulAssign<&Ad<N>> for Ad<N> {
-    fn mul_assign(&mut self, rhs: &Ad<N>) {
+impl<const N: usize, T: Real> MulAssign<&Ad<N, T>> for Ad<N, T> {
+    fn mul_assign(&mut self, rhs: &Ad<N, T>) {
         *self = &*self * rhs;
     }
 }
 
 // T *= T
-impl<const N: usize> MulAssign<Ad<N>> for Ad<N> {
-    fn mul_assign(&mut self, rhs: Ad<N>) {
+impl<const N: usize, T: Real> MulAssign<Ad<N, T>> for Ad<N, T> {
+    fn mul_assign(&mut self, rhs: Ad<N, T>) {
         *self = &*self * rhs;
     }
 }
 
 // T /= &T
-impl<const N: usize> DivAssign<&Ad<N>> for Ad<N> {
-    fn div_assign(&mut self, rhs: &Ad<N>) {
+impl<const N: usize, T: Real> DivAssign<&Ad<N, T>> for Ad<N, T> {
+    fn div_assign(&mut self, rhs: &Ad<N, T>) {
         *self = &*self / rhs;
     }
 }
 
 // T /= T
-impl<const N: usize> DivAssign<Ad<N>> for Ad<N> {
-    fn div_assign(&mut self, rhs: Ad<N>) {
+impl<const N: usize, T: Real> DivAssign<Ad<N, T>> for Ad<N, T> {
+    fn div_assign(&mut self, rhs: Ad<N, T>) {
         *self = &*self / rhs;
     }
 }
 
 // T %= &T
-impl<const N: usize> RemAssign<&Ad<N>> for Ad<N> {
-    fn rem_assign(&mut self, rhs: &Ad<N>) {
+impl<const N: usize, T: Real> RemAssign<&Ad<N, T>> for Ad<N, T> {
+    fn rem_assign(&mut self, rhs: &Ad<N, T>) {
         unimplemented!();
     }
 }
 
 // T %= T
-impl<const N: usize> RemAssign<Ad<N>> for Ad<N> {
-    fn rem_assign(&mut self, rhs: Ad<N>) {
+impl<const N: usize, T: Real> RemAssign<Ad<N, T>> for Ad<N, T> {
+    fn rem_assign(&mut self, rhs: Ad<N, T>) {
         unimplemented!();
     }
 }
@@ -1,41 +1,49 @@
 /*
 
-This code is generated by meta/scalar_matrix_mul.py at 12:19:50 @ 2025.01.08
+This code is generated by meta/scalar_matrix_mul.py at 12:07:05 @ 2026.10.18
 Do not modify it directly.
 
 */
-use crate::Ad;
+use crate::{types::Real, Ad};
 use na::SMatrix;
 use std::ops::Mul;
 
-impl<const N: usize, const R: usize, const C: usize> Mul<SMatrix<Ad<N>, R, C>> for Ad<N> {
-    type Output = SMatrix<Ad<N>, R, C>;
+impl<const N: usize, const R: usize, const C: usize, T: Real> Mul<SMatrix<Ad<N, T>, R, C>>
+    for Ad<N, T>
+{
+    type Output = SMatrix<Ad<N, T>, R, C>;
 
-    fn mul(self, rhs: SMatrix<Ad<N>, R, C>) -> Self::Output {
+    fn mul(self, rhs: SMatrix<Ad<N, T>, R, C>) -> Self::Output {
         rhs * self
     }
 }
 
-impl<const N: usize, const R: usize, const C: usize> Mul<SMatrix<Ad<N>, R, C>> for &Ad<N> {
-    type Output = SMatrix<Ad<N>, R, C>;
+impl<const N: usize, const R: usize, const C: usize, T: Real> Mul<SMatrix<Ad<N, T>, R, C>>
+    for &Ad<N, T>
+{
+    type Output = SMatrix<Ad<N, T>, R, C>;
 
-    fn mul(self, rhs: SMatrix<Ad<N>, R, C>) -> Self::Output {
+    fn mul(self, rhs: SMatrix<Ad<N, T>, R, C>) -> Self::Output {
         rhs * self.clone()
     }
 }
 
-impl<const N: usize, const R: usize, const C: usize> Mul<&SMatrix<Ad<N>, R, C>> for Ad<N> {
-    type Output = SMatrix<Ad<N>, R, C>;
+impl<const N: usize, const R: usize, const C: usize, T: Real> Mul<&SMatrix<Ad<N, T>, R, C>>
+    for Ad<N, T>
+{
+    type Output = SMatrix<Ad<N, T>, R, C>;
 
-    fn mul(self, rhs: &SMatrix<Ad<N>, R, C>) -> Self::Output {
+    fn mul(self, rhs: &SMatrix<Ad<N, T>, R, C>) -> Self::Output {
         rhs * self
     }
 }
 
-impl<const N: usize, const R: usize, const C: usize> Mul<&SMatrix<Ad<N>, R, C>> for &Ad<N> {
-    type Output = SMatrix<Ad<N>, R, C>;
+impl<const N: usize, const R: usize, const C: usize, T: Real> Mul<&SMatrix<Ad<N, T>, R, C>>
+    for &Ad<N, T>
+{
+    type Output = SMatrix<Ad<N, T>, R, C>;
 
-    fn mul(self, rhs: &SMatrix<Ad<N>, R, C>) -> Self::Output {
+    fn mul(self, rhs: &SMatrix<Ad<N, T>, R, C>) -> Self::Output {
         rhs * self.clone()
     }
 }
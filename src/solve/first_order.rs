@@ -0,0 +1,399 @@
+use crate::solve::{
+    line_search::{dot, LinePoint, StrongWolfe},
+    Problem, Termination,
+};
+use faer::Col;
+use std::{collections::VecDeque, ops::ControlFlow};
+
+/// Limited-memory BFGS, approximating the inverse Hessian from the latest
+/// `history` steps and gradient changes, with a strong Wolfe line search.
+///
+/// Only values and gradients are evaluated, so it suits problems whose Hessians
+/// are too expensive or indefinite.
+///
+/// ## Fields
+/// - `tolerance`: Convergence threshold on the L2 norm of the gradient
+/// - `max_iterations`: Maximum number of iterations
+/// - `history`: Number of stored step and gradient change pairs
+/// - `line_search`: The line search, whose curvature condition keeps the approximation
+///   positive definite
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LbfgsSolver {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub history: usize,
+    pub line_search: StrongWolfe,
+}
+
+impl Default for LbfgsSolver {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-8,
+            max_iterations: 1000,
+            history: 10,
+            line_search: StrongWolfe::default(),
+        }
+    }
+}
+
+/// Nonlinear conjugate gradient method with the Polak–Ribière+ update,
+/// `β = max(0, gₖ₊₁ᵀ(gₖ₊₁ - gₖ) / |gₖ|²)`, and a strong Wolfe line search.
+///
+/// Clamping `β` at zero restarts from the steepest descent direction when
+/// the conjugacy is lost. Only values and gradients are evaluated.
+///
+/// ## Fields
+/// - `tolerance`: Convergence threshold on the L2 norm of the gradient
+/// - `max_iterations`: Maximum number of iterations
+/// - `line_search`: The line search. A small curvature constant `c2` keeps the
+///   directions descent directions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConjugateGradientSolver {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub line_search: StrongWolfe,
+}
+
+impl Default for ConjugateGradientSolver {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-8,
+            max_iterations: 5000,
+            line_search: StrongWolfe {
+                c2: 0.1,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Summary of a single iteration of [`LbfgsSolver`] or [`ConjugateGradientSolver`]
+///
+/// ## Fields
+/// - `iteration`: Index of the iteration, starting at 0
+/// - `value`: Value at the start of the iteration
+/// - `grad_norm`: L2 norm of the gradient at the start of the iteration
+/// - `step_size`: Accepted line search step size `t`
+/// - `step_norm`: L2 norm of the accepted step `t * d`
+/// - `evaluations`: Number of value and gradient evaluations of the line search
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimizeReport {
+    pub iteration: usize,
+    pub value: f64,
+    pub grad_norm: f64,
+    pub step_size: f64,
+    pub step_norm: f64,
+    pub evaluations: usize,
+}
+
+/// Result of [`LbfgsSolver::solve`] and [`ConjugateGradientSolver::solve`]
+///
+/// ## Fields
+/// - `x`: The final iterate, the minimizer if `termination` is `Converged`
+/// - `value`: Value at `x`
+/// - `grad_norm`: L2 norm of the gradient at `x`
+/// - `termination`: Why the solver stopped
+/// - `reports`: One report per completed iteration
+#[derive(Debug, Clone)]
+pub struct MinimizeResult {
+    pub x: Col<f64>,
+    pub value: f64,
+    pub grad_norm: f64,
+    pub termination: Termination,
+    pub reports: Vec<MinimizeReport>,
+}
+
+impl MinimizeResult {
+    /// Returns whether the solver converged
+    pub fn converged(&self) -> bool {
+        self.termination == Termination::Converged
+    }
+}
+
+impl LbfgsSolver {
+    /// Minimizes `problem`
+    ///
+    /// ## Arguments
+    /// - `problem`: The function to minimize, of which only values and gradients are used
+    /// - `x0`: The initial guess
+    ///
+    /// ## Returns
+    /// The final iterate together with a report of every iteration
+    pub fn solve<P: Problem + ?Sized>(&self, problem: &P, x0: &Col<f64>) -> MinimizeResult {
+        self.solve_with_callback(problem, x0, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Minimizes `problem`, calling `callback` with the report and the new iterate
+    /// after every iteration
+    ///
+    /// ## Arguments
+    /// - `problem`: The function to minimize, of which only values and gradients are used
+    /// - `x0`: The initial guess
+    /// - `callback`: Returns `ControlFlow::Break(())` to stop with `Termination::Stopped`
+    ///
+    /// ## Returns
+    /// The final iterate together with a report of every iteration
+    pub fn solve_with_callback<P: Problem + ?Sized>(
+        &self,
+        problem: &P,
+        x0: &Col<f64>,
+        callback: impl FnMut(&MinimizeReport, &Col<f64>) -> ControlFlow<()>,
+    ) -> MinimizeResult {
+        let mut directions = Lbfgs {
+            history: self.history,
+            pairs: VecDeque::with_capacity(self.history),
+        };
+
+        minimize(
+            problem,
+            x0,
+            self.tolerance,
+            self.max_iterations,
+            &self.line_search,
+            &mut directions,
+            callback,
+        )
+    }
+}
+
+impl ConjugateGradientSolver {
+    /// Minimizes `problem`
+    ///
+    /// ## Arguments
+    /// - `problem`: The function to minimize, of which only values and gradients are used
+    /// - `x0`: The initial guess
+    ///
+    /// ## Returns
+    /// The final iterate together with a report of every iteration
+    pub fn solve<P: Problem + ?Sized>(&self, problem: &P, x0: &Col<f64>) -> MinimizeResult {
+        self.solve_with_callback(problem, x0, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Minimizes `problem`, calling `callback` with the report and the new iterate
+    /// after every iteration
+    ///
+    /// ## Arguments
+    /// - `problem`: The function to minimize, of which only values and gradients are used
+    /// - `x0`: The initial guess
+    /// - `callback`: Returns `ControlFlow::Break(())` to stop with `Termination::Stopped`
+    ///
+    /// ## Returns
+    /// The final iterate together with a report of every iteration
+    pub fn solve_with_callback<P: Problem + ?Sized>(
+        &self,
+        problem: &P,
+        x0: &Col<f64>,
+        callback: impl FnMut(&MinimizeReport, &Col<f64>) -> ControlFlow<()>,
+    ) -> MinimizeResult {
+        let mut directions = PolakRibiere { previous: None };
+
+        minimize(
+            problem,
+            x0,
+            self.tolerance,
+            self.max_iterations,
+            &self.line_search,
+            &mut directions,
+            callback,
+        )
+    }
+}
+
+/// Search directions of a line search method
+trait Directions {
+    /// Returns the search direction at `current`
+    fn direction(&mut self, current: &LinePoint) -> Col<f64>;
+
+    /// Returns the step size the line search starts with
+    fn initial_step(&self, current: &LinePoint) -> f64;
+
+    /// Records the accepted step from `previous` to `current` along `dir`
+    fn update(&mut self, previous: &LinePoint, current: &LinePoint, dir: &Col<f64>);
+
+    /// Discards the accumulated information, after an ascent direction
+    fn reset(&mut self);
+}
+
+/// Line search loop shared by the solvers
+fn minimize<P: Problem + ?Sized, D: Directions>(
+    problem: &P,
+    x0: &Col<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+    line_search: &StrongWolfe,
+    directions: &mut D,
+    mut callback: impl FnMut(&MinimizeReport, &Col<f64>) -> ControlFlow<()>,
+) -> MinimizeResult {
+    let (value, grad) = problem.value_grad(x0);
+    let mut current = LinePoint {
+        step: 0.0,
+        x: x0.clone(),
+        value,
+        grad,
+        slope: 0.0,
+    };
+    let mut reports = Vec::new();
+
+    let finish = |current: LinePoint, termination, reports| MinimizeResult {
+        grad_norm: current.grad.norm_l2(),
+        x: current.x,
+        value: current.value,
+        termination,
+        reports,
+    };
+
+    for iteration in 0..max_iterations {
+        let grad_norm = current.grad.norm_l2();
+        if grad_norm <= tolerance {
+            return finish(current, Termination::Converged, reports);
+        }
+
+        let mut dir = directions.direction(&current);
+        current.step = 0.0;
+        current.slope = dot(&current.grad, &dir);
+        if current.slope.is_nan() || current.slope >= 0.0 {
+            directions.reset();
+            dir = -&current.grad;
+            current.slope = -grad_norm * grad_norm;
+        }
+
+        let initial_step = directions.initial_step(&current);
+        let Some(accepted) = line_search.search(problem, &current, &dir, initial_step) else {
+            return finish(current, Termination::LineSearchFailed, reports);
+        };
+        let next = accepted.point;
+
+        let report = MinimizeReport {
+            iteration,
+            value: current.value,
+            grad_norm,
+            step_size: next.step,
+            step_norm: next.step * dir.norm_l2(),
+            evaluations: accepted.evaluations,
+        };
+        reports.push(report);
+
+        directions.update(&current, &next, &dir);
+        current = next;
+
+        if callback(&report, &current.x).is_break() {
+            return finish(current, Termination::Stopped, reports);
+        }
+    }
+
+    let termination = if current.grad.norm_l2() <= tolerance {
+        Termination::Converged
+    } else {
+        Termination::MaxIterations
+    };
+
+    finish(current, termination, reports)
+}
+
+/// Initial step of the first iteration, a unit step for normalized gradients
+fn first_step(current: &LinePoint) -> f64 {
+    f64::min(1.0, 1.0 / current.grad.norm_l2())
+}
+
+/// Two-loop recursion over the latest step and gradient change pairs
+struct Lbfgs {
+    history: usize,
+    /// `(s, y, 1 / sᵀy)`, oldest first
+    pairs: VecDeque<(Col<f64>, Col<f64>, f64)>,
+}
+
+impl Directions for Lbfgs {
+    fn direction(&mut self, current: &LinePoint) -> Col<f64> {
+        let mut q = current.grad.clone();
+        let mut alphas = Vec::with_capacity(self.pairs.len());
+
+        for (s, y, rho) in self.pairs.iter().rev() {
+            let alpha = rho * dot(s, &q);
+            q -= alpha * y;
+            alphas.push(alpha);
+        }
+
+        // Initial inverse Hessian `γ I`, scaled by the latest curvature
+        if let Some((s, y, _)) = self.pairs.back() {
+            q *= faer::scale(dot(s, y) / dot(y, y));
+        }
+
+        for ((s, y, rho), alpha) in self.pairs.iter().zip(alphas.into_iter().rev()) {
+            let beta = rho * dot(y, &q);
+            q += (alpha - beta) * s;
+        }
+
+        -q
+    }
+
+    fn initial_step(&self, current: &LinePoint) -> f64 {
+        if self.pairs.is_empty() {
+            first_step(current)
+        } else {
+            1.0
+        }
+    }
+
+    fn update(&mut self, previous: &LinePoint, current: &LinePoint, _: &Col<f64>) {
+        let s = &current.x - &previous.x;
+        let y = &current.grad - &previous.grad;
+        let sy = dot(&s, &y);
+
+        // Positive under the strong Wolfe conditions, up to round-off
+        if sy <= f64::EPSILON * dot(&y, &y) {
+            return;
+        }
+
+        if self.pairs.len() == self.history {
+            self.pairs.pop_front();
+        }
+        if self.history > 0 {
+            self.pairs.push_back((s, y, 1.0 / sy));
+        }
+    }
+
+    fn reset(&mut self) {
+        self.pairs.clear();
+    }
+}
+
+/// Polak–Ribière+ directions
+struct PolakRibiere {
+    /// Gradient, direction and value at the previous iterate
+    previous: Option<(Col<f64>, Col<f64>, f64)>,
+}
+
+impl Directions for PolakRibiere {
+    fn direction(&mut self, current: &LinePoint) -> Col<f64> {
+        let Some((grad, dir, _)) = &self.previous else {
+            return -&current.grad;
+        };
+
+        let change = &current.grad - grad;
+        let beta = f64::max(0.0, dot(&current.grad, &change) / dot(grad, grad));
+
+        beta * dir - &current.grad
+    }
+
+    fn initial_step(&self, current: &LinePoint) -> f64 {
+        let Some((_, _, value)) = self.previous else {
+            return first_step(current);
+        };
+
+        // Minimizer of the quadratic interpolating the last decrease and the current slope
+        let step = 2.0 * (current.value - value) / current.slope;
+        if step.is_finite() && step > 0.0 {
+            step.min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    fn update(&mut self, previous: &LinePoint, _: &LinePoint, dir: &Col<f64>) {
+        self.previous = Some((previous.grad.clone(), dir.clone(), previous.value));
+    }
+
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
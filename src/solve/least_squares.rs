@@ -0,0 +1,487 @@
+use crate::{
+    solve::Termination,
+    sparse::residual::{ComputedResidual, Residual},
+};
+use faer::{prelude::SpSolver, sparse::SparseColMat, Col, Side};
+
+/// A vector residual of the full variable vector, as minimized by the least-squares solvers.
+///
+/// Implemented by [`ResidualTerm`], which binds a [`Residual`] to its `operand_indices`
+/// and arguments, and by slices of problems, whose residuals are stacked.
+pub trait LeastSquaresProblem {
+    /// Returns the number of residuals
+    fn n_residuals(&self) -> usize;
+
+    /// Computes the residuals at `x`
+    fn residuals(&self, x: &Col<f64>) -> Col<f64>;
+
+    /// Computes the residuals and the Jacobian triplets at `x`
+    fn compute(&self, x: &Col<f64>) -> ComputedResidual;
+
+    /// Computes the least-squares value `0.5 * |r|^2` at `x`
+    fn value(&self, x: &Col<f64>) -> f64 {
+        0.5 * self.residuals(x).squared_norm_l2()
+    }
+}
+
+/// A [`Residual`] evaluated on fixed `operand_indices` with fixed arguments
+///
+/// ## Type Parameters
+/// - `N`: The problem size/dimension of a single element
+/// - `M`: The number of residuals of a single element
+/// - `R`: The residual type
+pub struct ResidualTerm<'a, const N: usize, const M: usize, R: Residual<N, M>> {
+    pub residual: &'a R,
+    pub operand_indices: &'a [[usize; N]],
+    pub args: &'a R::EvalArgs,
+}
+
+impl<'a, const N: usize, const M: usize, R: Residual<N, M>> ResidualTerm<'a, N, M, R> {
+    /// Binds `residual` to `operand_indices` and `args`
+    pub fn new(residual: &'a R, operand_indices: &'a [[usize; N]], args: &'a R::EvalArgs) -> Self {
+        Self {
+            residual,
+            operand_indices,
+            args,
+        }
+    }
+}
+
+impl<const N: usize, const M: usize, R: Residual<N, M>> LeastSquaresProblem
+    for ResidualTerm<'_, N, M, R>
+{
+    fn n_residuals(&self) -> usize {
+        self.operand_indices.len() * M
+    }
+
+    fn residuals(&self, x: &Col<f64>) -> Col<f64> {
+        self.residual.residuals(x, self.operand_indices, self.args)
+    }
+
+    fn compute(&self, x: &Col<f64>) -> ComputedResidual {
+        self.residual.compute(x, self.operand_indices, self.args)
+    }
+}
+
+impl<P: LeastSquaresProblem + ?Sized> LeastSquaresProblem for &P {
+    fn n_residuals(&self) -> usize {
+        (**self).n_residuals()
+    }
+
+    fn residuals(&self, x: &Col<f64>) -> Col<f64> {
+        (**self).residuals(x)
+    }
+
+    fn compute(&self, x: &Col<f64>) -> ComputedResidual {
+        (**self).compute(x)
+    }
+}
+
+/// The residuals of all problems in the slice, stacked in order
+impl<P: LeastSquaresProblem> LeastSquaresProblem for [P] {
+    fn n_residuals(&self) -> usize {
+        self.iter().map(|p| p.n_residuals()).sum()
+    }
+
+    fn residuals(&self, x: &Col<f64>) -> Col<f64> {
+        let parts = self.iter().map(|p| p.residuals(x)).collect::<Vec<_>>();
+        let mut res = Col::zeros(self.n_residuals());
+
+        let mut offset = 0;
+        for part in parts {
+            (0..part.nrows()).for_each(|i| res[offset + i] = part[i]);
+            offset += part.nrows();
+        }
+
+        res
+    }
+
+    fn compute(&self, x: &Col<f64>) -> ComputedResidual {
+        let mut residuals = Col::zeros(self.n_residuals());
+        let mut jacobian_trips = Vec::new();
+
+        let mut offset = 0;
+        for p in self {
+            let part = p.compute(x);
+            (0..part.residuals.nrows()).for_each(|i| residuals[offset + i] = part.residuals[i]);
+            jacobian_trips.extend(
+                part.jacobian_trips
+                    .into_iter()
+                    .map(|(row, col, v)| (offset + row, col, v)),
+            );
+            offset += part.residuals.nrows();
+        }
+
+        ComputedResidual {
+            residuals,
+            jacobian_trips,
+        }
+    }
+}
+
+/// Gauss-Newton method with a backtracking Armijo line search.
+///
+/// In each iteration the normal equations `(JᵀJ + damping * I) d = -Jᵀr` are solved
+/// by a sparse Cholesky decomposition. The default `damping` of zero gives pure Gauss-Newton
+/// steps, which can fail with [`Termination::FactorizationFailed`] when the Jacobian is rank deficient,
+/// e.g. for problems invariant under rigid motions. Set a small positive `damping` for those.
+///
+/// ## Fields
+/// - `tolerance`: Convergence threshold on the L2 norm of the gradient `Jᵀr`
+/// - `max_iterations`: Maximum number of iterations
+/// - `damping`: Fixed multiple of the identity added to `JᵀJ`
+/// - `armijo`: Sufficient decrease constant `c` in `f(x + t*d) <= f(x) + c * t * gᵀd`
+/// - `backtrack`: Factor in `(0, 1)` the step size is multiplied with on rejection
+/// - `min_step`: Smallest step size tried before the line search fails
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussNewtonSolver {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub damping: f64,
+    pub armijo: f64,
+    pub backtrack: f64,
+    pub min_step: f64,
+}
+
+impl Default for GaussNewtonSolver {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-8,
+            max_iterations: 100,
+            damping: 0.0,
+            armijo: 1e-4,
+            backtrack: 0.5,
+            min_step: 1e-10,
+        }
+    }
+}
+
+/// Levenberg-Marquardt method with adaptive damping.
+///
+/// In each iteration the step solves `(JᵀJ + λ I) d = -Jᵀr`. It is accepted if it decreases
+/// the value, and the damping `λ` is then adapted to the ratio of the actual decrease
+/// to the one predicted by the linearization (Nielsen's update): a good prediction moves
+/// towards Gauss-Newton steps, a rejected step towards short gradient descent steps.
+///
+/// ## Fields
+/// - `tolerance`: Convergence threshold on the L2 norm of the gradient `Jᵀr`
+/// - `max_iterations`: Maximum number of accepted or rejected steps
+/// - `initial_damping`: Initial `λ`, relative to the largest diagonal entry of `JᵀJ`
+/// - `max_damping`: Largest `λ` tried before the solver fails
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevenbergMarquardtSolver {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub initial_damping: f64,
+    pub max_damping: f64,
+}
+
+impl Default for LevenbergMarquardtSolver {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-8,
+            max_iterations: 200,
+            initial_damping: 1e-3,
+            max_damping: 1e16,
+        }
+    }
+}
+
+/// Summary of a single least-squares iteration
+///
+/// ## Fields
+/// - `iteration`: Index of the iteration, starting at 0
+/// - `value`: Value `0.5 * |r|^2` at the start of the iteration
+/// - `grad_norm`: L2 norm of the gradient `Jᵀr` at the start of the iteration
+/// - `damping`: Multiple of the identity added to `JᵀJ` for the accepted step
+/// - `step_size`: Accepted line search step size `t`, always `1.0` for Levenberg-Marquardt
+/// - `step_norm`: L2 norm of the accepted step
+/// - `rejected_steps`: Number of rejected step sizes or damping values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeastSquaresReport {
+    pub iteration: usize,
+    pub value: f64,
+    pub grad_norm: f64,
+    pub damping: f64,
+    pub step_size: f64,
+    pub step_norm: f64,
+    pub rejected_steps: usize,
+}
+
+/// Result of [`GaussNewtonSolver::solve`] and [`LevenbergMarquardtSolver::solve`]
+///
+/// ## Fields
+/// - `x`: The final iterate, the minimizer if `termination` is `Converged`
+/// - `value`: Value `0.5 * |r|^2` at `x`
+/// - `grad_norm`: L2 norm of the gradient `Jᵀr` at `x`
+/// - `termination`: Why the solver stopped
+/// - `reports`: One report per completed iteration
+#[derive(Debug, Clone)]
+pub struct LeastSquaresResult {
+    pub x: Col<f64>,
+    pub value: f64,
+    pub grad_norm: f64,
+    pub termination: Termination,
+    pub reports: Vec<LeastSquaresReport>,
+}
+
+impl LeastSquaresResult {
+    /// Returns whether the solver converged
+    pub fn converged(&self) -> bool {
+        self.termination == Termination::Converged
+    }
+}
+
+impl GaussNewtonSolver {
+    /// Minimizes `0.5 * |r(x)|^2`
+    ///
+    /// ## Arguments
+    /// - `problem`: The residuals to minimize
+    /// - `x0`: The initial guess
+    ///
+    /// ## Returns
+    /// The final iterate together with a report of every iteration
+    pub fn solve<P: LeastSquaresProblem + ?Sized>(
+        &self,
+        problem: &P,
+        x0: &Col<f64>,
+    ) -> LeastSquaresResult {
+        let n = x0.nrows();
+        let mut x = x0.clone();
+        let mut reports = Vec::new();
+
+        for iteration in 0..self.max_iterations {
+            let normal = NormalEquations::new(n, problem.compute(&x));
+            if normal.grad_norm <= self.tolerance {
+                return normal.finish(x, Termination::Converged, reports);
+            }
+
+            let Some(dir) = normal.solve(self.damping) else {
+                return normal.finish(x, Termination::FactorizationFailed, reports);
+            };
+
+            // Negative, since JᵀJ + damping * I is positive definite
+            let slope = normal.grad_dot(&dir);
+
+            let mut step_size = 1.0;
+            let mut rejected_steps = 0;
+            let x_new = loop {
+                let x_new = Col::from_fn(n, |i| x[i] + step_size * dir[i]);
+                // Also rejects NaN
+                if problem.value(&x_new) <= normal.value + self.armijo * step_size * slope {
+                    break x_new;
+                }
+
+                step_size *= self.backtrack;
+                rejected_steps += 1;
+                if step_size < self.min_step {
+                    return normal.finish(x, Termination::LineSearchFailed, reports);
+                }
+            };
+
+            reports.push(LeastSquaresReport {
+                iteration,
+                value: normal.value,
+                grad_norm: normal.grad_norm,
+                damping: self.damping,
+                step_size,
+                step_norm: step_size * dir.norm_l2(),
+                rejected_steps,
+            });
+
+            x = x_new;
+        }
+
+        let normal = NormalEquations::new(n, problem.compute(&x));
+        let termination = if normal.grad_norm <= self.tolerance {
+            Termination::Converged
+        } else {
+            Termination::MaxIterations
+        };
+
+        normal.finish(x, termination, reports)
+    }
+}
+
+impl LevenbergMarquardtSolver {
+    /// Minimizes `0.5 * |r(x)|^2`
+    ///
+    /// ## Arguments
+    /// - `problem`: The residuals to minimize
+    /// - `x0`: The initial guess
+    ///
+    /// ## Returns
+    /// The final iterate together with a report of every accepted step
+    pub fn solve<P: LeastSquaresProblem + ?Sized>(
+        &self,
+        problem: &P,
+        x0: &Col<f64>,
+    ) -> LeastSquaresResult {
+        let n = x0.nrows();
+        let mut x = x0.clone();
+        let mut reports = Vec::new();
+
+        let mut normal = NormalEquations::new(n, problem.compute(&x));
+        let mut damping = self.initial_damping * normal.max_diagonal().max(1.0);
+        // Growth of the damping on consecutive rejections
+        let mut growth = 2.0;
+        let mut rejected_steps = 0;
+
+        for _ in 0..self.max_iterations {
+            if normal.grad_norm <= self.tolerance {
+                return normal.finish(x, Termination::Converged, reports);
+            }
+            if damping > self.max_damping {
+                return normal.finish(x, Termination::DampingExceeded, reports);
+            }
+
+            let accepted = normal.solve(damping).and_then(|dir| {
+                let x_new = Col::from_fn(n, |i| x[i] + dir[i]);
+                let value_new = problem.value(&x_new);
+
+                // Decrease predicted by the linearization, positive for a descent step
+                let predicted = -normal.grad_dot(&dir) - 0.5 * normal.jacobian_norm2(&dir);
+                let ratio = (normal.value - value_new) / predicted;
+
+                // Also rejects NaN
+                (ratio > 0.0).then_some((dir, x_new, ratio))
+            });
+
+            let Some((dir, x_new, ratio)) = accepted else {
+                damping *= growth;
+                growth *= 2.0;
+                rejected_steps += 1;
+                continue;
+            };
+
+            reports.push(LeastSquaresReport {
+                iteration: reports.len(),
+                value: normal.value,
+                grad_norm: normal.grad_norm,
+                damping,
+                step_size: 1.0,
+                step_norm: dir.norm_l2(),
+                rejected_steps,
+            });
+
+            damping *= f64::max(1.0 / 3.0, 1.0 - (2.0 * ratio - 1.0).powi(3));
+            growth = 2.0;
+            rejected_steps = 0;
+
+            x = x_new;
+            normal = NormalEquations::new(n, problem.compute(&x));
+        }
+
+        let termination = if normal.grad_norm <= self.tolerance {
+            Termination::Converged
+        } else {
+            Termination::MaxIterations
+        };
+
+        normal.finish(x, termination, reports)
+    }
+}
+
+/// Normal equations `JᵀJ d = -Jᵀr` of the linearized residuals at an iterate
+struct NormalEquations {
+    n: usize,
+    computed: ComputedResidual,
+    /// Lower triangle of `JᵀJ`
+    lower_trips: Vec<(usize, usize, f64)>,
+    /// `Jᵀr`
+    grad: Col<f64>,
+    value: f64,
+    grad_norm: f64,
+}
+
+impl NormalEquations {
+    fn new(n: usize, computed: ComputedResidual) -> Self {
+        let r = &computed.residuals;
+
+        let mut grad = Col::zeros(n);
+        let mut rows = vec![Vec::new(); r.nrows()];
+        for &(row, col, v) in &computed.jacobian_trips {
+            grad[col] += v * r[row];
+            rows[row].push((col, v));
+        }
+
+        // JᵀJ is the sum of the outer products of the rows of J. Duplicate entries in a row
+        // pass the filter in both orders, which accounts for their cross term
+        let lower_trips = rows
+            .iter()
+            .flat_map(|row| {
+                row.iter().flat_map(move |&(ci, vi)| {
+                    row.iter()
+                        .filter(move |&&(cj, _)| ci >= cj)
+                        .map(move |&(cj, vj)| (ci, cj, vi * vj))
+                })
+            })
+            .collect();
+
+        Self {
+            n,
+            value: 0.5 * r.squared_norm_l2(),
+            grad_norm: grad.norm_l2(),
+            computed,
+            lower_trips,
+            grad,
+        }
+    }
+
+    /// Solves `(JᵀJ + damping * I) d = -Jᵀr`, or `None` if it is not positive definite
+    fn solve(&self, damping: f64) -> Option<Col<f64>> {
+        let mut trips = self.lower_trips.clone();
+        // Always present, so that the diagonal is part of the sparsity pattern
+        trips.extend((0..self.n).map(|i| (i, i, damping)));
+
+        let normal = SparseColMat::try_new_from_triplets(self.n, self.n, &trips)
+            .expect("Jacobian triplets out of bounds");
+
+        normal
+            .sp_cholesky(Side::Lower)
+            .ok()
+            .map(|llt| llt.solve(-&self.grad))
+    }
+
+    /// Largest diagonal entry of `JᵀJ`
+    fn max_diagonal(&self) -> f64 {
+        let mut diagonal = vec![0.0; self.n];
+        self.lower_trips
+            .iter()
+            .filter(|&&(row, col, _)| row == col)
+            .for_each(|&(row, _, v)| diagonal[row] += v);
+
+        diagonal.into_iter().fold(0.0, f64::max)
+    }
+
+    /// `(Jᵀr)ᵀ d`
+    fn grad_dot(&self, dir: &Col<f64>) -> f64 {
+        (0..self.n).map(|i| self.grad[i] * dir[i]).sum()
+    }
+
+    /// `|J d|^2`
+    fn jacobian_norm2(&self, dir: &Col<f64>) -> f64 {
+        let mut jd = Col::<f64>::zeros(self.computed.residuals.nrows());
+        self.computed
+            .jacobian_trips
+            .iter()
+            .for_each(|&(row, col, v)| jd[row] += v * dir[col]);
+
+        jd.squared_norm_l2()
+    }
+
+    fn finish(
+        &self,
+        x: Col<f64>,
+        termination: Termination,
+        reports: Vec<LeastSquaresReport>,
+    ) -> LeastSquaresResult {
+        LeastSquaresResult {
+            x,
+            value: self.value,
+            grad_norm: self.grad_norm,
+            termination,
+            reports,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::solve::Problem;
+use faer::Col;
+
+/// Line search finding a step size satisfying the strong Wolfe conditions
+///
+/// Along a descent direction `d`, a step size `t` is accepted if
+/// - `f(x + t*d) <= f(x) + c1 * t * gᵀd` (sufficient decrease), and
+/// - `|g(x + t*d)ᵀd| <= c2 * |gᵀd|` (curvature).
+///
+/// Trial steps grow until they bracket an acceptable step, which is then located by
+/// safeguarded cubic interpolation (Nocedal & Wright, Algorithms 3.5 and 3.6).
+///
+/// ## Fields
+/// - `c1`: Sufficient decrease constant, in `(0, c2)`
+/// - `c2`: Curvature constant, in `(c1, 1)`. Around `0.9` for quasi-Newton methods,
+///   `0.1` for nonlinear conjugate gradients
+/// - `growth`: Factor trial steps grow by while bracketing
+/// - `max_evaluations`: Maximum number of evaluations before the line search fails
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrongWolfe {
+    pub c1: f64,
+    pub c2: f64,
+    pub growth: f64,
+    pub max_evaluations: usize,
+}
+
+impl Default for StrongWolfe {
+    fn default() -> Self {
+        Self {
+            c1: 1e-4,
+            c2: 0.9,
+            growth: 2.0,
+            max_evaluations: 40,
+        }
+    }
+}
+
+/// A point along the search direction, with its value and derivatives
+#[derive(Debug, Clone)]
+pub(crate) struct LinePoint {
+    pub(crate) step: f64,
+    pub(crate) x: Col<f64>,
+    pub(crate) value: f64,
+    pub(crate) grad: Col<f64>,
+    /// Directional derivative `gᵀd`
+    pub(crate) slope: f64,
+}
+
+/// Accepted step of [`StrongWolfe::search`]
+#[derive(Debug, Clone)]
+pub(crate) struct LineSearchResult {
+    pub(crate) point: LinePoint,
+    pub(crate) evaluations: usize,
+}
+
+impl StrongWolfe {
+    /// Searches along `dir` from `start`, beginning with the step size `initial_step`
+    ///
+    /// ## Returns
+    /// The accepted point, or `None` if none was found within `max_evaluations`
+    ///
+    /// ## Panics
+    /// If `dir` is not a descent direction
+    pub(crate) fn search<P: Problem + ?Sized>(
+        &self,
+        problem: &P,
+        start: &LinePoint,
+        dir: &Col<f64>,
+        initial_step: f64,
+    ) -> Option<LineSearchResult> {
+        assert!(start.slope < 0.0, "Line search along an ascent direction!");
+
+        let mut evaluations = 0;
+        let mut eval = |step: f64| {
+            evaluations += 1;
+            let x = Col::from_fn(dir.nrows(), |i| start.x[i] + step * dir[i]);
+            let (value, grad) = problem.value_grad(&x);
+            let slope = dot(&grad, dir);
+            LinePoint {
+                step,
+                x,
+                value,
+                grad,
+                slope,
+            }
+        };
+
+        let mut prev = start.clone();
+        let mut step = initial_step;
+
+        for i in 0..self.max_evaluations {
+            let point = eval(step);
+
+            let accepted = if !self.sufficient_decrease(start, &point)
+                || (i > 0 && point.value >= prev.value)
+            {
+                self.zoom(start, prev, point, &mut eval, i + 1)
+            } else if self.curvature(start, &point) {
+                Some(point)
+            } else if point.slope >= 0.0 {
+                self.zoom(start, point, prev, &mut eval, i + 1)
+            } else {
+                step *= self.growth;
+                prev = point;
+                continue;
+            };
+
+            return accepted.map(|point| LineSearchResult { point, evaluations });
+        }
+
+        None
+    }
+
+    /// Locates an acceptable step between `lo`, the best point so far satisfying
+    /// sufficient decrease, and `hi`, such that the interval brackets one
+    fn zoom(
+        &self,
+        start: &LinePoint,
+        mut lo: LinePoint,
+        mut hi: LinePoint,
+        eval: &mut impl FnMut(f64) -> LinePoint,
+        mut evaluations: usize,
+    ) -> Option<LinePoint> {
+        while evaluations < self.max_evaluations {
+            let point = eval(interpolate(&lo, &hi));
+            evaluations += 1;
+
+            if !self.sufficient_decrease(start, &point) || point.value >= lo.value {
+                hi = point;
+            } else {
+                if self.curvature(start, &point) {
+                    return Some(point);
+                }
+                if point.slope * (hi.step - lo.step) >= 0.0 {
+                    hi = lo;
+                }
+                lo = point;
+            }
+
+            if (hi.step - lo.step).abs() <= f64::EPSILON * lo.step.abs() {
+                break;
+            }
+        }
+
+        None
+    }
+
+    fn sufficient_decrease(&self, start: &LinePoint, point: &LinePoint) -> bool {
+        // Also rejects NaN
+        point.value <= start.value + self.c1 * point.step * start.slope
+    }
+
+    fn curvature(&self, start: &LinePoint, point: &LinePoint) -> bool {
+        point.slope.abs() <= -self.c2 * start.slope
+    }
+}
+
+/// Minimizer of the cubic interpolating values and slopes at `a` and `b`,
+/// falling back to bisection if it is not well inside the interval
+fn interpolate(a: &LinePoint, b: &LinePoint) -> f64 {
+    let d1 = a.slope + b.slope - 3.0 * (a.value - b.value) / (a.step - b.step);
+    let d2 = (d1 * d1 - a.slope * b.slope).sqrt() * (b.step - a.step).signum();
+    let step = b.step - (b.step - a.step) * (b.slope + d2 - d1) / (b.slope - a.slope + 2.0 * d2);
+
+    let (low, high) = (a.step.min(b.step), a.step.max(b.step));
+    let margin = 0.1 * (high - low);
+    if step.is_finite() && step >= low + margin && step <= high - margin {
+        step
+    } else {
+        0.5 * (low + high)
+    }
+}
+
+pub(crate) fn dot(a: &Col<f64>, b: &Col<f64>) -> f64 {
+    (0..a.nrows()).map(|i| a[i] * b[i]).sum()
+}
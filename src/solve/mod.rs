@@ -0,0 +1,208 @@
+use crate::sparse::objective::{
+    elements_grad_into, elements_hess_trips_into, elements_hess_trips_triangular, elements_value,
+    evaluate_elements, scatter_grad, ElementArgs, Objective,
+};
+use faer::{Col, Side};
+
+pub mod first_order;
+pub mod least_squares;
+pub mod line_search;
+pub mod newton;
+
+pub use first_order::{ConjugateGradientSolver, LbfgsSolver, MinimizeReport, MinimizeResult};
+pub use least_squares::{
+    GaussNewtonSolver, LeastSquaresProblem, LeastSquaresReport, LeastSquaresResult,
+    LevenbergMarquardtSolver, ResidualTerm,
+};
+pub use line_search::StrongWolfe;
+pub use newton::{NewtonReport, NewtonResult, NewtonSolver, Termination};
+
+/// A scalar function of the full variable vector, as minimized by the solvers.
+///
+/// Implemented by [`ObjectiveTerm`], which binds an [`Objective`] to its
+/// `operand_indices` and arguments, shared or per element,
+/// and by slices of problems, which are summed.
+///
+/// ## Example
+/// ```ignore
+/// // Objectives of different stencil sizes
+/// let terms: [&dyn Problem; 2] = [
+///     &ObjectiveTerm::new(&springs, &edges, &()),
+///     &ObjectiveTerm::new(&bending, &hinges, &()),
+/// ];
+/// let result = NewtonSolver::default().solve(&terms[..], &x0);
+/// ```
+pub trait Problem {
+    /// Computes the value at `x`
+    fn value(&self, x: &Col<f64>) -> f64;
+
+    /// Computes the gradient at `x`
+    fn grad(&self, x: &Col<f64>) -> Col<f64>;
+
+    /// Computes the Hessian at `x` as sparse triplets
+    fn hess_trips(&self, x: &Col<f64>) -> Vec<(usize, usize, f64)>;
+
+    /// Computes the value and the gradient at `x`, as needed by line searches
+    fn value_grad(&self, x: &Col<f64>) -> (f64, Col<f64>) {
+        (self.value(x), self.grad(x))
+    }
+
+    /// Computes one triangle of the Hessian at `x` as sparse triplets
+    fn hess_trips_triangular(&self, x: &Col<f64>, side: Side) -> Vec<(usize, usize, f64)> {
+        self.hess_trips(x)
+            .into_iter()
+            .filter(|&(row, col, _)| match side {
+                Side::Lower => row >= col,
+                Side::Upper => row <= col,
+            })
+            .collect()
+    }
+}
+
+/// An [`Objective`] evaluated on fixed `operand_indices` with fixed arguments,
+/// shared by all elements or one per element (see [`ElementArgs`])
+///
+/// ## Type Parameters
+/// - `N`: The problem size/dimension of a single objective
+/// - `O`: The objective type
+/// - `A`: The type of the arguments, `O::EvalArgs` if they are shared
+pub struct ObjectiveTerm<'a, const N: usize, O, A = <O as Objective<N>>::EvalArgs>
+where
+    O: Objective<N>,
+    A: ?Sized,
+{
+    pub objective: &'a O,
+    pub operand_indices: &'a [[usize; N]],
+    pub args: &'a A,
+}
+
+/// An [`Objective`] evaluated on fixed `operand_indices`, with separate arguments
+/// for each element, e.g. the rest shapes of elements of a mesh
+pub type ElementwiseTerm<'a, const N: usize, O> =
+    ObjectiveTerm<'a, N, O, [<O as Objective<N>>::EvalArgs]>;
+
+impl<'a, const N: usize, O, A> ObjectiveTerm<'a, N, O, A>
+where
+    O: Objective<N>,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    /// Binds `objective` to `operand_indices` and `args`
+    ///
+    /// # Panics
+    /// If `args` holds arguments per element, but not exactly one for every element
+    pub fn new(objective: &'a O, operand_indices: &'a [[usize; N]], args: &'a A) -> Self {
+        args.check_elements(operand_indices.len());
+
+        Self {
+            objective,
+            operand_indices,
+            args,
+        }
+    }
+}
+
+impl<const N: usize, O, A> Problem for ObjectiveTerm<'_, N, O, A>
+where
+    O: Objective<N>,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    fn value(&self, x: &Col<f64>) -> f64 {
+        elements_value(self.objective, x, self.operand_indices, self.args)
+    }
+
+    fn grad(&self, x: &Col<f64>) -> Col<f64> {
+        let mut grad = Col::zeros(x.nrows());
+        elements_grad_into(
+            self.objective,
+            x,
+            self.operand_indices,
+            self.args,
+            &mut grad,
+        );
+        grad
+    }
+
+    fn hess_trips(&self, x: &Col<f64>) -> Vec<(usize, usize, f64)> {
+        let mut trips = Vec::new();
+        elements_hess_trips_into(
+            self.objective,
+            x,
+            self.operand_indices,
+            self.args,
+            &mut trips,
+        );
+        trips
+    }
+
+    fn hess_trips_triangular(&self, x: &Col<f64>, side: Side) -> Vec<(usize, usize, f64)> {
+        elements_hess_trips_triangular(self.objective, x, self.operand_indices, self.args, side)
+    }
+
+    fn value_grad(&self, x: &Col<f64>) -> (f64, Col<f64>) {
+        let mut value = 0.0;
+        let mut grad = Col::zeros(x.nrows());
+        evaluate_elements(self.objective, x, self.operand_indices, self.args).for_each(
+            |(inds, obj)| {
+                value += obj.value;
+                scatter_grad(&mut grad, inds, &obj.grad);
+            },
+        );
+        (value, grad)
+    }
+}
+
+impl<P: Problem + ?Sized> Problem for &P {
+    fn value(&self, x: &Col<f64>) -> f64 {
+        (**self).value(x)
+    }
+
+    fn grad(&self, x: &Col<f64>) -> Col<f64> {
+        (**self).grad(x)
+    }
+
+    fn hess_trips(&self, x: &Col<f64>) -> Vec<(usize, usize, f64)> {
+        (**self).hess_trips(x)
+    }
+
+    fn hess_trips_triangular(&self, x: &Col<f64>, side: Side) -> Vec<(usize, usize, f64)> {
+        (**self).hess_trips_triangular(x, side)
+    }
+
+    fn value_grad(&self, x: &Col<f64>) -> (f64, Col<f64>) {
+        (**self).value_grad(x)
+    }
+}
+
+/// The sum of all problems in the slice
+impl<P: Problem> Problem for [P] {
+    fn value(&self, x: &Col<f64>) -> f64 {
+        self.iter().map(|p| p.value(x)).sum()
+    }
+
+    fn grad(&self, x: &Col<f64>) -> Col<f64> {
+        let mut grad = Col::zeros(x.nrows());
+        self.iter().for_each(|p| grad += p.grad(x));
+        grad
+    }
+
+    fn hess_trips(&self, x: &Col<f64>) -> Vec<(usize, usize, f64)> {
+        self.iter().flat_map(|p| p.hess_trips(x)).collect()
+    }
+
+    fn hess_trips_triangular(&self, x: &Col<f64>, side: Side) -> Vec<(usize, usize, f64)> {
+        self.iter()
+            .flat_map(|p| p.hess_trips_triangular(x, side))
+            .collect()
+    }
+
+    fn value_grad(&self, x: &Col<f64>) -> (f64, Col<f64>) {
+        let mut value = 0.0;
+        let mut grad = Col::zeros(x.nrows());
+        self.iter().for_each(|p| {
+            let (v, g) = p.value_grad(x);
+            value += v;
+            grad += g;
+        });
+        (value, grad)
+    }
+}
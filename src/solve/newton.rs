@@ -0,0 +1,231 @@
+use crate::solve::Problem;
+use faer::{prelude::SpSolver, sparse::SparseColMat, Col, Side};
+
+/// Why a solver stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    /// The gradient norm dropped below the tolerance
+    Converged,
+    /// The maximum number of iterations was reached
+    MaxIterations,
+    /// No step satisfying the sufficient decrease condition was found
+    LineSearchFailed,
+    /// The Levenberg-Marquardt damping grew beyond its maximum without an accepted step
+    DampingExceeded,
+    /// The Hessian could not be factorized, even with the maximum diagonal shift
+    FactorizationFailed,
+    /// The per-iteration callback asked to stop
+    Stopped,
+}
+
+/// Damped Newton's method with a backtracking Armijo line search.
+///
+/// In each iteration the lower triangle of the Hessian is factorized by a sparse Cholesky
+/// decomposition. If the Hessian is not positive definite, a multiple of the identity
+/// is added to it, starting at `initial_shift` and growing by `shift_growth`,
+/// until the factorization succeeds. The resulting direction is always a descent direction.
+///
+/// ## Fields
+/// - `tolerance`: Convergence threshold on the L2 norm of the gradient
+/// - `max_iterations`: Maximum number of Newton iterations
+/// - `armijo`: Sufficient decrease constant `c` in `f(x + t*d) <= f(x) + c * t * gᵀd`
+/// - `backtrack`: Factor in `(0, 1)` the step size is multiplied with on rejection
+/// - `min_step`: Smallest step size tried before the line search fails
+/// - `initial_shift`: First diagonal shift tried when the factorization fails
+/// - `shift_growth`: Factor the diagonal shift is multiplied with on each failure
+/// - `max_shift`: Largest diagonal shift tried before the solver fails
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NewtonSolver {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub armijo: f64,
+    pub backtrack: f64,
+    pub min_step: f64,
+    pub initial_shift: f64,
+    pub shift_growth: f64,
+    pub max_shift: f64,
+}
+
+impl Default for NewtonSolver {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-8,
+            max_iterations: 100,
+            armijo: 1e-4,
+            backtrack: 0.5,
+            min_step: 1e-10,
+            initial_shift: 1e-8,
+            shift_growth: 10.0,
+            max_shift: 1e12,
+        }
+    }
+}
+
+/// Summary of a single Newton iteration
+///
+/// ## Fields
+/// - `iteration`: Index of the iteration, starting at 0
+/// - `value`: Value at the start of the iteration
+/// - `grad_norm`: L2 norm of the gradient at the start of the iteration
+/// - `shift`: Diagonal shift added to the Hessian, `0.0` if none was needed
+/// - `step_size`: Accepted line search step size `t`
+/// - `step_norm`: L2 norm of the accepted step `t * d`
+/// - `line_search_steps`: Number of rejected step sizes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NewtonReport {
+    pub iteration: usize,
+    pub value: f64,
+    pub grad_norm: f64,
+    pub shift: f64,
+    pub step_size: f64,
+    pub step_norm: f64,
+    pub line_search_steps: usize,
+}
+
+/// Result of [`NewtonSolver::solve`]
+///
+/// ## Fields
+/// - `x`: The final iterate, the minimizer if `termination` is `Converged`
+/// - `value`: Value at `x`
+/// - `grad_norm`: L2 norm of the gradient at `x`
+/// - `termination`: Why the solver stopped
+/// - `reports`: One report per completed iteration
+#[derive(Debug, Clone)]
+pub struct NewtonResult {
+    pub x: Col<f64>,
+    pub value: f64,
+    pub grad_norm: f64,
+    pub termination: Termination,
+    pub reports: Vec<NewtonReport>,
+}
+
+impl NewtonResult {
+    /// Returns whether the solver converged
+    pub fn converged(&self) -> bool {
+        self.termination == Termination::Converged
+    }
+}
+
+impl NewtonSolver {
+    /// Minimizes `problem`
+    ///
+    /// ## Arguments
+    /// - `problem`: The function to minimize
+    /// - `x0`: The initial guess
+    ///
+    /// ## Returns
+    /// The final iterate together with a report of every iteration
+    pub fn solve<P: Problem + ?Sized>(&self, problem: &P, x0: &Col<f64>) -> NewtonResult {
+        let n = x0.nrows();
+        let mut x = x0.clone();
+        let mut value = problem.value(&x);
+        let mut reports = Vec::new();
+
+        let finish = |x: Col<f64>, value, grad_norm, termination, reports| NewtonResult {
+            x,
+            value,
+            grad_norm,
+            termination,
+            reports,
+        };
+
+        for iteration in 0..self.max_iterations {
+            let grad = problem.grad(&x);
+            let grad_norm = grad.norm_l2();
+            if grad_norm <= self.tolerance {
+                return finish(x, value, grad_norm, Termination::Converged, reports);
+            }
+
+            let trips = problem.hess_trips_triangular(&x, Side::Lower);
+            let Some((dir, shift)) = self.shifted_newton_direction(n, trips, &grad) else {
+                return finish(
+                    x,
+                    value,
+                    grad_norm,
+                    Termination::FactorizationFailed,
+                    reports,
+                );
+            };
+
+            // Negative, since the shifted Hessian is positive definite
+            let slope: f64 = (0..n).map(|i| grad[i] * dir[i]).sum();
+
+            let mut step_size = 1.0;
+            let mut line_search_steps = 0;
+            let (x_new, value_new) = loop {
+                let x_new = Col::from_fn(n, |i| x[i] + step_size * dir[i]);
+                let value_new = problem.value(&x_new);
+                // Also rejects NaN
+                if value_new <= value + self.armijo * step_size * slope {
+                    break (x_new, value_new);
+                }
+
+                step_size *= self.backtrack;
+                line_search_steps += 1;
+                if step_size < self.min_step {
+                    return finish(x, value, grad_norm, Termination::LineSearchFailed, reports);
+                }
+            };
+
+            reports.push(NewtonReport {
+                iteration,
+                value,
+                grad_norm,
+                shift,
+                step_size,
+                step_norm: step_size * dir.norm_l2(),
+                line_search_steps,
+            });
+
+            x = x_new;
+            value = value_new;
+        }
+
+        let grad_norm = problem.grad(&x).norm_l2();
+        let termination = if grad_norm <= self.tolerance {
+            Termination::Converged
+        } else {
+            Termination::MaxIterations
+        };
+
+        finish(x, value, grad_norm, termination, reports)
+    }
+
+    /// Solves `(H + shift * I) d = -g` with the smallest tried shift
+    /// for which `H + shift * I` is positive definite
+    ///
+    /// ## Returns
+    /// The direction and the shift, or `None` if `max_shift` is exceeded
+    fn shifted_newton_direction(
+        &self,
+        n: usize,
+        lower_trips: Vec<(usize, usize, f64)>,
+        grad: &Col<f64>,
+    ) -> Option<(Col<f64>, f64)> {
+        let mut trips = lower_trips;
+        let n_trips = trips.len();
+        let mut shift = 0.0;
+
+        loop {
+            // Always present, so that the diagonal is part of the sparsity pattern
+            trips.truncate(n_trips);
+            trips.extend((0..n).map(|i| (i, i, shift)));
+
+            let hess = SparseColMat::try_new_from_triplets(n, n, &trips)
+                .expect("Hessian triplets out of bounds");
+
+            if let Ok(llt) = hess.sp_cholesky(Side::Lower) {
+                return Some((llt.solve(-grad), shift));
+            }
+
+            shift = if shift == 0.0 {
+                self.initial_shift
+            } else {
+                shift * self.shift_growth
+            };
+            if shift > self.max_shift {
+                return None;
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    solve::Problem,
+    sparse::objective::{
+        elements_value, evaluate_elements, local_hess_trips_triangular, scatter_grad, scatter_hess,
+        ElementArgs, Objective,
+    },
+    Ad,
+};
+use faer::{
+    sparse::{CreationError, SparseColMat},
+    Col, Side,
+};
+
+/// Represents the computed results of a composite objective evaluation,
+/// the counterpart of `ComputedObjective` without a stencil size.
+///
+/// ## Fields
+/// - `value`: The computed total value
+/// - `grad`: The gradient vector (first derivatives)
+/// - `hess_trips`: Hessian matrix entries stored as (row, col, value) triplets
+pub struct ComputedComposite {
+    pub value: f64,
+    pub grad: Col<f64>,
+    pub hess_trips: Vec<(usize, usize, f64)>,
+}
+
+/// A weighted sum of objectives with possibly different stencil sizes `N`,
+/// over a shared global variable vector.
+///
+/// Each term owns its objective, `operand_indices` and evaluation arguments,
+/// which are shared or per element, see [`ElementArgs`].
+///
+/// ## Example
+/// ```ignore
+/// let total = CompositeObjective::new()
+///     .with(SpringEnergy { k: 1.0 }, springs, (), 1.0) // N = 4
+///     .with(BendingEnergy, hinges, rest_angles, 0.1) // N = 6
+///     .with(AnchorEnergy, anchors, targets, 100.0); // N = 2
+///
+/// let computed = total.compute(&x);
+/// ```
+#[derive(Default)]
+pub struct CompositeObjective<'a> {
+    terms: Vec<Box<dyn Term + 'a>>,
+}
+
+impl<'a> CompositeObjective<'a> {
+    /// Creates a composite objective without terms, whose value is zero
+    pub fn new() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    /// Adds the term `weight * objective(x, operand_indices, args)`
+    ///
+    /// ## Panics
+    /// If `args` holds arguments per element, but not exactly one for every element
+    pub fn push<const N: usize, O: Objective<N> + 'a, A: ElementArgs<O::EvalArgs> + 'a>(
+        &mut self,
+        objective: O,
+        operand_indices: Vec<[usize; N]>,
+        args: A,
+        weight: f64,
+    ) {
+        args.check_elements(operand_indices.len());
+        self.terms.push(Box::new(WeightedTerm {
+            objective,
+            operand_indices,
+            args,
+            weight,
+        }));
+    }
+
+    /// Builder version of [`CompositeObjective::push`]
+    pub fn with<const N: usize, O: Objective<N> + 'a, A: ElementArgs<O::EvalArgs> + 'a>(
+        mut self,
+        objective: O,
+        operand_indices: Vec<[usize; N]>,
+        args: A,
+        weight: f64,
+    ) -> Self {
+        self.push(objective, operand_indices, args, weight);
+        self
+    }
+
+    /// Returns the number of terms
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns whether there are no terms
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Computes value, gradient and Hessian of all terms in one operation
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector, may be large
+    ///
+    /// ## Returns
+    /// A `ComputedComposite` containing all computed results
+    pub fn compute(&self, x: &Col<f64>) -> ComputedComposite {
+        let mut computed = ComputedComposite {
+            value: 0.0,
+            grad: Col::zeros(x.nrows()),
+            hess_trips: Vec::new(),
+        };
+
+        self.terms
+            .iter()
+            .for_each(|term| term.add_compute(x, &mut computed));
+
+        computed
+    }
+
+    /// Computes the total value
+    pub fn value(&self, x: &Col<f64>) -> f64 {
+        self.terms.iter().map(|term| term.value(x)).sum()
+    }
+
+    /// Computes the total gradient
+    pub fn grad(&self, x: &Col<f64>) -> Col<f64> {
+        let mut grad = Col::zeros(x.nrows());
+        self.terms
+            .iter()
+            .for_each(|term| term.add_grad(x, &mut grad));
+        grad
+    }
+
+    /// Computes the total Hessian as sparse triplets
+    pub fn hess_trips(&self, x: &Col<f64>) -> Vec<(usize, usize, f64)> {
+        let mut trips = Vec::new();
+        self.terms
+            .iter()
+            .for_each(|term| term.add_hess_trips(x, None, &mut trips));
+        trips
+    }
+
+    /// Computes one triangle of the total Hessian as sparse triplets
+    pub fn hess_trips_triangular(&self, x: &Col<f64>, side: Side) -> Vec<(usize, usize, f64)> {
+        let mut trips = Vec::new();
+        self.terms
+            .iter()
+            .for_each(|term| term.add_hess_trips(x, Some(side), &mut trips));
+        trips
+    }
+
+    /// Computes the total Hessian as a sparse matrix
+    pub fn hess(&self, x: &Col<f64>) -> Result<SparseColMat<usize, f64>, CreationError> {
+        let n = x.nrows();
+        SparseColMat::try_new_from_triplets(n, n, &self.hess_trips(x))
+    }
+
+    /// Computes one triangle of the total Hessian as a sparse matrix
+    pub fn hess_triangular(
+        &self,
+        x: &Col<f64>,
+        side: Side,
+    ) -> Result<SparseColMat<usize, f64>, CreationError> {
+        let n = x.nrows();
+        SparseColMat::try_new_from_triplets(n, n, &self.hess_trips_triangular(x, side))
+    }
+}
+
+impl Problem for CompositeObjective<'_> {
+    fn value(&self, x: &Col<f64>) -> f64 {
+        CompositeObjective::value(self, x)
+    }
+
+    fn grad(&self, x: &Col<f64>) -> Col<f64> {
+        CompositeObjective::grad(self, x)
+    }
+
+    fn hess_trips(&self, x: &Col<f64>) -> Vec<(usize, usize, f64)> {
+        CompositeObjective::hess_trips(self, x)
+    }
+
+    fn hess_trips_triangular(&self, x: &Col<f64>, side: Side) -> Vec<(usize, usize, f64)> {
+        CompositeObjective::hess_trips_triangular(self, x, side)
+    }
+}
+
+/// Object safe interface of a weighted term, hiding its stencil size
+trait Term {
+    fn value(&self, x: &Col<f64>) -> f64;
+    fn add_grad(&self, x: &Col<f64>, grad: &mut Col<f64>);
+    fn add_hess_trips(
+        &self,
+        x: &Col<f64>,
+        side: Option<Side>,
+        trips: &mut Vec<(usize, usize, f64)>,
+    );
+    fn add_compute(&self, x: &Col<f64>, computed: &mut ComputedComposite);
+}
+
+struct WeightedTerm<const N: usize, O, A> {
+    objective: O,
+    operand_indices: Vec<[usize; N]>,
+    args: A,
+    weight: f64,
+}
+
+impl<const N: usize, O: Objective<N>, A: ElementArgs<O::EvalArgs>> WeightedTerm<N, O, A> {
+    /// Evaluates every element
+    fn elements<'b>(&'b self, x: &'b Col<f64>) -> impl Iterator<Item = ([usize; N], Ad<N>)> + 'b {
+        evaluate_elements(&self.objective, x, &self.operand_indices, &self.args)
+    }
+}
+
+impl<const N: usize, O: Objective<N>, A: ElementArgs<O::EvalArgs>> Term for WeightedTerm<N, O, A> {
+    fn value(&self, x: &Col<f64>) -> f64 {
+        self.weight * elements_value(&self.objective, x, &self.operand_indices, &self.args)
+    }
+
+    fn add_grad(&self, x: &Col<f64>, grad: &mut Col<f64>) {
+        self.elements(x).for_each(|(ind, obj)| {
+            scatter_grad(grad, ind, &(obj.grad * self.weight));
+        });
+    }
+
+    fn add_hess_trips(
+        &self,
+        x: &Col<f64>,
+        side: Option<Side>,
+        trips: &mut Vec<(usize, usize, f64)>,
+    ) {
+        self.elements(x).for_each(|(ind, obj)| {
+            let hess = obj.hess * self.weight;
+            match side {
+                Some(side) => trips.extend(local_hess_trips_triangular(ind, &hess, side)),
+                None => scatter_hess(trips, ind, &hess),
+            }
+        });
+    }
+
+    fn add_compute(&self, x: &Col<f64>, computed: &mut ComputedComposite) {
+        self.elements(x).for_each(|(ind, obj)| {
+            computed.value += self.weight * obj.value;
+            scatter_grad(&mut computed.grad, ind, &(obj.grad * self.weight));
+            scatter_hess(&mut computed.hess_trips, ind, &(obj.hess * self.weight));
+        });
+    }
+}
@@ -0,0 +1,228 @@
+use crate::sparse::{composite::ComputedComposite, objective::ComputedObjective};
+use faer::{
+    sparse::{CreationError, SparseColMat},
+    Col,
+};
+
+/// A set of fixed global indices (Dirichlet boundary conditions) of a variable vector.
+///
+/// Fixed DOFs can be handled in two ways:
+/// - Elimination: `reduce*` drops them, yielding a smaller system over the free DOFs only.
+///   `free_indices` maps reduced indices back to the full vector, and `expand` scatters
+///   a reduced vector back.
+/// - Masking: `mask*` keeps the full size, zeroes their gradient entries and
+///   Hessian rows/cols, and puts identity on their diagonal. A Newton step then
+///   leaves them unchanged.
+///
+/// ## Example
+/// ```ignore
+/// let fixed = FixedDofs::new(x.nrows(), &[0, 1]);
+///
+/// let reduced = obj.compute(&x, &operand_indices, &args).reduced(&fixed);
+/// let hess = fixed.reduced_hess(&reduced.hess_trips)?;
+/// let dx = hess.sp_cholesky(Side::Lower)?.solve(-&reduced.grad);
+/// x = fixed.expand(&(fixed.reduce(&x) + dx), &x);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedDofs {
+    /// For each full index, its reduced index, or `None` if fixed
+    reduced_indices: Vec<Option<usize>>,
+    /// For each reduced index, its full index
+    free_indices: Vec<usize>,
+}
+
+impl FixedDofs {
+    /// Creates the set of fixed DOFs
+    ///
+    /// ## Arguments
+    /// - `n`: The size of the full variable vector
+    /// - `fixed_indices`: Fixed global indices, in any order, duplicates allowed
+    ///
+    /// ## Panics
+    /// If any fixed index is out of bounds
+    pub fn new(n: usize, fixed_indices: &[usize]) -> Self {
+        let mut is_fixed = vec![false; n];
+        for &i in fixed_indices {
+            assert!(i < n, "Fixed index {} out of bounds for size {}", i, n);
+            is_fixed[i] = true;
+        }
+
+        let free_indices: Vec<usize> = (0..n).filter(|&i| !is_fixed[i]).collect();
+        let mut reduced_indices = vec![None; n];
+        free_indices
+            .iter()
+            .enumerate()
+            .for_each(|(reduced, &full)| reduced_indices[full] = Some(reduced));
+
+        Self {
+            reduced_indices,
+            free_indices,
+        }
+    }
+
+    /// Returns the size of the full variable vector
+    pub fn n_full(&self) -> usize {
+        self.reduced_indices.len()
+    }
+
+    /// Returns the number of free DOFs, the size of the reduced system
+    pub fn n_free(&self) -> usize {
+        self.free_indices.len()
+    }
+
+    /// Returns whether the global index `i` is fixed
+    pub fn is_fixed(&self, i: usize) -> bool {
+        self.reduced_indices[i].is_none()
+    }
+
+    /// Returns the full index of each reduced index
+    pub fn free_indices(&self) -> &[usize] {
+        &self.free_indices
+    }
+
+    /// Returns the reduced index of the full index `i`, or `None` if it is fixed
+    pub fn reduced_index(&self, i: usize) -> Option<usize> {
+        self.reduced_indices[i]
+    }
+
+    // ################################### Elimination ###################################
+
+    /// Extracts the free entries of a full vector
+    pub fn reduce(&self, full: &Col<f64>) -> Col<f64> {
+        self.check_size(full);
+        Col::from_fn(self.n_free(), |i| full[self.free_indices[i]])
+    }
+
+    /// Builds a full vector whose free entries are taken from `reduced`
+    /// and whose fixed entries are taken from `full`
+    pub fn expand(&self, reduced: &Col<f64>, full: &Col<f64>) -> Col<f64> {
+        self.check_size(full);
+        assert_eq!(
+            reduced.nrows(),
+            self.n_free(),
+            "Reduced size mismatch: expected {}, got {}",
+            self.n_free(),
+            reduced.nrows()
+        );
+
+        let mut res = full.clone();
+        self.free_indices
+            .iter()
+            .enumerate()
+            .for_each(|(reduced_i, &full_i)| res[full_i] = reduced[reduced_i]);
+        res
+    }
+
+    /// Drops Hessian triplets in fixed rows or cols and renumbers the rest
+    pub fn reduce_trips(&self, trips: &[(usize, usize, f64)]) -> Vec<(usize, usize, f64)> {
+        trips
+            .iter()
+            .filter_map(|&(row, col, v)| {
+                Some((self.reduced_indices[row]?, self.reduced_indices[col]?, v))
+            })
+            .collect()
+    }
+
+    /// Builds the reduced Hessian, of size `n_free x n_free`, from full Hessian triplets
+    pub fn reduced_hess(
+        &self,
+        trips: &[(usize, usize, f64)],
+    ) -> Result<SparseColMat<usize, f64>, CreationError> {
+        let n = self.n_free();
+        SparseColMat::try_new_from_triplets(n, n, &self.reduce_trips(trips))
+    }
+
+    // ##################################### Masking #####################################
+
+    /// Zeroes the fixed entries of a full vector
+    pub fn mask(&self, full: &mut Col<f64>) {
+        self.check_size(full);
+        (0..self.n_full())
+            .filter(|&i| self.is_fixed(i))
+            .for_each(|i| full[i] = 0.0);
+    }
+
+    /// Drops Hessian triplets in fixed rows or cols
+    /// and puts `1.0` on the diagonal of fixed DOFs
+    pub fn mask_trips(&self, trips: &[(usize, usize, f64)]) -> Vec<(usize, usize, f64)> {
+        trips
+            .iter()
+            .copied()
+            .filter(|&(row, col, _)| !self.is_fixed(row) && !self.is_fixed(col))
+            .chain(
+                (0..self.n_full())
+                    .filter(|&i| self.is_fixed(i))
+                    .map(|i| (i, i, 1.0)),
+            )
+            .collect()
+    }
+
+    /// Builds the masked Hessian, of full size, from full Hessian triplets
+    pub fn masked_hess(
+        &self,
+        trips: &[(usize, usize, f64)],
+    ) -> Result<SparseColMat<usize, f64>, CreationError> {
+        let n = self.n_full();
+        SparseColMat::try_new_from_triplets(n, n, &self.mask_trips(trips))
+    }
+
+    fn check_size(&self, full: &Col<f64>) {
+        assert_eq!(
+            full.nrows(),
+            self.n_full(),
+            "Full size mismatch: expected {}, got {}",
+            self.n_full(),
+            full.nrows()
+        );
+    }
+}
+
+impl<const N: usize> ComputedObjective<N> {
+    /// Eliminates fixed DOFs from gradient and Hessian, see [`FixedDofs::reduce`].
+    /// The value is unchanged.
+    pub fn reduced(&self, fixed: &FixedDofs) -> Self {
+        Self {
+            value: self.value,
+            grad: fixed.reduce(&self.grad),
+            hess_trips: fixed.reduce_trips(&self.hess_trips),
+        }
+    }
+
+    /// Masks fixed DOFs in gradient and Hessian, see [`FixedDofs::mask`].
+    /// The value is unchanged.
+    pub fn masked(&self, fixed: &FixedDofs) -> Self {
+        let mut grad = self.grad.clone();
+        fixed.mask(&mut grad);
+
+        Self {
+            value: self.value,
+            grad,
+            hess_trips: fixed.mask_trips(&self.hess_trips),
+        }
+    }
+}
+
+impl ComputedComposite {
+    /// Eliminates fixed DOFs from gradient and Hessian, see [`FixedDofs::reduce`].
+    /// The value is unchanged.
+    pub fn reduced(&self, fixed: &FixedDofs) -> Self {
+        Self {
+            value: self.value,
+            grad: fixed.reduce(&self.grad),
+            hess_trips: fixed.reduce_trips(&self.hess_trips),
+        }
+    }
+
+    /// Masks fixed DOFs in gradient and Hessian, see [`FixedDofs::mask`].
+    /// The value is unchanged.
+    pub fn masked(&self, fixed: &FixedDofs) -> Self {
+        let mut grad = self.grad.clone();
+        fixed.mask(&mut grad);
+
+        Self {
+            value: self.value,
+            grad,
+            hess_trips: fixed.mask_trips(&self.hess_trips),
+        }
+    }
+}
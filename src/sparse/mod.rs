@@ -1 +1,16 @@
+/// Weighted sums of objectives with different stencil sizes.
+pub mod composite;
+/// Fixed DOFs (Dirichlet boundary conditions) in sparse assembly.
+pub mod fixed;
 pub mod objective;
+/// Matrix-free Hessian products for iterative solvers.
+pub mod operator;
+/// Parallel evaluation of objectives, enabled by the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub mod parallel;
+/// Cached Hessian sparsity patterns for allocation-free assembly.
+pub mod pattern;
+/// Vector residuals of least-squares problems and their sparse Jacobians.
+pub mod residual;
+/// Detection of structurally zero entries of local Hessians.
+pub mod sparsity;
@@ -1,33 +1,133 @@
-use crate::{make::var, types::advec, Ad};
+use crate::{
+    make::var,
+    projection::PsdProjection,
+    sparse::{
+        operator::{scatter_hess_vec, HessianOperator},
+        pattern::HessianPattern,
+        sparsity::{missing_entry, LocalSparsity, SparsityError},
+    },
+    types::{advec, mat, vec, Real},
+    Ad,
+};
 use faer::{
     sparse::{CreationError, SparseColMat},
-    Col,
+    Col, Side,
 };
 use itertools::Itertools;
 
+/// Real type of sparse objectives: a [`Real`] that faer can also store,
+/// such as `f64` or `f32`
+pub trait SparseReal: Real + faer::RealField + faer::SimpleEntity {}
+
+impl<T: Real + faer::RealField + faer::SimpleEntity> SparseReal for T {}
+
 /// Represents the computed results of an objective function evaluation
 /// including the function value, gradient, and Hessian triplets.
 ///
 /// ## Type Parameters
 /// - `N`: The problem size/dimension of a single objective
+/// - `T`: The real type, `f64` by default
 ///
 /// ## Fields
 /// - `value`: The computed objective function value
 /// - `grad`: The gradient vector (first derivatives)
 /// - `hess_trips`: Hessian matrix entries stored as (row, col, value) triplets
-pub struct ComputedObjective<const N: usize> {
-    pub value: f64,
-    pub grad: Col<f64>,
-    pub hess_trips: Vec<(usize, usize, f64)>,
+pub struct ComputedObjective<const N: usize, T: SparseReal = f64> {
+    pub value: T,
+    pub grad: Col<T>,
+    pub hess_trips: Vec<(usize, usize, T)>,
+}
+
+impl<const N: usize, T: SparseReal> Default for ComputedObjective<N, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: SparseReal> ComputedObjective<N, T> {
+    /// Creates empty results, to be used as a workspace by [`Objective::compute_into`].
+    /// Its buffers grow on first use and are reused afterwards.
+    pub fn new() -> Self {
+        Self {
+            value: T::zero(),
+            grad: Col::zeros(0),
+            hess_trips: Vec::new(),
+        }
+    }
+}
+
+/// Evaluation arguments of the elements of an [`Objective`]: either one `A` shared
+/// by all elements, or a slice or `Vec` of `A` holding one per element, such as the
+/// rest shapes of the elements of a mesh
+///
+/// The methods of [`Objective`] take shared arguments, and their `_elementwise`
+/// counterparts one per element. Wrappers such as
+/// [`ObjectiveTerm`](crate::solve::ObjectiveTerm) accept both.
+///
+/// ## Example
+/// ```ignore
+/// // One rest length shared by all springs
+/// let computed = springs.compute(&x, &edges, &1.0);
+///
+/// // One rest shape per tetrahedron
+/// let rest: Vec<TetRest> = tets.iter().map(|tet| TetRest::new(&corners(tet))).collect();
+/// let computed = material.compute_elementwise(&x, &tet_dofs, &rest);
+/// ```
+pub trait ElementArgs<A> {
+    /// Returns the arguments of the `element`-th element
+    fn element(&self, element: usize) -> &A;
+
+    /// Checks that there are arguments for `elements` elements
+    ///
+    /// ## Panics
+    /// If the arguments are per element and their count is not `elements`
+    fn check_elements(&self, _elements: usize) {}
+}
+
+/// The same arguments for all elements
+impl<A> ElementArgs<A> for A {
+    fn element(&self, _: usize) -> &A {
+        self
+    }
+}
+
+/// The arguments of each element, in the order of `operand_indices`
+impl<A> ElementArgs<A> for [A] {
+    fn element(&self, element: usize) -> &A {
+        &self[element]
+    }
+
+    fn check_elements(&self, elements: usize) {
+        assert_eq!(
+            self.len(),
+            elements,
+            "Element count mismatch: {} operand indices, {} arguments",
+            elements,
+            self.len()
+        );
+    }
+}
+
+/// The arguments of each element, in the order of `operand_indices`
+impl<A> ElementArgs<A> for Vec<A> {
+    fn element(&self, element: usize) -> &A {
+        self.as_slice().element(element)
+    }
+
+    fn check_elements(&self, elements: usize) {
+        self.as_slice().check_elements(elements);
+    }
 }
 
 /// Defines the interface for sparse objective functions
 ///
 /// ## Type Parameters
 /// - `N`: The problem size/dimension of a single objective
+/// - `T`: The real type, `f64` by default
 ///
 /// ## Associated Types
-/// - `EvalArgs`: Additional arguments needed for objective evaluation
+/// - `EvalArgs`: Additional arguments needed for objective evaluation.
+///   The `_elementwise` methods take one per element, see [`ElementArgs`]
 ///
 /// ## Example
 /// ```ignore
@@ -41,7 +141,7 @@ pub struct ComputedObjective<const N: usize> {
 ///     }
 /// }
 /// ```
-pub trait Objective<const N: usize> {
+pub trait Objective<const N: usize, T: SparseReal = f64> {
     type EvalArgs;
 
     /// Evaluates the objective function for given variables
@@ -51,8 +151,8 @@ pub trait Objective<const N: usize> {
     /// - `args`: Additional evaluation arguments
     ///
     /// ## Returns
-    /// An `Ad<N>` containing the function value, gradient and Hessian
-    fn eval(&self, variables: &advec<N, N>, args: &Self::EvalArgs) -> Ad<N>;
+    /// An `Ad<N, T>` containing the function value, gradient and Hessian
+    fn eval(&self, variables: &advec<N, N, T>, args: &Self::EvalArgs) -> Ad<N, T>;
 
     /// Helper method to evaluate objective for given indices
     ///
@@ -62,16 +162,14 @@ pub trait Objective<const N: usize> {
     /// - `args`: Additional evaluation arguments
     ///
     /// ## Returns
-    /// An `Ad<N>` containing the local evaluation results
+    /// An `Ad<N, T>` containing the local evaluation results
     fn evaluate_for_indices(
         &self,
         global_inds: [usize; N],
-        x: &Col<f64>,
+        x: &Col<T>,
         args: &Self::EvalArgs,
-    ) -> Ad<N> {
-        let vals = global_inds.map(|i| x[i]);
-        let vals_slice = vals.as_slice();
-        let vars = var::vector_from_slice(vals_slice);
+    ) -> Ad<N, T> {
+        let vars = var::vector_from_fn_of(|i| x[global_inds[i]]);
         self.eval(&vars, args)
     }
 
@@ -83,89 +181,361 @@ pub trait Objective<const N: usize> {
     /// - `args`: Additional evaluation arguments
     ///
     /// ## Returns
-    /// A `ComputedObjective<N>` containing all computed results
+    /// A `ComputedObjective<N, T>` containing all computed results
     fn compute(
         &self,
-        x: &Col<f64>,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> ComputedObjective<N, T> {
+        let mut computed = ComputedObjective::new();
+        self.compute_into(x, operand_indices, args, &mut computed);
+        computed
+    }
+
+    /// Computes value, gradient and Hessian triplets into an existing workspace,
+    /// reusing its buffers, so that repeated calls do not allocate
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector, may be large
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `computed`: The workspace, overwritten with the results
+    ///
+    /// ## Example
+    /// ```ignore
+    /// // One workspace per thread
+    /// let mut computed = ComputedObjective::new();
+    /// loop {
+    ///     obj.compute_into(&x, &operand_indices, &args, &mut computed);
+    ///     // ...
+    /// }
+    /// ```
+    fn compute_into(
+        &self,
+        x: &Col<T>,
         operand_indices: &[[usize; N]],
         args: &Self::EvalArgs,
-    ) -> ComputedObjective<N> {
-        let mut value = 0.0;
+        computed: &mut ComputedObjective<N, T>,
+    ) {
+        compute_elements_into(self, x, operand_indices, args, computed);
+    }
+
+    /// Computes value, gradient and Hessian triplets in one operation,
+    /// with separate arguments for each element
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector, may be large
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Evaluation arguments of each element, in the order of `operand_indices`
+    ///
+    /// ## Returns
+    /// A `ComputedObjective<N, T>` containing all computed results
+    ///
+    /// ## Panics
+    /// If there are not exactly as many `args` as `operand_indices`
+    fn compute_elementwise(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &[Self::EvalArgs],
+    ) -> ComputedObjective<N, T> {
+        let mut computed = ComputedObjective::new();
+        compute_elements_into(self, x, operand_indices, args, &mut computed);
+        computed
+    }
+
+    /// Computes just the objective function value
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// The computed objective function value
+    fn value(&self, x: &Col<T>, operand_indices: &[[usize; N]], args: &Self::EvalArgs) -> T {
+        elements_value(self, x, operand_indices, args)
+    }
+
+    /// Computes just the objective function value, with separate arguments for each element
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Evaluation arguments of each element, in the order of `operand_indices`
+    ///
+    /// ## Returns
+    /// The computed objective function value
+    ///
+    /// ## Panics
+    /// If there are not exactly as many `args` as `operand_indices`
+    fn value_elementwise(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &[Self::EvalArgs],
+    ) -> T {
+        elements_value(self, x, operand_indices, args)
+    }
+
+    /// Computes just the gradient vector
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// The computed gradient vector
+    fn grad(&self, x: &Col<T>, operand_indices: &[[usize; N]], args: &Self::EvalArgs) -> Col<T> {
         let mut grad = Col::zeros(x.nrows());
-        let mut hess_trips = Vec::new();
+        self.grad_into(x, operand_indices, args, &mut grad);
+        grad
+    }
 
-        for &global_inds in operand_indices {
-            let obj = self.evaluate_for_indices(global_inds, x, args);
+    /// Computes the gradient vector into an existing vector, reusing it
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `grad`: Overwritten with the gradient, resized if needed
+    fn grad_into(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        grad: &mut Col<T>,
+    ) {
+        elements_grad_into(self, x, operand_indices, args, grad);
+    }
 
-            let ind = global_inds.into_iter().enumerate();
+    /// Computes just the gradient vector, with separate arguments for each element
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Evaluation arguments of each element, in the order of `operand_indices`
+    ///
+    /// ## Returns
+    /// The computed gradient vector
+    ///
+    /// ## Panics
+    /// If there are not exactly as many `args` as `operand_indices`
+    fn grad_elementwise(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &[Self::EvalArgs],
+    ) -> Col<T> {
+        let mut grad = Col::zeros(x.nrows());
+        elements_grad_into(self, x, operand_indices, args, &mut grad);
+        grad
+    }
 
-            value += obj.value;
+    /// Computes Hessian matrix entries as triplets
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// Vector of (row, col, value) triplets representing the Hessian matrix
+    fn hess_trips(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Vec<(usize, usize, T)> {
+        let mut trips = Vec::new();
+        self.hess_trips_into(x, operand_indices, args, &mut trips);
+        trips
+    }
 
-            ind.clone()
-                .for_each(|(ilocal, iglobal)| grad[iglobal] += obj.grad[ilocal]);
+    /// Computes Hessian matrix entries as triplets into an existing vector, reusing it
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `trips`: Overwritten with the (row, col, value) triplets
+    fn hess_trips_into(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        trips: &mut Vec<(usize, usize, T)>,
+    ) {
+        elements_hess_trips_into(self, x, operand_indices, args, trips);
+    }
 
-            ind.clone().cartesian_product(ind).for_each(
-                |((ixlocal, ixglobal), (iylocal, iyglobal))| {
-                    hess_trips.push((ixglobal, iyglobal, obj.hess[(ixlocal, iylocal)]));
-                },
-            );
-        }
+    /// Computes Hessian matrix entries as triplets, with separate arguments for each element
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Evaluation arguments of each element, in the order of `operand_indices`
+    ///
+    /// ## Returns
+    /// Vector of (row, col, value) triplets representing the Hessian matrix
+    ///
+    /// ## Panics
+    /// If there are not exactly as many `args` as `operand_indices`
+    fn hess_trips_elementwise(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &[Self::EvalArgs],
+    ) -> Vec<(usize, usize, T)> {
+        let mut trips = Vec::new();
+        elements_hess_trips_into(self, x, operand_indices, args, &mut trips);
+        trips
+    }
 
-        ComputedObjective {
-            value,
-            grad,
-            hess_trips,
-        }
+    /// Computes the Hessian matrix as a sparse matrix
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// A sparse matrix representation of the Hessian
+    fn hess(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Result<SparseColMat<usize, T>, CreationError> {
+        let n = x.nrows();
+        SparseColMat::try_new_from_triplets(n, n, &self.hess_trips(x, operand_indices, args))
     }
 
-    /// Computes just the objective function value
+    /// Computes the Hessian matrix as a sparse matrix, with separate arguments for each element
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Evaluation arguments of each element, in the order of `operand_indices`
+    ///
+    /// ## Returns
+    /// A sparse matrix representation of the Hessian
+    ///
+    /// ## Panics
+    /// If there are not exactly as many `args` as `operand_indices`
+    fn hess_elementwise(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &[Self::EvalArgs],
+    ) -> Result<SparseColMat<usize, T>, CreationError> {
+        let n = x.nrows();
+        SparseColMat::try_new_from_triplets(
+            n,
+            n,
+            &self.hess_trips_elementwise(x, operand_indices, args),
+        )
+    }
+
+    /// Computes Hessian matrix entries as triplets, skipping structurally zero local entries
     ///
     /// ## Arguments
     /// - `x`: The full variable vector
     /// - `operand_indices`: Slice of indices of variables to evaluate
     /// - `args`: Additional evaluation arguments
+    /// - `sparsity`: Structurally nonzero local entries, e.g. from [`LocalSparsity::sample`]
     ///
     /// ## Returns
-    /// The computed objective function value
-    fn value(&self, x: &Col<f64>, operand_indices: &[[usize; N]], args: &Self::EvalArgs) -> f64 {
-        let mut res = 0.0;
+    /// Vector of (row, col, value) triplets of the entries in `sparsity`,
+    /// or [`SparsityError::MissingEntry`] if a local entry outside `sparsity` is nonzero
+    fn hess_trips_sparse(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        sparsity: &LocalSparsity<N>,
+    ) -> Result<Vec<(usize, usize, T)>, SparsityError> {
+        let mut trips = Vec::with_capacity(operand_indices.len() * sparsity.nnz());
 
-        operand_indices.iter().for_each(|&ind| {
-            let obj = self.evaluate_for_indices(ind, x, args);
-            res += obj.value;
-        });
+        for (element, (ind, obj)) in evaluate_elements(self, x, operand_indices, args).enumerate() {
+            if let Some((row, col)) = missing_entry(sparsity, &obj.hess) {
+                return Err(SparsityError::MissingEntry { element, row, col });
+            }
+            trips.extend(local_hess_trips_sparse(ind, &obj.hess, sparsity));
+        }
 
-        res
+        Ok(trips)
     }
 
-    /// Computes just the gradient vector
+    /// Computes the Hessian matrix as a sparse matrix, skipping structurally zero local entries
     ///
     /// ## Arguments
     /// - `x`: The full variable vector
     /// - `operand_indices`: Slice of indices of variables to evaluate
     /// - `args`: Additional evaluation arguments
+    /// - `sparsity`: Structurally nonzero local entries, e.g. from [`LocalSparsity::sample`]
     ///
     /// ## Returns
-    /// The computed gradient vector
-    fn grad(
+    /// A sparse matrix representation of the Hessian, without explicit structural zeros,
+    /// or [`SparsityError::MissingEntry`] if a local entry outside `sparsity` is nonzero
+    fn hess_sparse(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        sparsity: &LocalSparsity<N>,
+    ) -> Result<SparseColMat<usize, T>, SparsityError> {
+        let n = x.nrows();
+        let trips = self.hess_trips_sparse(x, operand_indices, args, sparsity)?;
+        Ok(SparseColMat::try_new_from_triplets(n, n, &trips)?)
+    }
+
+    /// Computes the Hessian-vector product `H * v` without assembling the global Hessian.
+    ///
+    /// This is a product of local dense Hessians, not a directional derivative: every element
+    /// is evaluated in full, including its `N * N` local Hessian, which is multiplied with the
+    /// local entries of `v` and discarded. Neither the global matrix nor its triplets are formed,
+    /// so the memory stays `O(N * N)` beyond `x` and `v`, but the cost per element is that of `eval`.
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `v`: The vector to multiply, with the size of `x`
+    ///
+    /// ## Returns
+    /// The product, with the size of `x`
+    ///
+    /// ## Panics
+    /// If `v` does not have the size of `x`
+    fn hess_vec(
         &self,
-        x: &Col<f64>,
+        x: &Col<T>,
         operand_indices: &[[usize; N]],
         args: &Self::EvalArgs,
-    ) -> Col<f64> {
+        v: &Col<T>,
+    ) -> Col<T> {
+        assert_eq!(
+            v.nrows(),
+            x.nrows(),
+            "Vector size mismatch: expected {}, got {}",
+            x.nrows(),
+            v.nrows()
+        );
+
         let mut res = Col::zeros(x.nrows());
 
-        operand_indices.iter().for_each(|&ind| {
-            let obj = self.evaluate_for_indices(ind, x, args);
-            ind.into_iter()
-                .enumerate()
-                .for_each(|(ilocal, iglobal)| res[iglobal] += obj.grad[ilocal]);
+        evaluate_elements(self, x, operand_indices, args).for_each(|(ind, obj)| {
+            scatter_hess_vec(&mut res, ind, &obj.hess, v);
         });
 
         res
     }
 
-    /// Computes Hessian matrix entries as triplets
+    /// Evaluates the Hessian as a matrix-free linear operator,
+    /// to be applied repeatedly by iterative solvers
     ///
     /// ## Arguments
     /// - `x`: The full variable vector
@@ -173,45 +543,454 @@ pub trait Objective<const N: usize> {
     /// - `args`: Additional evaluation arguments
     ///
     /// ## Returns
-    /// Vector of (row, col, value) triplets representing the Hessian matrix
-    fn hess_trips(
+    /// A [`HessianOperator`] holding the local Hessians
+    fn hess_operator(
         &self,
-        x: &Col<f64>,
+        x: &Col<T>,
         operand_indices: &[[usize; N]],
         args: &Self::EvalArgs,
-    ) -> Vec<(usize, usize, f64)> {
-        let mut trips = Vec::new();
+    ) -> HessianOperator<N, T> {
+        HessianOperator::new(self, x, operand_indices, args)
+    }
+
+    /// Computes value, gradient and Hessian triplets in one operation,
+    /// projecting each element's local Hessian before it is scattered
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector, may be large
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `projection`: Projection applied to every local Hessian
+    ///
+    /// ## Returns
+    /// A `ComputedObjective<N, T>` containing all computed results
+    fn compute_projected(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        projection: &PsdProjection,
+    ) -> ComputedObjective<N, T> {
+        let mut value = T::zero();
+        let mut grad = Col::zeros(x.nrows());
+        let mut hess_trips = Vec::with_capacity(operand_indices.len() * N * N);
 
-        operand_indices.iter().for_each(|&ind| {
-            let obj = self.evaluate_for_indices(ind, x, args);
-            let ind = ind.into_iter().enumerate();
+        for (global_inds, obj) in evaluate_elements(self, x, operand_indices, args) {
+            value += obj.value;
+            scatter_grad(&mut grad, global_inds, &obj.grad);
+            scatter_hess(&mut hess_trips, global_inds, &projection.project(&obj.hess));
+        }
 
-            ind.clone().cartesian_product(ind).for_each(
-                |((ixlocal, ixglobal), (iylocal, iyglobal))| {
-                    trips.push((ixglobal, iyglobal, obj.hess[(ixlocal, iylocal)]));
-                },
-            );
+        ComputedObjective {
+            value,
+            grad,
+            hess_trips,
+        }
+    }
+
+    /// Computes Hessian matrix entries as triplets,
+    /// projecting each element's local Hessian before it is scattered
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `projection`: Projection applied to every local Hessian
+    ///
+    /// ## Returns
+    /// Vector of (row, col, value) triplets representing the Hessian matrix
+    fn hess_trips_projected(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        projection: &PsdProjection,
+    ) -> Vec<(usize, usize, T)> {
+        let mut trips = Vec::with_capacity(operand_indices.len() * N * N);
+
+        evaluate_elements(self, x, operand_indices, args).for_each(|(ind, obj)| {
+            scatter_hess(&mut trips, ind, &projection.project(&obj.hess));
         });
 
         trips
     }
 
-    /// Computes the Hessian matrix as a sparse matrix
+    /// Computes the Hessian matrix as a sparse matrix,
+    /// projecting each element's local Hessian before it is scattered
     ///
     /// ## Arguments
     /// - `x`: The full variable vector
     /// - `operand_indices`: Slice of indices of variables to evaluate
     /// - `args`: Additional evaluation arguments
+    /// - `projection`: Projection applied to every local Hessian
     ///
     /// ## Returns
     /// A sparse matrix representation of the Hessian
-    fn hess(
+    fn hess_projected(
         &self,
-        x: &Col<f64>,
+        x: &Col<T>,
         operand_indices: &[[usize; N]],
         args: &Self::EvalArgs,
-    ) -> Result<SparseColMat<usize, f64>, CreationError> {
+        projection: &PsdProjection,
+    ) -> Result<SparseColMat<usize, T>, CreationError> {
         let n = x.nrows();
-        SparseColMat::try_new_from_triplets(n, n, &self.hess_trips(x, operand_indices, args))
+        SparseColMat::try_new_from_triplets(
+            n,
+            n,
+            &self.hess_trips_projected(x, operand_indices, args, projection),
+        )
     }
+
+    /// Computes only one triangle of the Hessian matrix as sparse triplets.
+    /// Since the Hessian is symmetric, this holds all of its information
+    /// with roughly half of the triplets.
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `side`: Which triangle to emit, diagonal included
+    ///
+    /// ## Returns
+    /// Vector of (row, col, value) triplets with `row >= col` for `Side::Lower`
+    /// and `row <= col` for `Side::Upper`
+    fn hess_trips_triangular(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        side: Side,
+    ) -> Vec<(usize, usize, T)> {
+        elements_hess_trips_triangular(self, x, operand_indices, args, side)
+    }
+
+    /// Computes one triangle of the Hessian matrix as a sparse matrix,
+    /// as read by faer's symmetric factorizations such as `sp_cholesky(side)`
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `side`: Which triangle to emit, diagonal included
+    ///
+    /// ## Returns
+    /// A triangular sparse matrix representation of the Hessian
+    fn hess_triangular(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        side: Side,
+    ) -> Result<SparseColMat<usize, T>, CreationError> {
+        let n = x.nrows();
+        SparseColMat::try_new_from_triplets(
+            n,
+            n,
+            &self.hess_trips_triangular(x, operand_indices, args, side),
+        )
+    }
+
+    /// Computes the Hessian into an existing sparse matrix, without allocation
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `pattern`: Sparsity pattern built from the same `operand_indices`
+    /// - `hess`: Hessian with the sparsity pattern of `pattern`, overwritten
+    ///
+    /// ## Panics
+    /// If `operand_indices` or `hess` do not match `pattern`
+    fn hess_in_place(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        pattern: &HessianPattern<N>,
+        hess: &mut SparseColMat<usize, T>,
+    ) {
+        pattern.check(operand_indices, hess);
+
+        let values = hess.values_mut();
+        values.fill(T::zero());
+
+        evaluate_elements(self, x, operand_indices, args)
+            .enumerate()
+            .for_each(|(element, (ind, obj))| {
+                scatter_hess_values(values, pattern.element_slots(element), ind, &obj.hess);
+            });
+    }
+
+    /// Computes value, gradient and Hessian in one operation,
+    /// writing into existing buffers without allocation
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector, may be large
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    /// - `pattern`: Sparsity pattern built from the same `operand_indices`
+    /// - `grad`: Gradient with the size of `x`, overwritten
+    /// - `hess`: Hessian with the sparsity pattern of `pattern`, overwritten
+    ///
+    /// ## Returns
+    /// The computed objective function value
+    ///
+    /// ## Panics
+    /// If `operand_indices` or `hess` do not match `pattern`, or `grad` has wrong size
+    fn compute_in_place(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+        pattern: &HessianPattern<N>,
+        grad: &mut Col<T>,
+        hess: &mut SparseColMat<usize, T>,
+    ) -> T {
+        pattern.check(operand_indices, hess);
+        assert_eq!(
+            grad.nrows(),
+            x.nrows(),
+            "Gradient size mismatch: expected {}, got {}",
+            x.nrows(),
+            grad.nrows()
+        );
+
+        let mut value = T::zero();
+        grad.fill_zero();
+        let values = hess.values_mut();
+        values.fill(T::zero());
+
+        for (element, (global_inds, obj)) in
+            evaluate_elements(self, x, operand_indices, args).enumerate()
+        {
+            value += obj.value;
+            scatter_grad(grad, global_inds, &obj.grad);
+            scatter_hess_values(
+                values,
+                pattern.element_slots(element),
+                global_inds,
+                &obj.hess,
+            );
+        }
+
+        value
+    }
+}
+
+/// Evaluates every element of an objective with its own arguments
+///
+/// ## Panics
+/// If `args` holds arguments per element, but not exactly one for every element
+pub(crate) fn evaluate_elements<'a, const N: usize, T, O, A>(
+    objective: &'a O,
+    x: &'a Col<T>,
+    operand_indices: &'a [[usize; N]],
+    args: &'a A,
+) -> impl Iterator<Item = ([usize; N], Ad<N, T>)> + 'a
+where
+    T: SparseReal,
+    O: Objective<N, T> + ?Sized,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    args.check_elements(operand_indices.len());
+
+    operand_indices
+        .iter()
+        .enumerate()
+        .map(move |(element, &ind)| {
+            let obj = objective.evaluate_for_indices(ind, x, args.element(element));
+            (ind, obj)
+        })
+}
+
+/// Generic body of [`Objective::compute_into`] and [`Objective::compute_elementwise`]
+pub(crate) fn compute_elements_into<const N: usize, T, O, A>(
+    objective: &O,
+    x: &Col<T>,
+    operand_indices: &[[usize; N]],
+    args: &A,
+    computed: &mut ComputedObjective<N, T>,
+) where
+    T: SparseReal,
+    O: Objective<N, T> + ?Sized,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    computed.value = T::zero();
+    reset_grad(&mut computed.grad, x.nrows());
+    reset_hess_trips(&mut computed.hess_trips, operand_indices.len() * N * N);
+
+    for (global_inds, obj) in evaluate_elements(objective, x, operand_indices, args) {
+        computed.value += obj.value;
+        scatter_grad(&mut computed.grad, global_inds, &obj.grad);
+        scatter_hess(&mut computed.hess_trips, global_inds, &obj.hess);
+    }
+}
+
+/// Generic body of [`Objective::value`] and [`Objective::value_elementwise`]
+pub(crate) fn elements_value<const N: usize, T, O, A>(
+    objective: &O,
+    x: &Col<T>,
+    operand_indices: &[[usize; N]],
+    args: &A,
+) -> T
+where
+    T: SparseReal,
+    O: Objective<N, T> + ?Sized,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    let mut res = T::zero();
+
+    evaluate_elements(objective, x, operand_indices, args).for_each(|(_, obj)| {
+        res += obj.value;
+    });
+
+    res
+}
+
+/// Generic body of [`Objective::grad_into`] and [`Objective::grad_elementwise`]
+pub(crate) fn elements_grad_into<const N: usize, T, O, A>(
+    objective: &O,
+    x: &Col<T>,
+    operand_indices: &[[usize; N]],
+    args: &A,
+    grad: &mut Col<T>,
+) where
+    T: SparseReal,
+    O: Objective<N, T> + ?Sized,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    reset_grad(grad, x.nrows());
+
+    evaluate_elements(objective, x, operand_indices, args).for_each(|(ind, obj)| {
+        scatter_grad(grad, ind, &obj.grad);
+    });
+}
+
+/// Generic body of [`Objective::hess_trips_into`] and [`Objective::hess_trips_elementwise`]
+pub(crate) fn elements_hess_trips_into<const N: usize, T, O, A>(
+    objective: &O,
+    x: &Col<T>,
+    operand_indices: &[[usize; N]],
+    args: &A,
+    trips: &mut Vec<(usize, usize, T)>,
+) where
+    T: SparseReal,
+    O: Objective<N, T> + ?Sized,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    reset_hess_trips(trips, operand_indices.len() * N * N);
+
+    evaluate_elements(objective, x, operand_indices, args).for_each(|(ind, obj)| {
+        scatter_hess(trips, ind, &obj.hess);
+    });
+}
+
+/// Generic body of [`Objective::hess_trips_triangular`]
+pub(crate) fn elements_hess_trips_triangular<const N: usize, T, O, A>(
+    objective: &O,
+    x: &Col<T>,
+    operand_indices: &[[usize; N]],
+    args: &A,
+    side: Side,
+) -> Vec<(usize, usize, T)>
+where
+    T: SparseReal,
+    O: Objective<N, T> + ?Sized,
+    A: ElementArgs<O::EvalArgs> + ?Sized,
+{
+    let mut trips = Vec::with_capacity(operand_indices.len() * N * (N + 1) / 2);
+
+    evaluate_elements(objective, x, operand_indices, args).for_each(|(ind, obj)| {
+        trips.extend(local_hess_trips_triangular(ind, &obj.hess, side));
+    });
+
+    trips
+}
+
+/// Zeroes a global gradient, reallocating only if its size is not `n`
+fn reset_grad<T: SparseReal>(grad: &mut Col<T>, n: usize) {
+    if grad.nrows() == n {
+        grad.fill_zero();
+    } else {
+        *grad = Col::zeros(n);
+    }
+}
+
+/// Clears global Hessian triplets, making room for `len` of them at once
+fn reset_hess_trips<T>(trips: &mut Vec<(usize, usize, T)>, len: usize) {
+    trips.clear();
+    trips.reserve(len);
+}
+
+/// Adds a local gradient into the global gradient
+pub(crate) fn scatter_grad<const N: usize, T: SparseReal>(
+    grad: &mut Col<T>,
+    global_inds: [usize; N],
+    local: &vec<N, T>,
+) {
+    global_inds
+        .into_iter()
+        .enumerate()
+        .for_each(|(ilocal, iglobal)| grad[iglobal] += local[ilocal]);
+}
+
+/// Global triplets of a local Hessian, in the order they are scattered
+pub(crate) fn local_hess_trips<const N: usize, T: Real>(
+    global_inds: [usize; N],
+    local: &mat<N, T>,
+) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+    let ind = global_inds.into_iter().enumerate();
+
+    ind.clone()
+        .cartesian_product(ind)
+        .map(|((ixlocal, ixglobal), (iylocal, iyglobal))| {
+            (ixglobal, iyglobal, local[(ixlocal, iylocal)])
+        })
+}
+
+/// Global triplets of a local Hessian, keeping only the triangle given by `side`.
+/// Filtering by global indices keeps both entries of two local operands
+/// sharing a global index, so the assembled diagonal stays correct.
+pub(crate) fn local_hess_trips_triangular<const N: usize, T: Real>(
+    global_inds: [usize; N],
+    local: &mat<N, T>,
+    side: Side,
+) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+    local_hess_trips(global_inds, local).filter(move |&(row, col, _)| match side {
+        Side::Lower => row >= col,
+        Side::Upper => row <= col,
+    })
+}
+
+/// Global triplets of the entries of a local Hessian given by `sparsity`
+pub(crate) fn local_hess_trips_sparse<'a, const N: usize, T: Real>(
+    global_inds: [usize; N],
+    local: &'a mat<N, T>,
+    sparsity: &'a LocalSparsity<N>,
+) -> impl Iterator<Item = (usize, usize, T)> + 'a {
+    sparsity
+        .entries()
+        .map(move |(row, col)| (global_inds[row], global_inds[col], local[(row, col)]))
+}
+
+/// Appends a local Hessian to the global Hessian triplets
+pub(crate) fn scatter_hess<const N: usize, T: Real>(
+    trips: &mut Vec<(usize, usize, T)>,
+    global_inds: [usize; N],
+    local: &mat<N, T>,
+) {
+    trips.extend(local_hess_trips(global_inds, local));
+}
+
+/// Adds a local Hessian into the CSC values of the global Hessian
+fn scatter_hess_values<const N: usize, T: Real>(
+    values: &mut [T],
+    slots: &[usize],
+    global_inds: [usize; N],
+    local: &mat<N, T>,
+) {
+    slots
+        .iter()
+        .zip(local_hess_trips(global_inds, local))
+        .for_each(|(&slot, (_, _, v))| values[slot] += v);
 }
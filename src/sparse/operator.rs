@@ -0,0 +1,179 @@
+use crate::{
+    sparse::objective::{evaluate_elements, ElementArgs, Objective, SparseReal},
+    types::{mat, vec},
+};
+use faer::Col;
+#[cfg(feature = "linop")]
+use faer::{
+    dyn_stack::{PodStack, SizeOverflow, StackReq},
+    linop::{BiLinOp, LinOp},
+    MatMut, MatRef, Parallelism,
+};
+
+/// The global Hessian of an objective as a matrix-free linear operator
+///
+/// Holds the local dense Hessians of all elements, evaluated once at construction,
+/// and applies them element by element without assembling the global sparse matrix.
+/// This stores `N * N` values per element, trading memory for not re-evaluating `eval` per product.
+/// With the `linop` feature enabled, it implements faer's `LinOp` and `BiLinOp`,
+/// so it can be passed straight to iterative solvers such as `faer::linop::conjugate_gradient`.
+///
+/// ## Type Parameters
+/// - `N`: The problem size/dimension of a single objective
+/// - `T`: The real type, `f64` by default
+///
+/// ## Example
+/// ```ignore
+/// let op = obj.hess_operator(&x, &operand_indices, &args);
+/// let hv = op.mul_col(&v);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HessianOperator<const N: usize, T: SparseReal = f64> {
+    n: usize,
+    elements: Vec<([usize; N], mat<N, T>)>,
+}
+
+impl<const N: usize, T: SparseReal> HessianOperator<N, T> {
+    /// Evaluates the local Hessians of an objective
+    ///
+    /// ## Arguments
+    /// - `objective`: The objective whose Hessian is represented
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// A square operator with the size of `x`
+    pub fn new<O, A>(objective: &O, x: &Col<T>, operand_indices: &[[usize; N]], args: &A) -> Self
+    where
+        O: Objective<N, T> + ?Sized,
+        A: ElementArgs<O::EvalArgs> + ?Sized,
+    {
+        let elements = evaluate_elements(objective, x, operand_indices, args)
+            .map(|(ind, obj)| (ind, obj.hess))
+            .collect();
+
+        Self {
+            n: x.nrows(),
+            elements,
+        }
+    }
+
+    /// Computes the Hessian-vector product `H * v`
+    ///
+    /// ## Panics
+    /// If `v` does not have the size of the operator
+    pub fn mul_col(&self, v: &Col<T>) -> Col<T> {
+        assert_eq!(
+            v.nrows(),
+            self.n,
+            "Vector size mismatch: expected {}, got {}",
+            self.n,
+            v.nrows()
+        );
+
+        let mut out = Col::zeros(self.n);
+        self.elements
+            .iter()
+            .for_each(|(ind, hess)| scatter_hess_vec(&mut out, *ind, hess, v));
+        out
+    }
+}
+
+#[cfg(feature = "linop")]
+impl<const N: usize, T: SparseReal> LinOp<T> for HessianOperator<N, T> {
+    fn apply_req(
+        &self,
+        _rhs_ncols: usize,
+        _parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        Ok(StackReq::empty())
+    }
+
+    fn nrows(&self) -> usize {
+        self.n
+    }
+
+    fn ncols(&self) -> usize {
+        self.n
+    }
+
+    fn apply(
+        &self,
+        mut out: MatMut<'_, T>,
+        rhs: MatRef<'_, T>,
+        _parallelism: Parallelism,
+        _stack: &mut PodStack,
+    ) {
+        out.fill_zero();
+
+        for j in 0..rhs.ncols() {
+            for (ind, hess) in &self.elements {
+                let local: vec<N, T> = vec::from_iterator(ind.iter().map(|&i| rhs.read(i, j)));
+                let prod = hess * local;
+                for (ilocal, &iglobal) in ind.iter().enumerate() {
+                    out.write(iglobal, j, out.read(iglobal, j) + prod[ilocal]);
+                }
+            }
+        }
+    }
+
+    /// Real operators are their own conjugate
+    fn conj_apply(
+        &self,
+        out: MatMut<'_, T>,
+        rhs: MatRef<'_, T>,
+        parallelism: Parallelism,
+        stack: &mut PodStack,
+    ) {
+        self.apply(out, rhs, parallelism, stack)
+    }
+}
+
+/// Hessians are symmetric, so every application is the same
+#[cfg(feature = "linop")]
+impl<const N: usize, T: SparseReal> BiLinOp<T> for HessianOperator<N, T> {
+    fn transpose_apply_req(
+        &self,
+        rhs_ncols: usize,
+        parallelism: Parallelism,
+    ) -> Result<StackReq, SizeOverflow> {
+        self.apply_req(rhs_ncols, parallelism)
+    }
+
+    fn transpose_apply(
+        &self,
+        out: MatMut<'_, T>,
+        rhs: MatRef<'_, T>,
+        parallelism: Parallelism,
+        stack: &mut PodStack,
+    ) {
+        self.apply(out, rhs, parallelism, stack)
+    }
+
+    fn adjoint_apply(
+        &self,
+        out: MatMut<'_, T>,
+        rhs: MatRef<'_, T>,
+        parallelism: Parallelism,
+        stack: &mut PodStack,
+    ) {
+        self.apply(out, rhs, parallelism, stack)
+    }
+}
+
+/// Adds the product of a local Hessian with the local entries of `v` into `out`
+pub(crate) fn scatter_hess_vec<const N: usize, T: SparseReal>(
+    out: &mut Col<T>,
+    global_inds: [usize; N],
+    local: &mat<N, T>,
+    v: &Col<T>,
+) {
+    let local_v: vec<N, T> = vec::from_iterator(global_inds.iter().map(|&i| v[i]));
+    let prod = local * local_v;
+
+    global_inds
+        .into_iter()
+        .enumerate()
+        .for_each(|(ilocal, iglobal)| out[iglobal] += prod[ilocal]);
+}
@@ -0,0 +1,297 @@
+use crate::{
+    sparse::objective::{local_hess_trips, ComputedObjective, Objective, SparseReal},
+    types::vec,
+};
+use faer::{
+    sparse::{CreationError, SparseColMat},
+    Col,
+};
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// Parallel counterparts of the [`Objective`] evaluation methods,
+/// implemented for every `Sync` objective with `Sync` evaluation arguments.
+///
+/// The elements are split into [`PAR_CHUNKS`] contiguous chunks, each accumulating
+/// its value and gradient into its own buffer on whichever thread evaluates it.
+/// The buffers are then merged pairwise in a fixed order. Since the chunks do not depend
+/// on the number of threads, the results are bitwise reproducible regardless of it.
+/// They can differ from the serial methods by rounding, as the sums are grouped differently.
+/// Hessian triplets are written in place, in the order of the serial methods.
+///
+/// ## Type Parameters
+/// - `N`: The problem size/dimension of a single objective
+/// - `T`: The real type, `f64` by default
+///
+/// ## Example
+/// ```ignore
+/// use raddy::sparse::parallel::ParObjective;
+///
+/// let computed = obj.par_compute(&x, &operand_indices, &args);
+/// ```
+pub trait ParObjective<const N: usize, T: SparseReal + Send + Sync = f64>:
+    Objective<N, T> + Sync
+where
+    Self::EvalArgs: Sync,
+{
+    /// Parallel version of [`Objective::compute`]
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector, may be large
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// A `ComputedObjective<N, T>` containing all computed results
+    fn par_compute(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> ComputedObjective<N, T> {
+        let mut hess_trips = vec![(0, 0, T::zero()); operand_indices.len() * N * N];
+        let chunk_len = chunk_len(operand_indices.len());
+
+        let chunks = operand_indices
+            .par_chunks(chunk_len)
+            .zip(hess_trips.par_chunks_mut(chunk_len * N * N))
+            .map(|(chunk, chunk_trips)| {
+                let mut sum = ChunkSum::new(chunk);
+                for (&global_inds, trips) in chunk.iter().zip(chunk_trips.chunks_mut(N * N)) {
+                    let obj = self.evaluate_for_indices(global_inds, x, args);
+                    trips
+                        .iter_mut()
+                        .zip(local_hess_trips(global_inds, &obj.hess))
+                        .for_each(|(dst, src)| *dst = src);
+                    sum.add(global_inds, obj.value, &obj.grad);
+                }
+                sum
+            })
+            .collect::<Vec<_>>();
+
+        let (value, grad) = ChunkSum::reduce(chunks).into_global(x.nrows());
+
+        ComputedObjective {
+            value,
+            grad,
+            hess_trips,
+        }
+    }
+
+    /// Parallel version of [`Objective::value`]
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// The computed objective function value
+    fn par_value(&self, x: &Col<T>, operand_indices: &[[usize; N]], args: &Self::EvalArgs) -> T {
+        let mut values = Vec::new();
+        operand_indices
+            .par_chunks(chunk_len(operand_indices.len()))
+            .map(|chunk| {
+                let mut value = T::zero();
+                chunk
+                    .iter()
+                    .for_each(|&ind| value += self.evaluate_for_indices(ind, x, args).value);
+                value
+            })
+            .collect_into_vec(&mut values);
+
+        let mut res = T::zero();
+        values.iter().for_each(|&v| res += v);
+
+        res
+    }
+
+    /// Parallel version of [`Objective::grad`]
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// The computed gradient vector
+    fn par_grad(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Col<T> {
+        let chunks = operand_indices
+            .par_chunks(chunk_len(operand_indices.len()))
+            .map(|chunk| {
+                let mut sum = ChunkSum::new(chunk);
+                for &ind in chunk {
+                    let obj = self.evaluate_for_indices(ind, x, args);
+                    sum.add(ind, obj.value, &obj.grad);
+                }
+                sum
+            })
+            .collect::<Vec<_>>();
+
+        ChunkSum::reduce(chunks).into_global(x.nrows()).1
+    }
+
+    /// Parallel version of [`Objective::hess_trips`]
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// Vector of (row, col, value) triplets representing the Hessian matrix
+    fn par_hess_trips(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Vec<(usize, usize, T)> {
+        let mut trips = vec![(0, 0, T::zero()); operand_indices.len() * N * N];
+
+        operand_indices
+            .par_iter()
+            .zip(trips.par_chunks_mut(N * N))
+            .for_each(|(&ind, trips)| {
+                let obj = self.evaluate_for_indices(ind, x, args);
+                trips
+                    .iter_mut()
+                    .zip(local_hess_trips(ind, &obj.hess))
+                    .for_each(|(dst, src)| *dst = src);
+            });
+
+        trips
+    }
+
+    /// Parallel version of [`Objective::hess`]
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// A sparse matrix representation of the Hessian
+    fn par_hess(
+        &self,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Result<SparseColMat<usize, T>, CreationError> {
+        let n = x.nrows();
+        SparseColMat::try_new_from_triplets(n, n, &self.par_hess_trips(x, operand_indices, args))
+    }
+}
+
+impl<const N: usize, T, O> ParObjective<N, T> for O
+where
+    T: SparseReal + Send + Sync,
+    O: Objective<N, T> + Sync + ?Sized,
+    O::EvalArgs: Sync,
+{
+}
+
+/// Number of contiguous chunks of elements whose values and gradients are
+/// accumulated separately. It does not depend on the number of threads,
+/// so that neither do the rounding errors of the results.
+pub const PAR_CHUNKS: usize = 64;
+
+/// Number of elements per chunk, so that there are at most [`PAR_CHUNKS`] chunks
+fn chunk_len(elements: usize) -> usize {
+    elements.div_ceil(PAR_CHUNKS).max(1)
+}
+
+/// Value and gradient of a chunk of elements, accumulated over
+/// the range of global indices the chunk touches instead of the full gradient
+struct ChunkSum<T> {
+    value: T,
+    range: Range<usize>,
+    grad: Vec<T>,
+}
+
+impl<T: SparseReal> ChunkSum<T> {
+    fn new<const N: usize>(chunk: &[[usize; N]]) -> Self {
+        let indices = || chunk.iter().flatten().copied();
+        let range = match (indices().min(), indices().max()) {
+            (Some(lo), Some(hi)) => lo..hi + 1,
+            _ => 0..0,
+        };
+
+        Self {
+            value: T::zero(),
+            grad: vec![T::zero(); range.len()],
+            range,
+        }
+    }
+
+    /// Adds the value and local gradient of one element
+    fn add<const N: usize>(&mut self, global_inds: [usize; N], value: T, grad: &vec<N, T>) {
+        self.value += value;
+        global_inds
+            .into_iter()
+            .enumerate()
+            .for_each(|(ilocal, iglobal)| self.grad[iglobal - self.range.start] += grad[ilocal]);
+    }
+
+    /// Adds `other`, the sum of the chunks following `self`
+    fn merge(mut self, other: Self) -> Self {
+        if other.range.is_empty() {
+            self.value += other.value;
+            return self;
+        }
+        if self.range.is_empty() {
+            return Self {
+                value: self.value + other.value,
+                ..other
+            };
+        }
+
+        let start = self.range.start.min(other.range.start);
+        let end = self.range.end.max(other.range.end);
+        if start < self.range.start || end > self.range.end {
+            let mut grad = vec![T::zero(); end - start];
+            grad[self.range.start - start..self.range.end - start].copy_from_slice(&self.grad);
+            self.grad = grad;
+            self.range = start..end;
+        }
+
+        self.value += other.value;
+        self.grad[other.range.start - start..other.range.end - start]
+            .iter_mut()
+            .zip(other.grad)
+            .for_each(|(dst, src)| *dst += src);
+
+        self
+    }
+
+    /// Merges consecutive chunks pairwise in parallel. The pairs only depend
+    /// on the number of chunks, so the rounding does not depend on the threads.
+    fn reduce(mut chunks: Vec<Self>) -> Self {
+        match chunks.len() {
+            0 => Self {
+                value: T::zero(),
+                range: 0..0,
+                grad: Vec::new(),
+            },
+            1 => chunks.pop().unwrap(),
+            len => {
+                let right = chunks.split_off(len / 2);
+                let (left, right) = rayon::join(|| Self::reduce(chunks), || Self::reduce(right));
+                left.merge(right)
+            }
+        }
+    }
+
+    /// Returns the value and the gradient with `n` entries
+    fn into_global(self, n: usize) -> (T, Col<T>) {
+        let mut grad = Col::zeros(n);
+        self.range
+            .zip(self.grad)
+            .for_each(|(iglobal, v)| grad[iglobal] = v);
+        (self.value, grad)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::sparse::objective::{local_hess_trips, SparseReal};
+use crate::types::mat;
+use faer::sparse::{CreationError, SparseColMat, SymbolicSparseColMat};
+use itertools::Itertools;
+
+/// Sparsity pattern of a global Hessian assembled from fixed `operand_indices`
+///
+/// Built once, it records for each element's local entry `(i, j)` the slot in
+/// the CSC value array it is accumulated into, and keeps the `operand_indices`
+/// to check that later assemblies use the same ones. Subsequent assemblies then write
+/// directly into the values of an existing [`SparseColMat`], without allocating
+/// or sorting triplets.
+///
+/// ## Type Parameters
+/// - `N`: The problem size/dimension of a single objective
+///
+/// ## Example
+/// ```ignore
+/// let pattern = HessianPattern::new(x.nrows(), &operand_indices)?;
+/// let mut hess = pattern.zeros();
+///
+/// loop {
+///     obj.hess_in_place(&x, &operand_indices, &args, &pattern, &mut hess);
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HessianPattern<const N: usize> {
+    symbolic: SymbolicSparseColMat<usize>,
+    slots: Vec<usize>,
+    operand_indices: Vec<[usize; N]>,
+}
+
+impl<const N: usize> HessianPattern<N> {
+    /// Computes the sparsity pattern
+    ///
+    /// ## Arguments
+    /// - `n`: The size of the full variable vector
+    /// - `operand_indices`: Slice of indices of variables of each element
+    ///
+    /// ## Returns
+    /// The pattern, or an error if an index is out of bounds
+    pub fn new(n: usize, operand_indices: &[[usize; N]]) -> Result<Self, CreationError> {
+        let zeros = mat::<N>::zeros();
+        let pairs = operand_indices
+            .iter()
+            .flat_map(|&inds| local_hess_trips(inds, &zeros).map(|(r, c, _)| (r, c)))
+            .collect_vec();
+
+        let (symbolic, _) = SymbolicSparseColMat::try_new_from_indices(n, n, &pairs)?;
+
+        let row_indices = symbolic.row_indices();
+        let slots = pairs
+            .iter()
+            .map(|&(row, col)| {
+                let range = symbolic.col_range(col);
+                // Every pair is present in the pattern by construction
+                range.start + row_indices[range].binary_search(&row).unwrap()
+            })
+            .collect();
+
+        Ok(Self {
+            symbolic,
+            slots,
+            operand_indices: operand_indices.to_vec(),
+        })
+    }
+
+    /// Returns the number of elements the pattern was built from
+    pub fn n_elements(&self) -> usize {
+        self.operand_indices.len()
+    }
+
+    /// Returns the number of structural non-zeros
+    pub fn nnz(&self) -> usize {
+        self.symbolic.row_indices().len()
+    }
+
+    /// Allocates a Hessian with this pattern and all values set to zero
+    pub fn zeros(&self) -> SparseColMat<usize, f64> {
+        self.zeros_of()
+    }
+
+    /// Allocates a Hessian of real type `T` with this pattern and all values set to zero
+    pub fn zeros_of<T: SparseReal>(&self) -> SparseColMat<usize, T> {
+        SparseColMat::new(self.symbolic.clone(), vec![T::zero(); self.nnz()])
+    }
+
+    /// Slots in the CSC value array of the `element`-th element's local Hessian entries,
+    /// in the order of `local_hess_trips`
+    pub(crate) fn element_slots(&self, element: usize) -> &[usize] {
+        &self.slots[element * N * N..(element + 1) * N * N]
+    }
+
+    /// Panics if `operand_indices` differ from the ones this pattern was built from,
+    /// or `hess` does not have this pattern
+    pub(crate) fn check<T: SparseReal>(
+        &self,
+        operand_indices: &[[usize; N]],
+        hess: &SparseColMat<usize, T>,
+    ) {
+        assert_eq!(
+            operand_indices.len(),
+            self.n_elements(),
+            "Element count mismatch: pattern has {}, got {}",
+            self.n_elements(),
+            operand_indices.len()
+        );
+        if let Some(element) =
+            (0..operand_indices.len()).find(|&e| operand_indices[e] != self.operand_indices[e])
+        {
+            panic!(
+                "Operand indices mismatch at element {}: pattern has {:?}, got {:?}",
+                element, self.operand_indices[element], operand_indices[element]
+            );
+        }
+        assert!(
+            hess.nrows() == self.symbolic.nrows()
+                && hess.col_ptrs() == self.symbolic.col_ptrs()
+                && hess.row_indices() == self.symbolic.row_indices(),
+            "Hessian does not have the sparsity pattern of this `HessianPattern`"
+        );
+    }
+}
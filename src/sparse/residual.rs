@@ -0,0 +1,219 @@
+use crate::{make::var, types::advec_grad, GetGrad, GetValue};
+use faer::{
+    sparse::{CreationError, SparseColMat},
+    Col,
+};
+use na::SVector;
+
+/// Represents the computed residuals of all elements together with their Jacobian
+///
+/// ## Fields
+/// - `residuals`: The stacked residual vector
+/// - `jacobian_trips`: Jacobian entries stored as (row, col, value) triplets
+#[derive(Debug, Clone)]
+pub struct ComputedResidual {
+    pub residuals: Col<f64>,
+    pub jacobian_trips: Vec<(usize, usize, f64)>,
+}
+
+/// Defines the interface for sparse vector residuals, as minimized by least squares
+///
+/// Each element maps its `N` variables to `M` residuals. The residuals of all elements
+/// are stacked into one vector, the `m`-th residual of the `e`-th element being
+/// row `e * M + m`, and the Jacobian has one column per variable of the full vector.
+///
+/// Least squares only needs the Jacobian, so residuals are evaluated with the
+/// first-order [`AdGrad`](crate::AdGrad), which does not propagate Hessians.
+///
+/// ## Type Parameters
+/// - `N`: The problem size/dimension of a single element
+/// - `M`: The number of residuals of a single element
+///
+/// ## Associated Types
+/// - `EvalArgs`: Additional arguments needed for residual evaluation
+///
+/// ## Example
+/// ```ignore
+/// struct PointToPoint;
+///
+/// impl Residual<2, 2> for PointToPoint {
+///     type EvalArgs = [f64; 2]; // Target point
+///
+///     fn eval(&self, variables: &advec_grad<2, 2>, target: &[f64; 2]) -> advec_grad<2, 2> {
+///         variables - val::grad_vector_from_slice(target)
+///     }
+/// }
+/// ```
+pub trait Residual<const N: usize, const M: usize> {
+    type EvalArgs;
+
+    /// Evaluates the residuals of one element for given variables
+    ///
+    /// ## Arguments
+    /// - `variables`: The input variables as an advec_grad
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// An `advec_grad<N, M>` of the `M` residuals with their gradients
+    fn eval(&self, variables: &advec_grad<N, N>, args: &Self::EvalArgs) -> advec_grad<N, M>;
+
+    /// Helper method to evaluate the residuals for given indices
+    ///
+    /// ## Arguments
+    /// - `global_inds`: Global indices of variables to evaluate
+    /// - `x`: The full variable vector
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// An `advec_grad<N, M>` containing the local evaluation results
+    fn evaluate_for_indices(
+        &self,
+        global_inds: [usize; N],
+        x: &Col<f64>,
+        args: &Self::EvalArgs,
+    ) -> advec_grad<N, M> {
+        let vars = var::grad_vector(&SVector::from_fn(|i, _| x[global_inds[i]]));
+        self.eval(&vars, args)
+    }
+
+    /// Computes the stacked residuals of all elements
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// The residual vector, with `operand_indices.len() * M` rows
+    fn residuals(
+        &self,
+        x: &Col<f64>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Col<f64> {
+        let mut res = Col::zeros(operand_indices.len() * M);
+
+        operand_indices
+            .iter()
+            .enumerate()
+            .for_each(|(element, &ind)| {
+                let local = self.evaluate_for_indices(ind, x, args).value();
+                (0..M).for_each(|m| res[element * M + m] = local[m]);
+            });
+
+        res
+    }
+
+    /// Computes residuals and Jacobian triplets in one operation
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// A `ComputedResidual` containing both
+    fn compute(
+        &self,
+        x: &Col<f64>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> ComputedResidual {
+        let mut residuals = Col::zeros(operand_indices.len() * M);
+        let mut jacobian_trips = Vec::with_capacity(operand_indices.len() * M * N);
+
+        operand_indices
+            .iter()
+            .enumerate()
+            .for_each(|(element, &ind)| {
+                let local = self.evaluate_for_indices(ind, x, args);
+                (0..M).for_each(|m| residuals[element * M + m] = local[m].value);
+                jacobian_trips.extend(local_jacobian_trips(element, ind, &local));
+            });
+
+        ComputedResidual {
+            residuals,
+            jacobian_trips,
+        }
+    }
+
+    /// Computes the least-squares value `0.5 * |r|^2` of the stacked residuals
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// Half the squared norm of the residuals
+    fn value(&self, x: &Col<f64>, operand_indices: &[[usize; N]], args: &Self::EvalArgs) -> f64 {
+        0.5 * self.residuals(x, operand_indices, args).squared_norm_l2()
+    }
+
+    /// Computes the Jacobian of the stacked residuals as triplets
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// Vector of (row, col, value) triplets, the rows being residuals
+    /// and the columns variables
+    fn jacobian_trips(
+        &self,
+        x: &Col<f64>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Vec<(usize, usize, f64)> {
+        let mut trips = Vec::with_capacity(operand_indices.len() * M * N);
+
+        operand_indices
+            .iter()
+            .enumerate()
+            .for_each(|(element, &ind)| {
+                let local = self.evaluate_for_indices(ind, x, args);
+                trips.extend(local_jacobian_trips(element, ind, &local));
+            });
+
+        trips
+    }
+
+    /// Computes the Jacobian of the stacked residuals as a sparse matrix
+    ///
+    /// ## Arguments
+    /// - `x`: The full variable vector
+    /// - `operand_indices`: Slice of indices of variables to evaluate
+    /// - `args`: Additional evaluation arguments
+    ///
+    /// ## Returns
+    /// A sparse matrix with `operand_indices.len() * M` rows and one column per variable
+    fn jacobian(
+        &self,
+        x: &Col<f64>,
+        operand_indices: &[[usize; N]],
+        args: &Self::EvalArgs,
+    ) -> Result<SparseColMat<usize, f64>, CreationError> {
+        SparseColMat::try_new_from_triplets(
+            operand_indices.len() * M,
+            x.nrows(),
+            &self.jacobian_trips(x, operand_indices, args),
+        )
+    }
+}
+
+/// Global triplets of the Jacobian of one element's residuals
+pub(crate) fn local_jacobian_trips<const N: usize, const M: usize>(
+    element: usize,
+    global_inds: [usize; N],
+    local: &advec_grad<N, M>,
+) -> impl Iterator<Item = (usize, usize, f64)> {
+    let jac = local.grad();
+
+    (0..M).flat_map(move |m| {
+        global_inds
+            .into_iter()
+            .enumerate()
+            .map(move |(ilocal, iglobal)| (element * M + m, iglobal, jac[(m, ilocal)]))
+    })
+}
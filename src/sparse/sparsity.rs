@@ -0,0 +1,215 @@
+use crate::sparse::objective::{ElementArgs, Objective, SparseReal};
+use crate::types::{lit, mat};
+use faer::{sparse::CreationError, Col};
+use std::fmt;
+
+/// Number of perturbed points, besides `x` itself, evaluated by [`LocalSparsity::sample`]
+const PROBES: usize = 2;
+
+/// Relative size of the perturbations of [`LocalSparsity::sample`]
+const PROBE_SCALE: f64 = 1e-3;
+
+/// Structurally nonzero entries of the local Hessian of an objective
+///
+/// Objectives often couple only some of their local variables, e.g. a sum of
+/// terms per coordinate. [`Objective::hess_trips`] still emits all `N * N` entries
+/// of every element, which the assembled matrix then stores as explicit zeros.
+/// Given a sparsity, [`Objective::hess_trips_sparse`] skips them instead.
+///
+/// [`LocalSparsity::sample`] is a heuristic: it only sees the branches `eval` takes
+/// near the sampled point. Entries of terms active elsewhere, such as barrier or
+/// contact terms below a threshold, have to be added with [`LocalSparsity::union`].
+/// [`Objective::hess_trips_sparse`] checks every element and returns a
+/// [`SparsityError`] instead of dropping a nonzero entry.
+///
+/// ## Type Parameters
+/// - `N`: The problem size/dimension of a single objective
+///
+/// ## Example
+/// ```ignore
+/// // The contact term only couples variables 0 and 2 when they are close
+/// let sparsity = LocalSparsity::sample(&obj, &x, &operand_indices, &args)
+///     .union(&LocalSparsity::from_entries(&[(0, 2)]));
+///
+/// loop {
+///     let hess = obj.hess_sparse(&x, &operand_indices, &args, &sparsity)?;
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalSparsity<const N: usize> {
+    pattern: [[bool; N]; N],
+}
+
+impl<const N: usize> LocalSparsity<N> {
+    /// Returns the sparsity with all entries nonzero, as assumed by [`Objective::hess_trips`]
+    pub fn dense() -> Self {
+        Self {
+            pattern: [[true; N]; N],
+        }
+    }
+
+    /// Builds a sparsity from local `(row, col)` entries, adding their symmetric counterparts
+    ///
+    /// ## Panics
+    /// If an index is not smaller than `N`
+    pub fn from_entries(entries: &[(usize, usize)]) -> Self {
+        let mut pattern = [[false; N]; N];
+        entries.iter().for_each(|&(row, col)| {
+            pattern[row][col] = true;
+            pattern[col][row] = true;
+        });
+
+        Self { pattern }
+    }
+
+    /// Samples which local variables interact in the Hessian of an objective
+    ///
+    /// This is a numerical heuristic, not a symbolic analysis: every element is
+    /// evaluated at `x` and at small deterministic perturbations of it, and the nonzero
+    /// entries are collected. Entries vanishing only at isolated points, such as the
+    /// second derivative of `x^3` at zero, are nonzero at one of the perturbed points.
+    ///
+    /// Branches of `eval` not taken near `x` are **not** covered. If an objective
+    /// switches terms on or off, add their entries with [`LocalSparsity::union`], or use
+    /// [`LocalSparsity::dense`]. [`Objective::hess_trips_sparse`] returns a
+    /// [`SparsityError`] when an element has a nonzero entry outside the sparsity.
+    ///
+    /// ## Arguments
+    /// - `objective`: The objective
+    /// - `x`: The full variable vector, within the domain of the objective
+    /// - `operand_indices`: Slice of indices of variables of each element
+    /// - `args`: Additional evaluation arguments, shared or per element
+    ///
+    /// ## Returns
+    /// The union of the nonzero entries of all elements at all evaluated points
+    pub fn sample<T, O, A>(
+        objective: &O,
+        x: &Col<T>,
+        operand_indices: &[[usize; N]],
+        args: &A,
+    ) -> Self
+    where
+        T: SparseReal,
+        O: Objective<N, T> + ?Sized,
+        A: ElementArgs<O::EvalArgs> + ?Sized,
+    {
+        args.check_elements(operand_indices.len());
+        let mut pattern = [[false; N]; N];
+
+        for (element, &ind) in operand_indices.iter().enumerate() {
+            for probe in 0..=PROBES {
+                let mut local = Col::<T>::from_fn(N, |i| x[ind[i]]);
+                if probe > 0 {
+                    (0..N).for_each(|i| {
+                        let h = lit::<T>(PROBE_SCALE * perturbation(probe * N + i));
+                        local[i] = local[i] + (local[i] + T::one()) * h;
+                    });
+                }
+
+                let hess = objective
+                    .evaluate_for_indices(std::array::from_fn(|i| i), &local, args.element(element))
+                    .hess;
+                union_nonzeros(&mut pattern, &hess);
+            }
+
+            if pattern.iter().flatten().all(|&nz| nz) {
+                break;
+            }
+        }
+
+        Self { pattern }
+    }
+
+    /// Returns the sparsity with the entries of both `self` and `other`
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            pattern: std::array::from_fn(|row| {
+                std::array::from_fn(|col| self.pattern[row][col] || other.pattern[row][col])
+            }),
+        }
+    }
+
+    /// Returns whether the local entry `(row, col)` is structurally nonzero
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        self.pattern[row][col]
+    }
+
+    /// Returns the number of structurally nonzero local entries
+    pub fn nnz(&self) -> usize {
+        self.pattern.iter().flatten().filter(|&&nz| nz).count()
+    }
+
+    /// Iterates over the structurally nonzero local entries, in column-major order
+    pub fn entries(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..N)
+            .flat_map(|col| (0..N).map(move |row| (row, col)))
+            .filter(|&(row, col)| self.pattern[row][col])
+    }
+}
+
+/// Errors assembling Hessians with a [`LocalSparsity`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SparsityError {
+    /// A nonzero local Hessian entry outside the sparsity, e.g. of a term not sampled
+    MissingEntry {
+        element: usize,
+        row: usize,
+        col: usize,
+    },
+    /// Failure of the sparse matrix creation
+    Creation(CreationError),
+}
+
+impl fmt::Display for SparsityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SparsityError::MissingEntry { element, row, col } => write!(
+                f,
+                "Nonzero Hessian entry ({}, {}) outside the sparsity of element {}",
+                row, col, element
+            ),
+            SparsityError::Creation(err) => write!(f, "Sparse matrix creation error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SparsityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SparsityError::Creation(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<CreationError> for SparsityError {
+    fn from(err: CreationError) -> Self {
+        SparsityError::Creation(err)
+    }
+}
+
+/// Returns the first nonzero entry of a local Hessian outside a sparsity
+pub(crate) fn missing_entry<const N: usize, T: SparseReal>(
+    sparsity: &LocalSparsity<N>,
+    hess: &mat<N, T>,
+) -> Option<(usize, usize)> {
+    (0..N)
+        .flat_map(|col| (0..N).map(move |row| (row, col)))
+        .find(|&(row, col)| !sparsity.contains(row, col) && hess[(row, col)] != T::zero())
+}
+
+/// Marks the nonzero entries of a local Hessian
+fn union_nonzeros<const N: usize, T: SparseReal>(pattern: &mut [[bool; N]; N], hess: &mat<N, T>) {
+    for (row, pattern_row) in pattern.iter_mut().enumerate() {
+        for (col, nz) in pattern_row.iter_mut().enumerate() {
+            *nz |= hess[(row, col)] != T::zero();
+        }
+    }
+}
+
+/// Deterministic perturbation in `[-1, 1)`, equidistributed over `k`
+fn perturbation(k: usize) -> f64 {
+    // Fractional parts of multiples of the golden ratio conjugate
+    2.0 * ((k + 1) as f64 * 0.618_033_988_749_895).fract() - 1.0
+}
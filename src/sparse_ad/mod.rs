@@ -0,0 +1,206 @@
+/// Implementations of operators on `&AdSparse`
+pub mod borrow_operator_traits_impl;
+pub mod operator_traits_impl;
+
+use faer::Col;
+use std::collections::BTreeMap;
+
+// ################################### Data Structure ###################################
+
+/// Automatic differentiation value with sparse gradient and Hessian
+///
+/// Unlike [`Ad<N>`](crate::Ad), derivatives are stored as maps keyed by variable index,
+/// holding only the variables a value structurally depends on. Functions of many variables,
+/// each intermediate of which depends on few, are differentiated at a cost proportional
+/// to the number of nonzeros rather than to the number of variables squared.
+/// Contributions whose chain rule coefficient is exactly zero, such as the second derivative
+/// of a linear operation, are not stored.
+///
+/// # Value getters:
+/// - `value() -> f64`: Returns the current numerical value
+/// - `grad() -> &BTreeMap<usize, f64>`: Returns the nonzero gradient entries
+/// - `hess() -> &BTreeMap<(usize, usize), f64>`: Returns the nonzero Hessian entries
+///   of the upper triangle, i.e. with keys `(i, j)` where `i <= j`
+///
+/// # Fields (private)
+/// * `value` - The current value of the function
+/// * `grad` - The gradient (first derivatives), keyed by variable index
+/// * `hess` - The upper triangle of the Hessian matrix (second derivatives), keyed by `(row, col)`
+#[derive(Debug, Clone, Default)]
+pub struct AdSparse {
+    pub(crate) value: f64,
+    pub(crate) grad: BTreeMap<usize, f64>,
+    pub(crate) hess: BTreeMap<(usize, usize), f64>,
+}
+
+// ################################### Accessors ###################################
+
+impl AdSparse {
+    /// Returns the current value of the AD variable
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the structurally nonzero gradient entries, keyed by variable index
+    pub fn grad(&self) -> &BTreeMap<usize, f64> {
+        &self.grad
+    }
+
+    /// Returns the structurally nonzero entries of the upper triangle of the Hessian,
+    /// keyed by `(row, col)` with `row <= col`
+    pub fn hess(&self) -> &BTreeMap<(usize, usize), f64> {
+        &self.hess
+    }
+
+    /// Returns the derivative w.r.t. the `i`-th variable, zero if structurally zero
+    pub fn grad_entry(&self, i: usize) -> f64 {
+        self.grad.get(&i).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the second derivative w.r.t. the `i`-th and `j`-th variables,
+    /// zero if structurally zero
+    pub fn hess_entry(&self, i: usize, j: usize) -> f64 {
+        self.hess.get(&(i.min(j), i.max(j))).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the gradient as a dense vector of `n` variables
+    ///
+    /// # Panics
+    /// If the value depends on a variable of index `n` or more
+    pub fn grad_col(&self, n: usize) -> Col<f64> {
+        let mut grad = Col::zeros(n);
+        self.grad.iter().for_each(|(&i, &g)| grad[i] = g);
+        grad
+    }
+
+    /// Returns the Hessian as (row, col, value) triplets of both triangles
+    pub fn hess_trips(&self) -> Vec<(usize, usize, f64)> {
+        let mut trips = Vec::with_capacity(2 * self.hess.len());
+        self.hess.iter().for_each(|(&(i, j), &h)| {
+            trips.push((i, j, h));
+            if i != j {
+                trips.push((j, i, h));
+            }
+        });
+        trips
+    }
+}
+
+// ################################### Public Constructors ###################################
+
+impl AdSparse {
+    /// Creates an inactive (constant) AD value, without derivatives
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    pub fn inactive_scalar(value: f64) -> Self {
+        Self {
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Creates an active AD value which is the `index`-th variable
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    /// * `index` - The index of this variable
+    pub fn active_scalar(value: f64, index: usize) -> Self {
+        Self {
+            value,
+            grad: BTreeMap::from([(index, 1.0)]),
+            hess: BTreeMap::new(),
+        }
+    }
+
+    /// Creates active AD values from a slice of f64 values,
+    /// the `i`-th being the `i`-th variable
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    pub fn active_from_slice(values: &[f64]) -> Vec<Self> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| Self::active_scalar(v, i))
+            .collect()
+    }
+}
+
+// ################################### Utils ###################################
+
+impl AdSparse {
+    fn chain(
+        value: f64, // f
+        d: f64,     // df/da
+        d2: f64,    // ddf/daa
+        a: &Self,
+    ) -> Self {
+        let mut res = Self::inactive_scalar(value);
+
+        res.add_scaled(d, a);
+        res.add_outer(d2, a, a);
+
+        res
+    }
+
+    /// Binary counterpart of `chain`
+    #[allow(clippy::too_many_arguments)]
+    fn chain2(
+        value: f64, // f
+        da: f64,    // df/da
+        db: f64,    // df/db
+        daa: f64,   // ddf/daa
+        dab: f64,   // ddf/dadb
+        dbb: f64,   // ddf/dbb
+        a: &Self,
+        b: &Self,
+    ) -> Self {
+        let mut res = Self::inactive_scalar(value);
+
+        res.add_scaled(da, a);
+        res.add_scaled(db, b);
+        res.add_outer(daa, a, a);
+        res.add_outer(dbb, b, b);
+        res.add_outer(2.0 * dab, a, b);
+
+        res
+    }
+
+    /// Adds `s` times the derivatives of `a`
+    pub(crate) fn add_scaled(&mut self, s: f64, a: &Self) {
+        if s == 0.0 {
+            return;
+        }
+        a.grad
+            .iter()
+            .for_each(|(&i, &g)| *self.grad.entry(i).or_default() += s * g);
+        a.hess
+            .iter()
+            .for_each(|(&ij, &h)| *self.hess.entry(ij).or_default() += s * h);
+    }
+
+    /// Adds the symmetric part of `s` times the outer product of the gradients of `a` and `b`
+    /// to the Hessian, i.e. `s / 2 * (ga * gb^T + gb * ga^T)`
+    fn add_outer(&mut self, s: f64, a: &Self, b: &Self) {
+        if s == 0.0 {
+            return;
+        }
+        for (&i, &ga) in &a.grad {
+            for (&j, &gb) in &b.grad {
+                // Only the upper triangle is stored, lower entries are folded onto it
+                match i.cmp(&j) {
+                    std::cmp::Ordering::Less => {
+                        *self.hess.entry((i, j)).or_default() += 0.5 * s * ga * gb
+                    }
+                    std::cmp::Ordering::Greater => {
+                        *self.hess.entry((j, i)).or_default() += 0.5 * s * ga * gb
+                    }
+                    std::cmp::Ordering::Equal => {
+                        *self.hess.entry((i, i)).or_default() += s * ga * gb
+                    }
+                }
+            }
+        }
+    }
+}
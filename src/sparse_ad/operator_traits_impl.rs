@@ -0,0 +1,408 @@
+/*
+
+This code is generated by meta/operators.py at 12:40:25 @ 2026.10.18
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdSparse;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+impl Neg for &AdSparse {
+    type Output = AdSparse;
+
+    fn neg(self) -> AdSparse {
+        AdSparse {
+            value: -self.value,
+            grad: self.grad.iter().map(|(&i, &g)| (i, -g)).collect(),
+            hess: self.hess.iter().map(|(&ij, &h)| (ij, -h)).collect(),
+        }
+    }
+}
+
+impl Neg for AdSparse {
+    type Output = AdSparse;
+
+    fn neg(self) -> AdSparse {
+        AdSparse {
+            value: -self.value,
+            grad: self.grad.iter().map(|(&i, &g)| (i, -g)).collect(),
+            hess: self.hess.iter().map(|(&ij, &h)| (ij, -h)).collect(),
+        }
+    }
+}
+
+// &T + &T
+impl Add<&AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn add(self, rhs: &AdSparse) -> Self::Output {
+        AdSparse::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, self, rhs)
+    }
+}
+
+// &T + T
+impl Add<AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn add(self, rhs: AdSparse) -> Self::Output {
+        AdSparse::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, self, &rhs)
+    }
+}
+
+// T + &T
+impl Add<&AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn add(self, rhs: &AdSparse) -> Self::Output {
+        AdSparse::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, &self, rhs)
+    }
+}
+
+// T + T
+impl Add<AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn add(self, rhs: AdSparse) -> Self::Output {
+        AdSparse::chain2(self.value + rhs.value, 1.0, 1.0, 0.0, 0.0, 0.0, &self, &rhs)
+    }
+}
+
+// &T - &T
+impl Sub<&AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn sub(self, rhs: &AdSparse) -> Self::Output {
+        AdSparse::chain2(self.value - rhs.value, 1.0, -1.0, 0.0, 0.0, 0.0, self, rhs)
+    }
+}
+
+// &T - T
+impl Sub<AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn sub(self, rhs: AdSparse) -> Self::Output {
+        AdSparse::chain2(self.value - rhs.value, 1.0, -1.0, 0.0, 0.0, 0.0, self, &rhs)
+    }
+}
+
+// T - &T
+impl Sub<&AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn sub(self, rhs: &AdSparse) -> Self::Output {
+        AdSparse::chain2(self.value - rhs.value, 1.0, -1.0, 0.0, 0.0, 0.0, &self, rhs)
+    }
+}
+
+// T - T
+impl Sub<AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn sub(self, rhs: AdSparse) -> Self::Output {
+        AdSparse::chain2(
+            self.value - rhs.value,
+            1.0,
+            -1.0,
+            0.0,
+            0.0,
+            0.0,
+            &self,
+            &rhs,
+        )
+    }
+}
+
+// &T * &T
+impl Mul<&AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn mul(self, rhs: &AdSparse) -> Self::Output {
+        AdSparse::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            self,
+            rhs,
+        )
+    }
+}
+
+// &T * T
+impl Mul<AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn mul(self, rhs: AdSparse) -> Self::Output {
+        AdSparse::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            self,
+            &rhs,
+        )
+    }
+}
+
+// T * &T
+impl Mul<&AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn mul(self, rhs: &AdSparse) -> Self::Output {
+        AdSparse::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            &self,
+            rhs,
+        )
+    }
+}
+
+// T * T
+impl Mul<AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn mul(self, rhs: AdSparse) -> Self::Output {
+        AdSparse::chain2(
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+            0.0,
+            1.0,
+            0.0,
+            &self,
+            &rhs,
+        )
+    }
+}
+
+// &T / &T
+impl Div<&AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn div(self, rhs: &AdSparse) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdSparse::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            self,
+            rhs,
+        )
+    }
+}
+
+// &T / T
+impl Div<AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn div(self, rhs: AdSparse) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdSparse::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            self,
+            &rhs,
+        )
+    }
+}
+
+// T / &T
+impl Div<&AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn div(self, rhs: &AdSparse) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdSparse::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            &self,
+            rhs,
+        )
+    }
+}
+
+// T / T
+impl Div<AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn div(self, rhs: AdSparse) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+
+        AdSparse::chain2(
+            value,
+            inv,
+            -value * inv,
+            0.0,
+            -inv * inv,
+            2.0 * value * inv * inv,
+            &self,
+            &rhs,
+        )
+    }
+}
+
+// &T % &T
+impl Rem<&AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn rem(self, rhs: &AdSparse) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// &T % T
+impl Rem<AdSparse> for &AdSparse {
+    type Output = AdSparse;
+
+    fn rem(self, rhs: AdSparse) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % &T
+impl Rem<&AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn rem(self, rhs: &AdSparse) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % T
+impl Rem<AdSparse> for AdSparse {
+    type Output = AdSparse;
+
+    fn rem(self, rhs: AdSparse) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T += &T
+// Accumulates in place, so summing many terms does not copy the sum each time
+impl AddAssign<&AdSparse> for AdSparse {
+    fn add_assign(&mut self, rhs: &AdSparse) {
+        self.value += rhs.value;
+        self.add_scaled(1.0, rhs);
+    }
+}
+
+// T += T
+// Accumulates in place, so summing many terms does not copy the sum each time
+impl AddAssign<AdSparse> for AdSparse {
+    fn add_assign(&mut self, rhs: AdSparse) {
+        self.value += rhs.value;
+        self.add_scaled(1.0, &rhs);
+    }
+}
+
+// T -= &T
+// Accumulates in place, so summing many terms does not copy the sum each time
+impl SubAssign<&AdSparse> for AdSparse {
+    fn sub_assign(&mut self, rhs: &AdSparse) {
+        self.value -= rhs.value;
+        self.add_scaled(-1.0, rhs);
+    }
+}
+
+// T -= T
+// Accumulates in place, so summing many terms does not copy the sum each time
+impl SubAssign<AdSparse> for AdSparse {
+    fn sub_assign(&mut self, rhs: AdSparse) {
+        self.value -= rhs.value;
+        self.add_scaled(-1.0, &rhs);
+    }
+}
+
+// T *= &T
+impl MulAssign<&AdSparse> for AdSparse {
+    fn mul_assign(&mut self, rhs: &AdSparse) {
+        *self = &*self * rhs;
+    }
+}
+
+// T *= T
+impl MulAssign<AdSparse> for AdSparse {
+    fn mul_assign(&mut self, rhs: AdSparse) {
+        *self = &*self * rhs;
+    }
+}
+
+// T /= &T
+impl DivAssign<&AdSparse> for AdSparse {
+    fn div_assign(&mut self, rhs: &AdSparse) {
+        *self = &*self / rhs;
+    }
+}
+
+// T /= T
+impl DivAssign<AdSparse> for AdSparse {
+    fn div_assign(&mut self, rhs: AdSparse) {
+        *self = &*self / rhs;
+    }
+}
+
+// T %= &T
+impl RemAssign<&AdSparse> for AdSparse {
+    fn rem_assign(&mut self, rhs: &AdSparse) {
+        unimplemented!();
+    }
+}
+
+// T %= T
+impl RemAssign<AdSparse> for AdSparse {
+    fn rem_assign(&mut self, rhs: AdSparse) {
+        unimplemented!();
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    check::FiniteDiff,
+    sparse::objective::Objective,
+    test::SpringEnergy,
+    types::{advec, mat, vec},
+    Ad,
+};
+use faer::Col;
+use na::Vector3;
+use rand::{thread_rng, Rng};
+
+/// `x0 * x1`, with hand-written derivatives and a wrong Hessian entry
+struct WrongBilinear;
+
+impl Objective<2> for WrongBilinear {
+    type EvalArgs = ();
+    fn eval(&self, variables: &advec<2, 2>, _: &()) -> Ad<2> {
+        let (a, b) = (variables[0].value(), variables[1].value());
+        Ad::given_vector(
+            a * b,
+            &vec::<2>::new(b, a),
+            // The off-diagonal entries should be 1
+            &mat::<2>::new(0.0, 1.0, 2.0, 0.0),
+        )
+    }
+}
+
+#[test]
+fn test_check_scalar() {
+    let mut rng = thread_rng();
+    let x = Vector3::new(
+        rng.gen_range(0.5..1.5),
+        rng.gen_range(0.5..1.5),
+        rng.gen_range(0.5..1.5),
+    );
+
+    assert_eq!(FiniteDiff::new(1e-4).step(), 1e-4);
+    let check = FiniteDiff::default().check_scalar(
+        |x: &advec<3, 3>| (&x[0] * &x[1]).sin() * x[2].exp() / (&x[0] + &x[2]).sqrt(),
+        &x,
+    );
+
+    assert_eq!(check.grad.len(), 3);
+    assert_eq!(check.hess.len(), 9);
+    assert!(check.passes(1e-6, 1e-6), "{}", check);
+    assert!(check.max_grad_error() < 1e-6);
+    assert!(check.max_hess_error() < 1e-6);
+}
+
+#[test]
+fn test_check_objective() {
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [0, 1, 4, 5]];
+    let x = faer::col::from_slice(&[0.0, 0.0, 1.6, 0.3, 0.8, 0.6]).to_owned();
+    let obj = SpringEnergy {
+        k: 2.0,
+        restlen: 1.0,
+    };
+
+    let check = FiniteDiff::default().check_objective(&obj, &x, &springs, &());
+
+    assert_eq!(check.grad.len(), 6);
+    assert_eq!(check.hess.len(), 36);
+    assert!(check.passes(1e-6, 1e-6), "{}", check);
+}
+
+#[test]
+fn test_check_reports_wrong_entries() {
+    let x = Col::from_fn(3, |i| 0.5 + i as f64);
+    // Two elements share variable 1
+    let check = FiniteDiff::default().check_objective(&WrongBilinear, &x, &[[0, 1], [1, 2]], &());
+
+    assert!(!check.passes(1e-6, 1e-6));
+    assert!(check.max_grad_error() < 1e-6);
+
+    // Each element contributes an error of 1 to its lower off-diagonal entry
+    let worst = check.worst_hess(3);
+    let mut positions: Vec<_> = worst[..2].iter().map(|e| (e.row, e.col)).collect();
+    positions.sort();
+    assert_eq!(positions, vec![(1, 0), (2, 1)]);
+    assert!(worst[..2].iter().all(|e| (e.abs_error - 1.0).abs() < 1e-6));
+    assert!(worst[2].abs_error < 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "Finite difference step must be positive")]
+fn test_finite_diff_zero_step() {
+    FiniteDiff::new(0.0);
+}
@@ -0,0 +1,147 @@
+use crate::{
+    make::val,
+    solve::NewtonSolver,
+    sparse::{composite::CompositeObjective, objective::Objective},
+    test::{SpringEnergy, EPS},
+    types::advec,
+    Ad,
+};
+use approx::assert_abs_diff_eq;
+use faer::{sparse::SparseColMat, Col, Side};
+use rand::{thread_rng, Rng};
+
+/// Penalizes the deviation of a triangle's signed area
+struct AreaEnergy;
+
+// 2d * 3nodes = 6dof
+impl Objective<6> for AreaEnergy {
+    type EvalArgs = f64;
+    fn eval(&self, variables: &advec<6, 6>, rest_area: &f64) -> Ad<6> {
+        let e1x = &variables[2] - &variables[0];
+        let e1y = &variables[3] - &variables[1];
+        let e2x = &variables[4] - &variables[0];
+        let e2y = &variables[5] - &variables[1];
+
+        let area = val::scalar(0.5) * (&e1x * &e2y - &e1y * &e2x);
+        (area - val::scalar(*rest_area)).powi(2)
+    }
+}
+
+/// Pins a 2d node to a target position
+struct AnchorEnergy;
+
+impl Objective<2> for AnchorEnergy {
+    type EvalArgs = [f64; 2];
+    fn eval(&self, variables: &advec<2, 2>, target: &[f64; 2]) -> Ad<2> {
+        let dx = &variables[0] - val::scalar(target[0]);
+        let dy = &variables[1] - val::scalar(target[1]);
+        &dx * &dx + &dy * &dy
+    }
+}
+
+fn dense(n: usize, trips: &[(usize, usize, f64)]) -> faer::Mat<f64> {
+    SparseColMat::try_new_from_triplets(n, n, trips)
+        .unwrap()
+        .to_dense()
+}
+
+#[test]
+fn test_composite_matches_terms() {
+    let mut rng = thread_rng();
+
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [0, 1, 4, 5], [4, 5, 6, 7]];
+    let triangles = vec![[0, 1, 2, 3, 4, 5], [2, 3, 6, 7, 4, 5]];
+    let anchors = vec![[0, 1]];
+    let (w_spring, w_area, w_anchor) = (1.5, 0.3, 10.0);
+
+    let total = CompositeObjective::new()
+        .with(
+            SpringEnergy {
+                k: 2.0,
+                restlen: 0.8,
+            },
+            springs.clone(),
+            (),
+            w_spring,
+        )
+        .with(AreaEnergy, triangles.clone(), 0.4, w_area)
+        .with(AnchorEnergy, anchors.clone(), [0.1, -0.2], w_anchor);
+    assert_eq!(total.len(), 3);
+
+    let x = Col::from_fn(8, |_| rng.gen_range(-1.0..1.0));
+
+    let spring = SpringEnergy {
+        k: 2.0,
+        restlen: 0.8,
+    }
+    .compute(&x, &springs, &());
+    let area = AreaEnergy.compute(&x, &triangles, &0.4);
+    let anchor = AnchorEnergy.compute(&x, &anchors, &[0.1, -0.2]);
+
+    let value = w_spring * spring.value + w_area * area.value + w_anchor * anchor.value;
+    let grad = &spring.grad * faer::scale(w_spring)
+        + &area.grad * faer::scale(w_area)
+        + &anchor.grad * faer::scale(w_anchor);
+    let hess = dense(8, &spring.hess_trips) * faer::scale(w_spring)
+        + dense(8, &area.hess_trips) * faer::scale(w_area)
+        + dense(8, &anchor.hess_trips) * faer::scale(w_anchor);
+
+    let computed = total.compute(&x);
+    assert_abs_diff_eq!(computed.value, value, epsilon = EPS);
+    assert_abs_diff_eq!(total.value(&x), value, epsilon = EPS);
+    assert_abs_diff_eq!((&computed.grad - &grad).norm_l2(), 0.0, epsilon = EPS);
+    assert_abs_diff_eq!((total.grad(&x) - &grad).norm_l2(), 0.0, epsilon = EPS);
+    assert_abs_diff_eq!(
+        (dense(8, &computed.hess_trips) - &hess).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+    assert_eq!(computed.hess_trips, total.hess_trips(&x));
+    assert_abs_diff_eq!(
+        (total.hess(&x).unwrap().to_dense() - &hess).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+
+    let lower = total.hess_triangular(&x, Side::Lower).unwrap().to_dense();
+    for i in 0..8 {
+        for j in 0..8 {
+            let expected = if i >= j { hess.read(i, j) } else { 0.0 };
+            assert_abs_diff_eq!(lower.read(i, j), expected, epsilon = EPS);
+        }
+    }
+}
+
+#[test]
+fn test_composite_empty() {
+    let total = CompositeObjective::new();
+    let x = Col::from_fn(4, |i| i as f64);
+
+    assert!(total.is_empty());
+    assert_eq!(total.value(&x), 0.0);
+    assert_eq!(total.grad(&x), Col::<f64>::zeros(4));
+    assert!(total.hess_trips(&x).is_empty());
+}
+
+#[test]
+fn test_composite_solve() {
+    // A chain of two springs whose first node is pinned
+    let total = CompositeObjective::new()
+        .with(
+            SpringEnergy {
+                k: 10.0,
+                restlen: 1.0,
+            },
+            vec![[0, 1, 2, 3], [2, 3, 4, 5]],
+            (),
+            1.0,
+        )
+        .with(AnchorEnergy, vec![[0, 1]], [1.0, 2.0], 50.0);
+
+    let x0 = faer::col::from_slice(&[0.1, 0.2, 0.5, 0.1, 1.2, 0.3]).to_owned();
+    let result = NewtonSolver::default().solve(&total, &x0);
+
+    assert!(result.converged());
+    assert_abs_diff_eq!(result.x[0], 1.0, epsilon = 1e-8);
+    assert_abs_diff_eq!(result.x[1], 2.0, epsilon = 1e-8);
+}
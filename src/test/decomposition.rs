@@ -0,0 +1,171 @@
+use crate::{
+    check::FiniteDiff,
+    make::var,
+    types::{admat, advec},
+    Ad, GetValue,
+};
+use approx::{assert_abs_diff_eq, assert_relative_eq};
+use na::{Matrix3, RealField, SVector, U3};
+use rand::{thread_rng, Rng};
+
+const N: usize = 9;
+
+fn random_vec<const L: usize>(lo: f64, hi: f64) -> SVector<f64, L> {
+    let mut rng = thread_rng();
+    SVector::from_fn(|_, _| rng.gen_range(lo..hi))
+}
+
+/// Column-major 3x3 matrix of the variables
+fn as_matrix(x: &advec<N, N>) -> admat<N, 3, 3> {
+    x.clone().reshape_generic(U3, U3)
+}
+
+/// Symmetric 3x3 matrix `(A + Aᵀ) / 2` of the variables
+fn as_symmetric(x: &advec<N, N>) -> admat<N, 3, 3> {
+    let a = as_matrix(x);
+    (&a + a.transpose()) * Ad::inactive_scalar(0.5)
+}
+
+#[test]
+fn test_real_field_values() {
+    let a = var::scalar(-1.5);
+    let b = var::scalar(2.0);
+
+    assert!(a.is_sign_negative() && b.is_sign_positive());
+    assert_eq!(a.clone().copysign(b.clone()).value(), 1.5);
+    assert_eq!(a.clone().copysign(b.clone()).grad()[0], -1.0);
+    assert_eq!(RealField::max(a.clone(), b.clone()).value(), 2.0);
+    assert_eq!(RealField::min(a.clone(), b.clone()).value(), -1.5);
+    assert_eq!(
+        RealField::clamp(a.clone(), Ad::inactive_scalar(-1.0), b.clone()).grad()[0],
+        0.0
+    );
+    assert_eq!(<Ad<1> as RealField>::pi().value(), std::f64::consts::PI);
+    assert_eq!(
+        <Ad<1> as RealField>::ln_10().value(),
+        std::f64::consts::LN_10
+    );
+    assert_eq!(<Ad<1> as RealField>::max_value().unwrap().value(), f64::MAX);
+    assert_eq!(<Ad<1> as RealField>::pi().grad()[0], 0.0);
+
+    assert_abs_diff_eq!(
+        a,
+        Ad::inactive_scalar(-1.5 + 1e-13),
+        epsilon = Ad::inactive_scalar(1e-12)
+    );
+    assert_relative_eq!(b, Ad::inactive_scalar(2.0));
+}
+
+#[test]
+fn test_try_inverse() {
+    let x = random_vec::<N>(-1.0, 1.0)
+        + SVector::<f64, N>::from_column_slice(Matrix3::<f64>::identity().scale(3.0).as_slice());
+    let a = as_matrix(&var::vector(&x));
+
+    let inv = a.clone().try_inverse().unwrap();
+    let identity = &a * &inv;
+    assert_abs_diff_eq!(
+        (identity.value() - Matrix3::identity()).norm(),
+        0.0,
+        epsilon = 1e-12
+    );
+
+    // d(A⁻¹) = -A⁻¹ dA A⁻¹, so d(A⁻¹)_{00} / dA_{ij} = -(A⁻¹)_{0i} (A⁻¹)_{j0}
+    let inv_val = inv.value();
+    for j in 0..3 {
+        for i in 0..3 {
+            assert_abs_diff_eq!(
+                inv[(0, 0)].grad()[3 * j + i],
+                -inv_val[(0, i)] * inv_val[(j, 0)],
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    let check = FiniteDiff::default().check_scalar(
+        |x| {
+            let inv = as_matrix(x).try_inverse().unwrap();
+            &inv[(0, 1)] + &inv[(2, 2)]
+        },
+        &x,
+    );
+    assert!(check.passes(1e-6, 1e-6), "{}", check);
+}
+
+#[test]
+fn test_symmetric_eigen() {
+    let x = random_vec::<N>(-1.0, 1.0);
+    let a = as_symmetric(&var::vector(&x));
+
+    let eigen = a.clone().symmetric_eigen();
+    let expected = a.value().symmetric_eigen();
+    let mut values: Vec<f64> = eigen.eigenvalues.iter().map(|e| e.value()).collect();
+    let mut expected_values: Vec<f64> = expected.eigenvalues.iter().copied().collect();
+    values.sort_by(f64::total_cmp);
+    expected_values.sort_by(f64::total_cmp);
+    for (v, e) in values.iter().zip(&expected_values) {
+        assert_abs_diff_eq!(v, e, epsilon = 1e-10);
+    }
+
+    // The sum of eigenvalues is the trace
+    let sum = eigen
+        .eigenvalues
+        .iter()
+        .fold(Ad::inactive_scalar(0.0), |acc, e| acc + e);
+    let trace = a.trace();
+    assert_abs_diff_eq!((sum.grad() - trace.grad()).norm(), 0.0, epsilon = 1e-8);
+
+    // The sum of squares of eigenvalues is the squared Frobenius norm
+    let sum_sq = eigen
+        .eigenvalues
+        .iter()
+        .fold(Ad::inactive_scalar(0.0), |acc, e| acc + e * e);
+    let norm_sq = a.norm_squared();
+    assert_abs_diff_eq!((sum_sq.grad() - norm_sq.grad()).norm(), 0.0, epsilon = 1e-8);
+    assert_abs_diff_eq!((sum_sq.hess() - norm_sq.hess()).norm(), 0.0, epsilon = 1e-6);
+}
+
+#[test]
+fn test_svd() {
+    let x = random_vec::<N>(-1.0, 1.0);
+    let a = as_matrix(&var::vector(&x));
+
+    let svd = a.clone().svd(true, true);
+    let expected = a.value().svd(false, false);
+    let mut values: Vec<f64> = svd.singular_values.iter().map(|s| s.value()).collect();
+    let mut expected_values: Vec<f64> = expected.singular_values.iter().copied().collect();
+    values.sort_by(f64::total_cmp);
+    expected_values.sort_by(f64::total_cmp);
+    for (v, e) in values.iter().zip(&expected_values) {
+        assert_abs_diff_eq!(v, e, epsilon = 1e-10);
+    }
+
+    let recomposed = svd.clone().recompose().unwrap();
+    assert_abs_diff_eq!(
+        (recomposed.value() - a.value()).norm(),
+        0.0,
+        epsilon = 1e-10
+    );
+
+    // The sum of squared singular values is the squared Frobenius norm
+    let sum_sq = svd
+        .singular_values
+        .iter()
+        .fold(Ad::inactive_scalar(0.0), |acc, s| acc + s * s);
+    let norm_sq = a.norm_squared();
+    assert_abs_diff_eq!((sum_sq.grad() - norm_sq.grad()).norm(), 0.0, epsilon = 1e-8);
+
+    // The derivative of the nuclear norm is U Vᵀ
+    let nuclear = svd
+        .singular_values
+        .iter()
+        .fold(Ad::inactive_scalar(0.0), |acc, s| acc + s);
+    let u = svd.u.as_ref().unwrap().value();
+    let v_t = svd.v_t.as_ref().unwrap().value();
+    let expected_grad = u * v_t;
+    assert_abs_diff_eq!(
+        (nuclear.grad() - SVector::<f64, N>::from_column_slice(expected_grad.as_slice())).norm(),
+        0.0,
+        epsilon = 1e-8
+    );
+}
@@ -0,0 +1,187 @@
+#![allow(unused)]
+
+use crate::make::{val, var};
+use crate::misc::symbolic_1::{grad_0, grad_beta, grad_det4, hess_0, hess_beta, hess_det4};
+use crate::misc::symbolic_3::{grad_mmsin2, hess_mmsin2};
+use crate::{
+    test::{BIG_EPS, EPS},
+    Ad, AdDyn, GetValue,
+};
+use approx::assert_abs_diff_eq;
+use na::{DMatrix, DVector, SMatrix, SVector};
+use rand::{thread_rng, Rng};
+use std::ops::{Add, Mul};
+
+#[test]
+fn test_dyn_scalar() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let sv = rng.gen_range(0.5..1.5);
+        let s = &var::dvector_from_slice(&[sv])[0];
+        let expr = s
+            .cosh()
+            .mul(s.sinh().mul(val::dscalar(1.245) / s.powi(-2)))
+            .add(s.tanh());
+        assert_abs_diff_eq!(expr.grad()[0], grad_0(sv), epsilon = EPS);
+        assert_abs_diff_eq!(expr.hess()[(0, 0)], hess_0(sv), epsilon = BIG_EPS);
+
+        let sv = rng.gen_range(-2.14514..4.919810);
+        let s = &var::dvector_from_slice(&[sv])[0];
+        let expr = s.atan2(&s.recip()) * s.sinh() + s * s.cosh().powi(-3);
+        assert_abs_diff_eq!(expr.grad()[0], grad_beta(sv), epsilon = EPS);
+        assert_abs_diff_eq!(expr.hess()[(0, 0)], hess_beta(sv), epsilon = BIG_EPS);
+    }
+}
+
+#[test]
+fn test_dyn_det4() {
+    const N: usize = 4;
+    let mut rng = thread_rng();
+    let vals: Vec<f64> = (0..N * N).map(|_| rng.gen_range(-4.0..4.0)).collect();
+
+    let s = var::dvector_from_slice(&vals);
+    // Row major, same as the static test
+    let z = DMatrix::from_row_slice(N, N, s.as_slice());
+    let det = z.determinant();
+
+    assert_abs_diff_eq!(det.value(), z.value().determinant(), epsilon = EPS);
+
+    let expected_grad = grad_det4(
+        vals[0], vals[1], vals[2], vals[3], vals[4], vals[5], vals[6], vals[7], vals[8], vals[9],
+        vals[10], vals[11], vals[12], vals[13], vals[14], vals[15],
+    );
+    let g_diff = (DVector::from_column_slice(expected_grad.as_slice()) - det.grad()).norm_squared();
+    assert_abs_diff_eq!(g_diff, 0.0, epsilon = EPS);
+
+    let expected_hess = hess_det4(
+        vals[0], vals[1], vals[2], vals[3], vals[4], vals[5], vals[6], vals[7], vals[8], vals[9],
+        vals[10], vals[11], vals[12], vals[13], vals[14], vals[15],
+    );
+    let h_diff =
+        (DMatrix::from_column_slice(16, 16, expected_hess.as_slice()) - det.hess()).norm_squared();
+    assert_abs_diff_eq!(h_diff, 0.0, epsilon = EPS);
+}
+
+#[test]
+fn test_dyn_mm() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let vals: Vec<f64> = (0..4).map(|_| rng.gen_range(-4.0..4.0)).collect();
+
+        let s = var::dvector_from_slice(&vals);
+        let mut z = DMatrix::from_row_slice(2, 2, s.as_slice());
+
+        let w = z.clone();
+        z.apply(|x| *x = x.sin());
+
+        let res = (z * w).norm().cos();
+
+        let expected_grad = grad_mmsin2(vals[0], vals[1], vals[2], vals[3]);
+        let dg = (res.grad() - DVector::from_column_slice(expected_grad.as_slice())).norm_squared();
+        assert_abs_diff_eq!(dg, 0.0, epsilon = EPS);
+
+        let expected_hess = hess_mmsin2(vals[0], vals[1], vals[2], vals[3]);
+        let dh = (res.hess() - DMatrix::from_column_slice(4, 4, expected_hess.as_slice()))
+            .norm_squared();
+        assert_abs_diff_eq!(dh, 0.0, epsilon = EPS);
+    }
+}
+
+#[test]
+fn test_dyn_norm_large() {
+    // Too large to comfortably live on the stack as `Ad<N>`
+    const N: usize = 200;
+
+    let mut rng = thread_rng();
+    let vals: Vec<f64> = (0..N).map(|_| rng.gen_range(-4.0..4.0)).collect();
+
+    let s = var::dvector_from_slice(&vals);
+    let z = s.norm();
+
+    let x = DVector::from_column_slice(&vals);
+    let norm = x.norm();
+    let expected_grad = &x / norm;
+    let expected_hess =
+        (DMatrix::<f64>::identity(N, N) - &expected_grad * expected_grad.transpose()) / norm;
+
+    assert_abs_diff_eq!(z.value(), norm, epsilon = EPS);
+    assert_abs_diff_eq!(
+        (z.grad() - expected_grad).norm_squared(),
+        0.0,
+        epsilon = EPS
+    );
+    assert_abs_diff_eq!(
+        (z.hess() - expected_hess).norm_squared(),
+        0.0,
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn test_dyn_constant_broadcast() {
+    let x = var::dvector_from_slice(&[1.5, -2.0, 0.5]);
+    let c = val::dscalar(3.0);
+    assert_eq!(c.dim(), 0);
+
+    let y = &c * &x[1] + c.clone() - &x[0] / &c;
+    assert_eq!(y.dim(), 3);
+    assert_abs_diff_eq!(y.value(), 3.0 * -2.0 + 3.0 - 1.5 / 3.0, epsilon = EPS);
+    assert_abs_diff_eq!(y.grad()[0], -1.0 / 3.0, epsilon = EPS);
+    assert_abs_diff_eq!(y.grad()[1], 3.0, epsilon = EPS);
+    assert_abs_diff_eq!(y.grad()[2], 0.0, epsilon = EPS);
+    assert_abs_diff_eq!(y.hess().norm_squared(), 0.0, epsilon = EPS);
+
+    let scaled = c * x;
+    assert_eq!(
+        scaled.value(),
+        DVector::from_column_slice(&[4.5, -6.0, 1.5])
+    );
+}
+
+#[test]
+fn test_dyn_pow_log() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let vals = [rng.gen_range(0.1..3.0), rng.gen_range(1.5..5.0)];
+        let d = var::dvector_from_slice(&vals);
+        let s: SVector<Ad<2>, 2> = var::vector_from_slice(&vals);
+
+        for (dyn_f, f) in [
+            (
+                na::ComplexField::powf(d[0].clone(), d[1].clone()),
+                na::ComplexField::powf(s[0].clone(), s[1].clone()),
+            ),
+            (
+                na::ComplexField::powc(d[1].clone(), d[0].clone()),
+                na::ComplexField::powc(s[1].clone(), s[0].clone()),
+            ),
+            (
+                na::ComplexField::log(d[0].clone(), d[1].clone()),
+                na::ComplexField::log(s[0].clone(), s[1].clone()),
+            ),
+        ] {
+            assert_abs_diff_eq!(dyn_f.value(), f.value(), epsilon = EPS);
+            assert_abs_diff_eq!(dyn_f.grad().as_slice(), f.grad().as_slice(), epsilon = EPS);
+            assert_abs_diff_eq!(dyn_f.hess().as_slice(), f.hess().as_slice(), epsilon = EPS);
+        }
+    }
+
+    // Constant exponent and base
+    let x = &var::dvector_from_slice(&[8.0])[0];
+    let f = na::ComplexField::powf(x.clone(), val::dscalar(2.0));
+    assert_abs_diff_eq!(f.grad()[0], 16.0, epsilon = EPS);
+    let f = na::ComplexField::log(x.clone(), val::dscalar(2.0));
+    assert_abs_diff_eq!(f.value(), 3.0, epsilon = EPS);
+    assert_abs_diff_eq!(f.grad()[0], 1.0 / (8.0 * 2_f64.ln()), epsilon = EPS);
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn test_dyn_dimension_mismatch() {
+    let x = var::dvector_from_slice(&[1.0, 2.0]);
+    let y = var::dvector_from_slice(&[1.0, 2.0, 3.0]);
+    let _ = &x[0] + &y[0];
+}
@@ -0,0 +1,355 @@
+use crate::{
+    check::FiniteDiff,
+    energy::{
+        Arap, Corotated, DiscreteShell, HingeRest, LinearElasticity, MassSpring, StVenantKirchhoff,
+        StableNeoHookean, SymmetricDirichlet, TetRest, TriangleRest,
+    },
+    make::var,
+    solve::{ElementwiseTerm, ObjectiveTerm, Problem},
+    sparse::{composite::CompositeObjective, objective::Objective},
+    test::EPS,
+};
+use approx::assert_abs_diff_eq;
+use na::{Rotation2, Rotation3, SVector, Vector2, Vector3};
+use rand::{thread_rng, Rng};
+
+fn perturbed<const N: usize>(x: &SVector<f64, N>, scale: f64) -> SVector<f64, N> {
+    let mut rng = thread_rng();
+    x.map(|v| v + rng.gen_range(-scale..scale))
+}
+
+fn rest_triangle() -> [Vector2<f64>; 3] {
+    [
+        Vector2::new(0.1, -0.2),
+        Vector2::new(1.3, 0.1),
+        Vector2::new(0.4, 0.9),
+    ]
+}
+
+fn rest_tet() -> [Vector3<f64>; 4] {
+    [
+        Vector3::new(0.1, -0.2, 0.0),
+        Vector3::new(1.2, 0.1, 0.2),
+        Vector3::new(0.3, 1.1, -0.1),
+        Vector3::new(0.2, 0.3, 0.9),
+    ]
+}
+
+fn flatten<const D: usize, const V: usize, const N: usize>(
+    vertices: &[SVector<f64, D>; V],
+) -> SVector<f64, N> {
+    SVector::from_iterator(vertices.iter().flat_map(|v| v.iter().copied()))
+}
+
+/// Checks derivatives, zero energy and zero gradient at rest,
+/// and invariance to rigid rotations (unless `rotation_invariant` is false)
+fn check_element<O>(energy: &O, rotation_invariant: bool)
+where
+    O: Objective<6, EvalArgs = TriangleRest> + Objective<12, EvalArgs = TetRest>,
+{
+    let tri = rest_triangle();
+    let tri_rest = TriangleRest::new(&tri);
+    let x_tri: SVector<f64, 6> = flatten(&tri);
+
+    let at_rest = Objective::<6>::eval(energy, &var::vector(&x_tri), &tri_rest);
+    assert_abs_diff_eq!(at_rest.value(), 0.0, epsilon = EPS);
+    assert_abs_diff_eq!(at_rest.grad().norm(), 0.0, epsilon = 1e-10);
+
+    let x = perturbed(&x_tri, 0.2);
+    let check =
+        FiniteDiff::default().check_scalar(|v| Objective::<6>::eval(energy, v, &tri_rest), &x);
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+
+    let tet = rest_tet();
+    let tet_rest = TetRest::new(&tet);
+    let x_tet: SVector<f64, 12> = flatten(&tet);
+
+    let at_rest = Objective::<12>::eval(energy, &var::vector(&x_tet), &tet_rest);
+    assert_abs_diff_eq!(at_rest.value(), 0.0, epsilon = EPS);
+    assert_abs_diff_eq!(at_rest.grad().norm(), 0.0, epsilon = 1e-10);
+
+    let x = perturbed(&x_tet, 0.2);
+    let check =
+        FiniteDiff::default().check_scalar(|v| Objective::<12>::eval(energy, v, &tet_rest), &x);
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+
+    if rotation_invariant {
+        let rotation = Rotation3::from_euler_angles(0.7, -0.3, 2.1);
+        let rotated: [Vector3<f64>; 4] =
+            std::array::from_fn(|i| rotation * x.fixed_rows::<3>(3 * i));
+        let x_rotated: SVector<f64, 12> = flatten(&rotated);
+
+        let before = Objective::<12>::eval(energy, &var::vector(&x), &tet_rest);
+        let after = Objective::<12>::eval(energy, &var::vector(&x_rotated), &tet_rest);
+        assert_abs_diff_eq!(before.value(), after.value(), epsilon = 1e-10);
+
+        let rotation = Rotation2::new(1.1);
+        let x = perturbed(&x_tri, 0.2);
+        let rotated: [Vector2<f64>; 3] =
+            std::array::from_fn(|i| rotation * x.fixed_rows::<2>(2 * i));
+        let x_rotated: SVector<f64, 6> = flatten(&rotated);
+
+        let before = Objective::<6>::eval(energy, &var::vector(&x), &tri_rest);
+        let after = Objective::<6>::eval(energy, &var::vector(&x_rotated), &tri_rest);
+        assert_abs_diff_eq!(before.value(), after.value(), epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn test_linear_elasticity() {
+    check_element(&LinearElasticity::from_young_poisson(10.0, 0.3), false);
+}
+
+#[test]
+fn test_corotated() {
+    check_element(&Corotated::from_young_poisson(10.0, 0.3), true);
+}
+
+#[test]
+fn test_stvk() {
+    check_element(&StVenantKirchhoff::from_young_poisson(10.0, 0.3), true);
+}
+
+#[test]
+fn test_stable_neo_hookean() {
+    let material = StableNeoHookean::from_young_poisson(10.0, 0.3);
+    check_element(&material, true);
+
+    // Inverted elements are fine
+    let tet = rest_tet();
+    let rest = TetRest::new(&tet);
+    let mut inverted = tet;
+    inverted[3].z = -inverted[3].z;
+    let x: SVector<f64, 12> = flatten(&inverted);
+
+    let check = FiniteDiff::default().check_scalar(|v| material.eval(v, &rest), &x);
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+
+    // A Poisson's ratio of zero gives `λ = 0`
+    let material = StableNeoHookean::from_young_poisson(10.0, 0.0);
+    assert_eq!(material.lambda, 0.0);
+    check_element(&material, true);
+}
+
+#[test]
+fn test_symmetric_dirichlet() {
+    check_element(&SymmetricDirichlet, true);
+
+    // Scaling is penalized in both directions
+    let tri = rest_triangle();
+    let rest = TriangleRest::new(&tri);
+    let x: SVector<f64, 6> = flatten(&tri);
+    let grown = SymmetricDirichlet
+        .eval(&var::vector(&(x * 2.0)), &rest)
+        .value();
+    let shrunk = SymmetricDirichlet
+        .eval(&var::vector(&(x * 0.5)), &rest)
+        .value();
+    assert_abs_diff_eq!(grown, shrunk, epsilon = 1e-10);
+    assert!(grown > 0.0);
+}
+
+#[test]
+fn test_arap_flattened() {
+    check_element(&Arap, true);
+
+    // A 3D triangle and its isometric flattening have zero distortion
+    let rotation = Rotation3::from_euler_angles(0.2, 0.5, -0.4);
+    let tri = rest_triangle();
+    let tri_3d = tri.map(|v| rotation * Vector3::new(v.x, v.y, 0.0));
+    let rest = TriangleRest::flattened(&tri_3d);
+    assert_abs_diff_eq!(rest.area, TriangleRest::new(&tri).area, epsilon = EPS);
+
+    let x: SVector<f64, 6> = flatten(&tri);
+    assert_abs_diff_eq!(
+        Arap.eval(&var::vector(&x), &rest).value(),
+        0.0,
+        epsilon = 1e-10
+    );
+}
+
+#[test]
+fn test_mass_spring() {
+    let spring = MassSpring { stiffness: 3.0 };
+
+    let x = SVector::<f64, 4>::new(0.1, 0.2, 1.0, 0.8);
+    let check = FiniteDiff::default().check_scalar(|v| spring.eval(v, &0.7), &x);
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+
+    let x = SVector::<f64, 6>::new(0.1, 0.2, -0.3, 1.0, 0.8, 0.4);
+    let check = FiniteDiff::default().check_scalar(|v| spring.eval(v, &0.7), &x);
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+
+    let len = (x.fixed_rows::<3>(3) - x.fixed_rows::<3>(0)).norm();
+    assert_abs_diff_eq!(
+        spring.eval(&var::vector(&x), &0.7).value(),
+        0.5 * 3.0 * (len - 0.7) * (len - 0.7),
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn test_objective_shared_args() {
+    let spring = MassSpring { stiffness: 3.0 };
+    let edges = [[0, 1, 2, 3], [2, 3, 4, 5]];
+    let x = faer::col::from_slice(&[0.1, 0.2, 1.0, 0.8, 1.5, -0.2]).to_owned();
+    let expected = Objective::<4>::value(&spring, &x, &edges, &0.7);
+
+    // Objects of `Objective` are usable, and shared arguments coerce like any reference
+    let objective: &dyn Objective<4, EvalArgs = f64> = &spring;
+    let restlen = Box::new(0.7);
+    assert_eq!(objective.value(&x, &edges, &restlen), expected);
+    assert_eq!(objective.compute(&x, &edges, &restlen).value, expected);
+    assert_eq!(
+        Objective::<4>::value_elementwise(&spring, &x, &edges, &[0.7, 0.7]),
+        expected
+    );
+}
+
+#[test]
+fn test_discrete_shell() {
+    let shell = DiscreteShell { stiffness: 2.0 };
+    let hinge = [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.1, 0.0),
+        Vector3::new(0.4, 0.8, 0.1),
+        Vector3::new(0.6, -0.9, 0.2),
+    ];
+    let rest = HingeRest::new(&hinge);
+    let x: SVector<f64, 12> = flatten(&hinge);
+
+    let at_rest = shell.eval(&var::vector(&x), &rest);
+    assert_abs_diff_eq!(at_rest.value(), 0.0, epsilon = EPS);
+    assert_abs_diff_eq!(at_rest.grad().norm(), 0.0, epsilon = 1e-10);
+
+    let x = perturbed(&x, 0.2);
+    let check = FiniteDiff::default().check_scalar(|v| shell.eval(v, &rest), &x);
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+
+    // A flat hinge has zero angle, folding either way changes its sign
+    let flat = HingeRest::new(&[
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.5, 1.0, 0.0),
+        Vector3::new(0.5, -1.0, 0.0),
+    ]);
+    let up = HingeRest::new(&[
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.5, 1.0, 0.0),
+        Vector3::new(0.5, -1.0, 0.5),
+    ]);
+    let down = HingeRest::new(&[
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.5, 1.0, 0.0),
+        Vector3::new(0.5, -1.0, -0.5),
+    ]);
+    assert_abs_diff_eq!(flat.angle, 0.0, epsilon = EPS);
+    assert_abs_diff_eq!(up.angle, -down.angle, epsilon = EPS);
+    assert_abs_diff_eq!(up.angle.abs(), 0.5_f64.atan2(1.0), epsilon = EPS);
+}
+
+#[test]
+fn test_elementwise_term() {
+    let material = StableNeoHookean::from_young_poisson(10.0, 0.3);
+
+    // Two triangles of a quad with different rest shapes
+    let positions = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.2, 1.1),
+        Vector2::new(0.0, 1.0),
+    ];
+    let triangles = [[0, 1, 2], [0, 2, 3]];
+    let rest = triangles.map(|t| TriangleRest::new(&t.map(|i| positions[i])));
+    let operand_indices = triangles.map(|t| {
+        [
+            2 * t[0],
+            2 * t[0] + 1,
+            2 * t[1],
+            2 * t[1] + 1,
+            2 * t[2],
+            2 * t[2] + 1,
+        ]
+    });
+
+    let x0: SVector<f64, 8> = flatten(&positions);
+    let x = perturbed(&x0, 0.1);
+    let x = faer::col::from_slice(x.as_slice()).to_owned();
+
+    let term = ElementwiseTerm::new(&material, &operand_indices, &rest);
+    let separate =
+        [0, 1].map(|i| ObjectiveTerm::new(&material, &operand_indices[i..i + 1], &rest[i]));
+
+    assert_abs_diff_eq!(term.value(&x), separate[..].value(&x), epsilon = EPS);
+    assert_abs_diff_eq!(
+        (term.grad(&x) - separate[..].grad(&x)).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+    let hess = |trips: Vec<(usize, usize, f64)>| {
+        faer::sparse::SparseColMat::try_new_from_triplets(8, 8, &trips)
+            .unwrap()
+            .to_dense()
+    };
+    assert_abs_diff_eq!(
+        (hess(term.hess_trips(&x)) - hess(separate[..].hess_trips(&x))).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+
+    // The elementwise objective methods take the same per-element arguments
+    let computed = material.compute_elementwise(&x, &operand_indices, &rest);
+    assert_abs_diff_eq!(computed.value, term.value(&x), epsilon = EPS);
+    assert_abs_diff_eq!(
+        material.value_elementwise(&x, &operand_indices, &rest),
+        term.value(&x),
+        epsilon = EPS
+    );
+    assert_abs_diff_eq!(
+        (material.grad_elementwise(&x, &operand_indices, &rest) - term.grad(&x)).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+    assert_abs_diff_eq!(
+        (hess(computed.hess_trips) - hess(term.hess_trips(&x))).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+    let elementwise = material
+        .hess_elementwise(&x, &operand_indices, &rest)
+        .unwrap();
+    assert_abs_diff_eq!(
+        (elementwise.to_dense() - hess(term.hess_trips(&x))).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+
+    let composite = CompositeObjective::new().with(
+        material.clone(),
+        operand_indices.to_vec(),
+        rest.to_vec(),
+        2.0,
+    );
+    assert_abs_diff_eq!(composite.value(&x), 2.0 * term.value(&x), epsilon = EPS);
+
+    // Zero at rest
+    let x0 = faer::col::from_slice(x0.as_slice()).to_owned();
+    assert_abs_diff_eq!(term.value(&x0), 0.0, epsilon = EPS);
+}
+
+#[test]
+#[should_panic(expected = "Element count mismatch")]
+fn test_per_element_args_mismatch() {
+    let rest = vec![TriangleRest::new(&rest_triangle())];
+    let x = faer::Col::zeros(6);
+    Arap.value_elementwise(&x, &[[0, 1, 2, 3, 4, 5], [0, 1, 4, 5, 2, 3]], &rest);
+}
+
+#[test]
+#[should_panic]
+fn test_elementwise_term_mismatch() {
+    let rest = [TriangleRest::new(&rest_triangle())];
+    ElementwiseTerm::new(&Arap, &[[0, 1, 2, 3, 4, 5], [0, 1, 4, 5, 2, 3]], &rest);
+}
@@ -0,0 +1,160 @@
+#![allow(unused)]
+
+use crate::make::{val, var};
+use crate::misc::symbolic_1::{grad_0, grad_beta, grad_det4, grad_kappa};
+use crate::misc::symbolic_3::grad_mmsin2;
+use crate::{test::EPS, types::advec, Ad, AdGrad, AdScalar, GetValue};
+use approx::assert_abs_diff_eq;
+use na::{Const, SMatrix, SVector};
+use rand::{thread_rng, Rng};
+use std::ops::{Add, Mul};
+
+fn spring<T: AdScalar<4>>(x: &SVector<T, 4>) -> T {
+    let dx = x[2].clone() - x[0].clone();
+    let dy = x[3].clone() - x[1].clone();
+    let len = (dx.clone() * dx + dy.clone() * dy).sqrt();
+    let stretch = len - T::inactive_scalar(1.0);
+
+    T::inactive_scalar(0.5) * stretch.clone() * stretch
+}
+
+#[test]
+fn test_grad_scalar() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let sv = rng.gen_range(0.5..1.5);
+        let s: AdGrad<1> = var::grad_scalar(sv);
+        let s = &s;
+        let expr = s
+            .cosh()
+            .mul(s.sinh().mul(val::grad_scalar(1.245) / s.powi(-2)))
+            .add(s.tanh());
+        assert_abs_diff_eq!(expr.grad()[0], grad_0(sv), epsilon = EPS);
+
+        let sv = rng.gen_range(-2.14514..4.919810);
+        let s = &var::grad_scalar(sv);
+        let expr = s.atan2(&s.recip()) * s.sinh() + s * s.cosh().powi(-3);
+        assert_abs_diff_eq!(expr.grad()[0], grad_beta(sv), epsilon = EPS);
+
+        let sv = rng.gen_range(1.0..114.514);
+        let s = &var::grad_scalar(sv);
+        let expr = s.atan2(&s.asinh()) * s.acosh() + s * s.tanh().powi(-3) - s.recip().atanh();
+        assert_abs_diff_eq!(expr.grad()[0], grad_kappa(sv), epsilon = EPS);
+    }
+}
+
+#[test]
+fn test_grad_det4() {
+    const N_TEST_MAT_4: usize = 4;
+    type NaConst = Const<N_TEST_MAT_4>;
+    const N_VEC_4: usize = N_TEST_MAT_4 * N_TEST_MAT_4;
+
+    let mut rng = thread_rng();
+    let vals: &[f64] = &(0..N_VEC_4)
+        .map(|_| rng.gen_range(-4.0..4.0))
+        .collect::<Vec<_>>();
+
+    let s: SVector<AdGrad<N_VEC_4>, N_VEC_4> = var::grad_vector_from_slice(vals);
+    let z = s
+        .clone()
+        // This reshape is COL MAJOR!!!!!!!!!!!!!
+        .reshape_generic(NaConst {}, NaConst {})
+        .transpose();
+
+    let det = z.determinant();
+    assert_abs_diff_eq!(det.value(), z.value().determinant(), epsilon = EPS);
+
+    let expected_grad = grad_det4(
+        vals[0], vals[1], vals[2], vals[3], vals[4], vals[5], vals[6], vals[7], vals[8], vals[9],
+        vals[10], vals[11], vals[12], vals[13], vals[14], vals[15],
+    );
+    let g_diff = (expected_grad - det.grad()).norm_squared();
+    assert_abs_diff_eq!(g_diff, 0.0, epsilon = EPS);
+}
+
+#[test]
+fn test_grad_mm() {
+    const N_TEST_MAT_5: usize = 2;
+    type NaConst = Const<N_TEST_MAT_5>;
+    const N_VEC_5: usize = N_TEST_MAT_5 * N_TEST_MAT_5;
+
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let vals: &[f64] = &(0..N_VEC_5)
+            .map(|_| rng.gen_range(-4.0..4.0))
+            .collect::<Vec<_>>();
+
+        let s: SVector<AdGrad<N_VEC_5>, N_VEC_5> = var::grad_vector_from_slice(vals);
+        let mut z = s
+            .clone()
+            // This reshape is COL MAJOR!!!!!!!!!!!!!
+            .reshape_generic(NaConst {}, NaConst {})
+            .transpose();
+
+        let w = z.clone();
+        z.apply(|x| *x = x.sin());
+
+        let res = (z * w).norm().cos();
+
+        let dg = (res.grad() - grad_mmsin2(vals[0], vals[1], vals[2], vals[3])).norm_squared();
+        assert_abs_diff_eq!(dg, 0.0, epsilon = EPS);
+    }
+}
+
+#[test]
+fn test_grad_matches_full() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let vals: Vec<f64> = (0..4).map(|_| rng.gen_range(-4.0..4.0)).collect();
+
+        let full: Ad<4> = spring(&var::vector_from_slice(&vals));
+        let cheap: AdGrad<4> = spring(&var::grad_vector_from_slice(&vals));
+
+        assert_abs_diff_eq!(full.value(), cheap.value(), epsilon = EPS);
+        assert_abs_diff_eq!(
+            (full.grad() - cheap.grad()).norm_squared(),
+            0.0,
+            epsilon = EPS
+        );
+    }
+}
+
+#[test]
+fn test_grad_pow_log() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let vals = [rng.gen_range(0.1..3.0), rng.gen_range(1.5..5.0)];
+        let s: SVector<Ad<2>, 2> = var::vector_from_slice(&vals);
+        let g: SVector<AdGrad<2>, 2> = var::grad_vector_from_slice(&vals);
+
+        for (cheap, full) in [
+            (
+                na::ComplexField::powf(g[0].clone(), g[1].clone()),
+                na::ComplexField::powf(s[0].clone(), s[1].clone()),
+            ),
+            (
+                na::ComplexField::powc(g[1].clone(), g[0].clone()),
+                na::ComplexField::powc(s[1].clone(), s[0].clone()),
+            ),
+            (
+                na::ComplexField::log(g[0].clone(), g[1].clone()),
+                na::ComplexField::log(s[0].clone(), s[1].clone()),
+            ),
+        ] {
+            assert_abs_diff_eq!(cheap.value(), full.value(), epsilon = EPS);
+            assert_abs_diff_eq!(cheap.grad(), full.grad(), epsilon = EPS);
+        }
+    }
+
+    // Constant exponent and base
+    let x = &var::grad_vector_from_slice::<1>(&[8.0])[0];
+    let f = na::ComplexField::powf(x.clone(), val::grad_scalar(2.0));
+    assert_abs_diff_eq!(f.grad()[0], 16.0, epsilon = EPS);
+    let f = na::ComplexField::log(x.clone(), val::grad_scalar(2.0));
+    assert_abs_diff_eq!(f.value(), 3.0, epsilon = EPS);
+    assert_abs_diff_eq!(f.grad()[0], 1.0 / (8.0 * 2_f64.ln()), epsilon = EPS);
+}
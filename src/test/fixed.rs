@@ -0,0 +1,122 @@
+use crate::{
+    sparse::{fixed::FixedDofs, objective::Objective},
+    test::{SpringEnergy, EPS},
+};
+use approx::assert_abs_diff_eq;
+use faer::{prelude::SpSolver, sparse::SparseColMat, Col};
+use rand::{thread_rng, Rng};
+
+#[test]
+fn test_fixed_index_map() {
+    let fixed = FixedDofs::new(6, &[4, 0, 4]);
+
+    assert_eq!(fixed.n_full(), 6);
+    assert_eq!(fixed.n_free(), 4);
+    assert_eq!(fixed.free_indices(), &[1, 2, 3, 5]);
+    assert_eq!(fixed.reduced_index(0), None);
+    assert_eq!(fixed.reduced_index(5), Some(3));
+    assert!(fixed.is_fixed(4));
+
+    let full = Col::from_fn(6, |i| i as f64);
+    let reduced = fixed.reduce(&full);
+    assert_eq!(reduced, Col::from_fn(4, |i| [1.0, 2.0, 3.0, 5.0][i]));
+
+    let expanded = fixed.expand(&Col::from_fn(4, |i| -(i as f64)), &full);
+    assert_eq!(
+        expanded,
+        Col::from_fn(6, |i| [0.0, 0.0, -1.0, -2.0, 4.0, -3.0][i])
+    );
+
+    let mut masked = full.clone();
+    fixed.mask(&mut masked);
+    assert_eq!(
+        masked,
+        Col::from_fn(6, |i| [0.0, 1.0, 2.0, 3.0, 0.0, 5.0][i])
+    );
+}
+
+#[test]
+fn test_fixed_reduced_and_masked() {
+    let mut rng = thread_rng();
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [0, 1, 4, 5], [4, 5, 6, 7]];
+    let obj = SpringEnergy {
+        k: 3.0,
+        restlen: 1.0,
+    };
+    let x = Col::from_fn(8, |_| rng.gen_range(-1.0..1.0));
+    let fixed = FixedDofs::new(8, &[0, 1, 6]);
+
+    let computed = obj.compute(&x, &springs, &());
+    let full_hess = obj.hess(&x, &springs, &()).unwrap().to_dense();
+
+    let reduced = computed.reduced(&fixed);
+    let reduced_hess = fixed.reduced_hess(&computed.hess_trips).unwrap().to_dense();
+    assert_eq!(reduced.value, computed.value);
+    assert_eq!(reduced.grad.nrows(), 5);
+    assert_eq!(
+        SparseColMat::try_new_from_triplets(5, 5, &reduced.hess_trips)
+            .unwrap()
+            .to_dense(),
+        reduced_hess
+    );
+
+    let masked = computed.masked(&fixed);
+    let masked_hess = fixed.masked_hess(&computed.hess_trips).unwrap().to_dense();
+    assert_eq!(masked.value, computed.value);
+
+    for (ri, &i) in fixed.free_indices().iter().enumerate() {
+        assert_eq!(reduced.grad[ri], computed.grad[i]);
+        for (rj, &j) in fixed.free_indices().iter().enumerate() {
+            assert_abs_diff_eq!(
+                reduced_hess.read(ri, rj),
+                full_hess.read(i, j),
+                epsilon = EPS
+            );
+        }
+    }
+
+    for i in 0..8 {
+        let expected = if fixed.is_fixed(i) {
+            0.0
+        } else {
+            computed.grad[i]
+        };
+        assert_eq!(masked.grad[i], expected);
+        for j in 0..8 {
+            let expected = match (fixed.is_fixed(i), fixed.is_fixed(j)) {
+                (false, false) => full_hess.read(i, j),
+                _ if i == j => 1.0,
+                _ => 0.0,
+            };
+            assert_abs_diff_eq!(masked_hess.read(i, j), expected, epsilon = EPS);
+        }
+    }
+}
+
+#[test]
+fn test_fixed_newton_step() {
+    // Both approaches give the same step, which leaves fixed DOFs untouched
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    let obj = SpringEnergy {
+        k: 3.0,
+        restlen: 1.0,
+    };
+    let x = faer::col::from_slice(&[0.0, 0.0, 0.6, 0.9, 1.1, 1.8]).to_owned();
+    let fixed = FixedDofs::new(6, &[0, 1]);
+    let computed = obj.compute(&x, &springs, &());
+
+    let reduced = computed.reduced(&fixed);
+    let h = SparseColMat::try_new_from_triplets(4, 4, &reduced.hess_trips).unwrap();
+    let dx_reduced = h.sp_lu().unwrap().solve(-&reduced.grad);
+    let x_reduced = fixed.expand(&(fixed.reduce(&x) + dx_reduced), &x);
+
+    let masked = computed.masked(&fixed);
+    let h = SparseColMat::try_new_from_triplets(6, 6, &masked.hess_trips).unwrap();
+    let dx_masked = h.sp_lu().unwrap().solve(-&masked.grad);
+    let x_masked = &x + dx_masked;
+
+    assert_eq!(x_reduced[0], 0.0);
+    assert_eq!(x_reduced[1], 0.0);
+    assert_abs_diff_eq!(x_masked[0], 0.0, epsilon = EPS);
+    assert_abs_diff_eq!((x_reduced - x_masked).norm_l2(), 0.0, epsilon = EPS);
+}
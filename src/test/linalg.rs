@@ -0,0 +1,225 @@
+use crate::{
+    check::FiniteDiff,
+    linalg::{inverse, polar, svd, symmetric_eigen, symmetric_eigenvalues},
+    make::var,
+    types::{admat, advec},
+    Ad, GetValue,
+};
+use approx::assert_abs_diff_eq;
+use na::{Const, Matrix3, SMatrix, SVector};
+use rand::{thread_rng, Rng};
+
+fn random_vec<const L: usize>(lo: f64, hi: f64) -> SVector<f64, L> {
+    let mut rng = thread_rng();
+    SVector::from_fn(|_, _| rng.gen_range(lo..hi))
+}
+
+/// Column-major square matrix of the variables
+fn as_matrix<const N: usize, const D: usize>(x: &advec<N, N>) -> admat<N, D, D> {
+    admat::<N, D, D>::from_fn(|i, j| x[i + j * D].clone())
+}
+
+/// A weighted sum of all entries, to check all their derivatives at once
+fn weighted<const N: usize, const R: usize, const C: usize>(
+    m: &admat<N, R, C>,
+    weights: &SMatrix<f64, R, C>,
+) -> Ad<N> {
+    m.iter()
+        .zip(weights.iter())
+        .fold(Ad::inactive_scalar(0.0), |acc, (x, &w)| {
+            acc + x.mul_value(w)
+        })
+}
+
+fn assert_derivatives<const N: usize>(f: impl Fn(&advec<N, N>) -> Ad<N>, x: &SVector<f64, N>) {
+    let check = FiniteDiff::default().check_scalar(f, x);
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+}
+
+#[test]
+fn test_inverse() {
+    let x2 = random_vec::<4>(-1.0, 1.0) + SVector::<f64, 4>::new(2.0, 0.0, 0.0, 2.0);
+    let x3 = random_vec::<9>(-1.0, 1.0)
+        + SVector::<f64, 9>::from_column_slice(Matrix3::identity().scale(2.0).as_slice());
+
+    let m2: admat<4, 2, 2> = as_matrix(&var::vector(&x2));
+    let inv2 = inverse(&m2).unwrap();
+    assert_abs_diff_eq!(
+        (inv2.value() - m2.value().try_inverse().unwrap()).norm(),
+        0.0,
+        epsilon = 1e-12
+    );
+
+    let m3: admat<9, 3, 3> = as_matrix(&var::vector(&x3));
+    let inv3 = inverse(&m3).unwrap();
+    assert_abs_diff_eq!(
+        (inv3.value() - m3.value().try_inverse().unwrap()).norm(),
+        0.0,
+        epsilon = 1e-12
+    );
+
+    let w2 = random_vec::<4>(-1.0, 1.0).reshape_generic(Const::<2>, Const::<2>);
+    assert_derivatives(
+        |x| weighted(&inverse::<4, 2>(&as_matrix(x)).unwrap(), &w2),
+        &x2,
+    );
+    let w3 = random_vec::<9>(-1.0, 1.0).reshape_generic(Const::<3>, Const::<3>);
+    assert_derivatives(
+        |x| weighted(&inverse::<9, 3>(&as_matrix(x)).unwrap(), &w3),
+        &x3,
+    );
+
+    let singular: admat<4, 2, 2> = as_matrix(&var::vector_from_slice(&[1.0, 2.0, 2.0, 4.0]));
+    assert!(inverse(&singular).is_none());
+}
+
+#[test]
+fn test_svd() {
+    for det_sign in [1.0, -1.0] {
+        let mut x = random_vec::<9>(-1.0, 1.0);
+        // Fix the sign of the determinant
+        if x.reshape_generic(Const::<3>, Const::<3>).determinant() * det_sign < 0.0 {
+            x.fixed_rows_mut::<3>(0).neg_mut();
+        }
+        let m: admat<9, 3, 3> = as_matrix(&var::vector(&x));
+        let a = m.value();
+
+        let decomposition = svd(&m);
+        let (u, sigma, v) = (
+            decomposition.u.value(),
+            decomposition.singular_values.value(),
+            decomposition.v.value(),
+        );
+
+        assert_abs_diff_eq!(u.determinant(), 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(v.determinant(), 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(
+            (u * Matrix3::from_diagonal(&sigma) * v.transpose() - a).norm(),
+            0.0,
+            epsilon = 1e-10
+        );
+        assert_eq!(
+            sigma.iter().filter(|&&s| s < 0.0).count(),
+            (det_sign < 0.0) as usize
+        );
+
+        let ws = random_vec::<3>(-1.0, 1.0);
+        let wm = random_vec::<9>(-1.0, 1.0).reshape_generic(Const::<3>, Const::<3>);
+        assert_derivatives(
+            |x| weighted(&svd::<9, 3>(&as_matrix(x)).singular_values, &ws),
+            &x,
+        );
+        assert_derivatives(|x| weighted(&svd::<9, 3>(&as_matrix(x)).u, &wm), &x);
+        assert_derivatives(|x| weighted(&svd::<9, 3>(&as_matrix(x)).v, &wm), &x);
+    }
+}
+
+#[test]
+fn test_svd_2d() {
+    let x = random_vec::<4>(-1.0, 1.0);
+    let wm = random_vec::<4>(-1.0, 1.0).reshape_generic(Const::<2>, Const::<2>);
+    let ws = random_vec::<2>(-1.0, 1.0);
+
+    assert_derivatives(
+        |x| weighted(&svd::<4, 2>(&as_matrix(x)).singular_values, &ws),
+        &x,
+    );
+    assert_derivatives(|x| weighted(&svd::<4, 2>(&as_matrix(x)).u, &wm), &x);
+    assert_derivatives(|x| weighted(&polar::<4, 2>(&as_matrix(x)).r, &wm), &x);
+    assert_derivatives(|x| weighted(&polar::<4, 2>(&as_matrix(x)).s, &wm), &x);
+}
+
+#[test]
+fn test_polar() {
+    let x = random_vec::<9>(-1.0, 1.0);
+    let m: admat<9, 3, 3> = as_matrix(&var::vector(&x));
+    let polar3 = polar(&m);
+    let (r, s) = (polar3.r.value(), polar3.s.value());
+
+    assert_abs_diff_eq!(
+        (r * r.transpose() - Matrix3::identity()).norm(),
+        0.0,
+        epsilon = 1e-10
+    );
+    assert_abs_diff_eq!(r.determinant(), 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!((s - s.transpose()).norm(), 0.0, epsilon = 1e-12);
+    assert_abs_diff_eq!((r * s - m.value()).norm(), 0.0, epsilon = 1e-10);
+
+    let wm = random_vec::<9>(-1.0, 1.0).reshape_generic(Const::<3>, Const::<3>);
+    assert_derivatives(|x| weighted(&polar::<9, 3>(&as_matrix(x)).r, &wm), &x);
+    assert_derivatives(|x| weighted(&polar::<9, 3>(&as_matrix(x)).s, &wm), &x);
+}
+
+#[test]
+fn test_polar_degenerate() {
+    // Repeated singular values: the rotation is still smooth
+    let q = na::Rotation3::from_euler_angles(0.3, -0.4, 1.2).into_inner();
+    let a = q * Matrix3::from_diagonal(&na::Vector3::new(2.0, 2.0, 0.5));
+    let x = SVector::<f64, 9>::from_column_slice(a.as_slice());
+
+    let m: admat<9, 3, 3> = as_matrix(&var::vector(&x));
+    let p = polar(&m);
+    assert_abs_diff_eq!((p.r.value() - q).norm(), 0.0, epsilon = 1e-10);
+
+    let wm = random_vec::<9>(-1.0, 1.0).reshape_generic(Const::<3>, Const::<3>);
+    assert_derivatives(|x| weighted(&polar::<9, 3>(&as_matrix(x)).r, &wm), &x);
+
+    // The trace of S is a symmetric function of the singular values
+    assert_derivatives(|x| polar::<9, 3>(&as_matrix(x)).s.trace(), &x);
+}
+
+#[test]
+fn test_symmetric_eigen() {
+    let x = random_vec::<9>(-1.0, 1.0);
+    let m: admat<9, 3, 3> = as_matrix(&var::vector(&x));
+    let eigen = symmetric_eigen(&m);
+    let (lambda, q) = (eigen.eigenvalues.value(), eigen.eigenvectors.value());
+
+    let sym = (m.value() + m.value().transpose()) * 0.5;
+    assert!(lambda[0] <= lambda[1] && lambda[1] <= lambda[2]);
+    assert_abs_diff_eq!(
+        (q * Matrix3::from_diagonal(&lambda) * q.transpose() - sym).norm(),
+        0.0,
+        epsilon = 1e-10
+    );
+
+    let wl = random_vec::<3>(-1.0, 1.0);
+    let wm = random_vec::<9>(-1.0, 1.0).reshape_generic(Const::<3>, Const::<3>);
+    assert_derivatives(
+        |x| weighted(&symmetric_eigenvalues::<9, 3>(&as_matrix(x)), &wl),
+        &x,
+    );
+    assert_derivatives(
+        |x| weighted(&symmetric_eigen::<9, 3>(&as_matrix(x)).eigenvectors, &wm),
+        &x,
+    );
+
+    let x = random_vec::<4>(-1.0, 1.0);
+    let wl = random_vec::<2>(-1.0, 1.0);
+    assert_derivatives(
+        |x| weighted(&symmetric_eigenvalues::<4, 2>(&as_matrix(x)), &wl),
+        &x,
+    );
+}
+
+#[test]
+fn test_symmetric_eigen_degenerate() {
+    let q = na::Rotation3::from_euler_angles(0.3, -0.4, 1.2).into_inner();
+    let a = q * Matrix3::from_diagonal(&na::Vector3::new(1.0, 1.0, 3.0)) * q.transpose();
+    let x = SVector::<f64, 9>::from_column_slice(a.as_slice());
+
+    // Repeated eigenvalues are not twice differentiable individually, but their sum is.
+    // Nearby, the eigenvectors of the pair turn fast, so differences need a larger step.
+    let check = FiniteDiff::new(1e-3).check_scalar(
+        |x| {
+            let lambda = symmetric_eigenvalues::<9, 3>(&as_matrix(x));
+            &lambda[0] + &lambda[1]
+        },
+        &x,
+    );
+    assert!(check.passes(1e-6, 1e-5), "{}", check);
+    assert_derivatives(
+        |x| symmetric_eigenvalues::<9, 3>(&as_matrix(x))[2].clone(),
+        &x,
+    );
+}
@@ -5,7 +5,7 @@ use crate::misc::symbolic_3::{grad_mmsin2, hess_mmsin2};
 use crate::{
     misc::symbolic_1::{grad_det3, grad_det4, hess_det3, hess_det4},
     test::EPS,
-    Ad, GetValue,
+    Ad, GetGrad, GetHess, GetValue,
 };
 use approx::assert_abs_diff_eq;
 use na::{Const, SMatrix, SVector};
@@ -185,3 +185,82 @@ fn test_mm() {
         println!("Test mm iter {}", i);
     }
 }
+
+#[test]
+fn test_jacobian() {
+    let (a, b): (f64, f64) = (0.7, -1.3);
+
+    let x = var::vector_from_slice::<2>(&[a, b]);
+    let v = SVector::<Ad<2>, 3>::new(&x[0] * &x[1], x[0].sin(), x[1].powi(2));
+
+    let jac = v.grad();
+    let expected = SMatrix::<f64, 3, 2>::new(b, a, a.cos(), 0.0, 0.0, 2.0 * b);
+    assert_abs_diff_eq!((jac - expected).norm(), 0.0, epsilon = EPS);
+
+    let hess = v.hess();
+    let expected = [
+        SMatrix::<f64, 2, 2>::new(0.0, 1.0, 1.0, 0.0),
+        SMatrix::<f64, 2, 2>::new(-a.sin(), 0.0, 0.0, 0.0),
+        SMatrix::<f64, 2, 2>::new(0.0, 0.0, 0.0, 2.0),
+    ];
+    for i in 0..3 {
+        assert_abs_diff_eq!((hess[i] - expected[i]).norm(), 0.0, epsilon = EPS);
+    }
+
+    let xg = var::grad_vector_from_slice::<2>(&[a, b]);
+    let vg = SVector::<_, 3>::new(&xg[0] * &xg[1], xg[0].sin(), xg[1].powi(2));
+    assert_abs_diff_eq!((vg.grad() - jac).norm(), 0.0, epsilon = EPS);
+
+    // Constants of dynamic vectors have no derivatives, but still get rows
+    let xd = var::dvector_from_slice(&[a, b]);
+    let vd = na::DVector::from_vec(vec![&xd[0] * &xd[1], make::val::dscalar(2.0)]);
+    assert_eq!(vd.grad().shape(), (2, 2));
+    assert_eq!(vd.grad()[(1, 0)], 0.0);
+    assert_eq!(vd.hess()[1].shape(), (2, 2));
+}
+
+#[test]
+fn test_matrix_jacobian() {
+    let (a, b): (f64, f64) = (0.7, -1.3);
+
+    let x = var::vector_from_slice::<2>(&[a, b]);
+    let m = SMatrix::<Ad<2>, 2, 3>::new(
+        &x[0] * &x[1],
+        x[0].sin(),
+        x[1].powi(2),
+        x[0].clone(),
+        x[1].exp(),
+        make::val::scalar(3.0),
+    );
+
+    // `new` takes rows, but entries are stacked column by column
+    let jac = m.grad();
+    assert_eq!(jac.shape(), (6, 2));
+    for c in 0..3 {
+        let column = m.column(c).into_owned().grad();
+        assert_abs_diff_eq!(
+            (jac.fixed_rows::<2>(2 * c) - column).norm(),
+            0.0,
+            epsilon = EPS
+        );
+    }
+    assert_abs_diff_eq!(jac[(1, 0)], 1.0, epsilon = EPS);
+    assert_abs_diff_eq!(jac[(3, 1)], b.exp(), epsilon = EPS);
+
+    let hess = m.hess();
+    assert_abs_diff_eq!(
+        (hess[0] - SMatrix::<f64, 2, 2>::new(0.0, 1.0, 1.0, 0.0)).norm(),
+        0.0,
+        epsilon = EPS
+    );
+    assert_abs_diff_eq!(hess[3][(1, 1)], b.exp(), epsilon = EPS);
+    assert_eq!(hess[5], SMatrix::<f64, 2, 2>::zeros());
+
+    let xg = var::grad_vector_from_slice::<2>(&[a, b]);
+    let mg = SMatrix::<_, 2, 2>::new(&xg[0] * &xg[1], xg[0].sin(), xg[0].clone(), xg[1].exp());
+    assert_abs_diff_eq!(
+        (mg.grad() - jac.fixed_rows::<4>(0)).norm(),
+        0.0,
+        epsilon = EPS
+    );
+}
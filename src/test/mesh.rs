@@ -0,0 +1,196 @@
+use crate::{
+    mesh::{dofs, obj, off, ply, Mesh, MeshError},
+    test::EPS,
+};
+use approx::assert_abs_diff_eq;
+
+fn quad() -> Mesh {
+    Mesh::new(
+        &[
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.5],
+            [0.0, 1.0, 0.0],
+        ],
+        vec![[0, 1, 2], [0, 2, 3]],
+        Vec::new(),
+    )
+}
+
+fn tet() -> Mesh {
+    Mesh::new(
+        &[
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1.0, 1.0, 1.0],
+        ],
+        vec![[0, 1, 2]],
+        vec![[0, 1, 2, 3], [1, 2, 3, 4]],
+    )
+}
+
+fn assert_same(a: &Mesh, b: &Mesh) {
+    assert_eq!(a.dim, b.dim);
+    assert_eq!(a.triangles, b.triangles);
+    assert_eq!(a.tets, b.tets);
+    assert_abs_diff_eq!((&a.positions - &b.positions).norm_l2(), 0.0, epsilon = EPS);
+}
+
+#[test]
+fn test_obj() {
+    let source = "\
+# A quad with texture coordinates
+v 0 0 0
+v 1 0 0
+v 1 1 0.5
+v 0 1 0  # trailing comment
+vt 0 0
+vn 0 0 1
+g quad
+f 1/1/1 2/1/1 -2/1/1 -1
+";
+    let mesh = obj::read(source.as_bytes()).unwrap();
+    assert_same(&mesh, &quad());
+
+    let mut written = Vec::new();
+    obj::write(&mesh, &mut written).unwrap();
+    assert_same(&obj::read(written.as_slice()).unwrap(), &mesh);
+
+    assert!(matches!(
+        obj::write(&tet(), &mut Vec::new()),
+        Err(MeshError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_off() {
+    let source = "\
+OFF
+# vertices faces edges
+4 1 0
+0 0 0
+1 0 0
+1 1 0.5
+0 1 0
+4 0 1 2 3 255 0 0
+";
+    let mesh = off::read(source.as_bytes()).unwrap();
+    assert_same(&mesh, &quad());
+
+    let mut written = Vec::new();
+    off::write(&mesh, &mut written).unwrap();
+    assert_same(&off::read(written.as_slice()).unwrap(), &mesh);
+
+    let truncated = "OFF 4 1 0\n0 0 0\n1 0 0\n";
+    assert!(matches!(
+        off::read(truncated.as_bytes()),
+        Err(MeshError::Parse { line: 4, .. })
+    ));
+    assert!(matches!(
+        off::read("COFF\n".as_bytes()),
+        Err(MeshError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_ply() {
+    let source = "\
+ply
+format ascii 1.0
+comment made by hand
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+element face 1
+property uchar flags
+property list uchar int vertex_indices
+end_header
+0 0 0 10
+1 0 0 20
+1 1 0.5 30
+0 1 0 40
+7 4 0 1 2 3
+";
+    let mesh = ply::read(source.as_bytes()).unwrap();
+    assert_same(&mesh, &quad());
+
+    let mut written = Vec::new();
+    ply::write(&tet(), &mut written).unwrap();
+    assert_same(&ply::read(written.as_slice()).unwrap(), &tet());
+
+    let binary = "ply\nformat binary_little_endian 1.0\nend_header\n";
+    assert!(matches!(
+        ply::read(binary.as_bytes()),
+        Err(MeshError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_out_of_bounds() {
+    let source = "v 0 0 0\nv 1 0 0\nf 1 2 3\n";
+    assert!(matches!(
+        obj::read(source.as_bytes()),
+        Err(MeshError::IndexOutOfBounds {
+            index: 2,
+            n_vertices: 2
+        })
+    ));
+}
+
+#[test]
+fn test_load_save() {
+    let dir = std::env::temp_dir().join(format!("raddy_mesh_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for name in ["quad.obj", "quad.OFF", "quad.ply"] {
+        let path = dir.join(name);
+        quad().save(&path).unwrap();
+        assert_same(&Mesh::load(&path).unwrap(), &quad());
+    }
+    assert!(matches!(
+        quad().save(dir.join("quad.stl")),
+        Err(MeshError::Unsupported(_))
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_dofs() {
+    let mesh = quad().with_dim(2);
+    assert_eq!(mesh.n_vertices(), 4);
+    assert_eq!(mesh.vertex(2), [1.0, 1.0, 0.0]);
+
+    let triangles: Vec<[usize; 6]> = mesh.dofs(&mesh.triangles);
+    assert_eq!(triangles, vec![[0, 1, 2, 3, 4, 5], [0, 1, 4, 5, 6, 7]]);
+
+    let springs: Vec<[usize; 6]> = dofs(&[[1, 3]], 3);
+    assert_eq!(springs, vec![[3, 4, 5, 9, 10, 11]]);
+}
+
+#[test]
+#[should_panic]
+fn test_dofs_mismatch() {
+    let _: Vec<[usize; 6]> = dofs(&[[0, 1, 2]], 3);
+}
+
+#[test]
+fn test_edges_hinges() {
+    let mesh = quad();
+    assert_eq!(mesh.edges(), vec![[0, 1], [0, 2], [0, 3], [1, 2], [2, 3]]);
+    assert_eq!(mesh.hinges(), vec![[2, 0, 1, 3]]);
+
+    // 6 edges of each tet, sharing the 3 of face 1 2 3; the triangle's are among them
+    assert_eq!(tet().edges().len(), 9);
+}
+
+#[test]
+fn test_with_positions() {
+    let mesh = quad();
+    let moved = mesh.with_positions(&(&mesh.positions * faer::scale(2.0)));
+    assert_eq!(moved.vertex(2), [2.0, 2.0, 1.0]);
+}
@@ -1,7 +1,55 @@
+use crate::{
+    make::val,
+    sparse::objective::{Objective, SparseReal},
+    types::advec,
+    Ad,
+};
+
 const EPS: f64 = 1e-12;
 const BIG_EPS: f64 = 1e-8;
 const RELRATIO: f64 = 6e-4;
 
+pub mod check;
+pub mod composite;
+pub mod decomposition;
+pub mod dynamic;
+pub mod energy;
+pub mod first_order;
+pub mod fixed;
+pub mod linalg;
 pub mod matrix;
+pub mod mesh;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod pattern;
+pub mod precision;
+pub mod projection;
+pub mod residual;
+pub mod reverse;
 pub mod scalar;
+pub mod solve;
 pub mod sparse;
+pub mod sparse_ad;
+pub mod third_order;
+pub mod workspace;
+
+/// Hooke's law spring between two 2d nodes, shared by the objective tests
+struct SpringEnergy {
+    k: f64,
+    restlen: f64,
+}
+
+// 2d * 2nodes = 4dof
+impl<T: SparseReal> Objective<4, T> for SpringEnergy {
+    type EvalArgs = ();
+    fn eval(&self, variables: &advec<4, 4, T>, _: &()) -> Ad<4, T> {
+        let p1 = advec::<4, 2, T>::new(variables[0].clone(), variables[1].clone());
+        let p2 = advec::<4, 2, T>::new(variables[2].clone(), variables[3].clone());
+
+        let len = (p2 - p1).norm();
+        let k = val::scalar_of(T::from_subset(&(0.5 * self.k)));
+        let restlen = val::scalar_of(T::from_subset(&self.restlen));
+        // Hooke's law
+        k * (len - restlen).powi(2)
+    }
+}
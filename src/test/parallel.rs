@@ -0,0 +1,135 @@
+use crate::{
+    sparse::{
+        objective::Objective,
+        parallel::{ParObjective, PAR_CHUNKS},
+    },
+    test::{SpringEnergy, EPS},
+};
+use approx::assert_abs_diff_eq;
+use faer::Col;
+use rand::{thread_rng, Rng};
+
+/// Springs along the edges of a `n x n` grid of nodes
+fn grid_springs(n: usize) -> Vec<[usize; 4]> {
+    let node = |i: usize, j: usize| i * n + j;
+    let mut springs = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            let a = node(i, j);
+            if j + 1 < n {
+                let b = node(i, j + 1);
+                springs.push([2 * a, 2 * a + 1, 2 * b, 2 * b + 1]);
+            }
+            if i + 1 < n {
+                let b = node(i + 1, j);
+                springs.push([2 * a, 2 * a + 1, 2 * b, 2 * b + 1]);
+            }
+        }
+    }
+    springs
+}
+
+fn in_pool<T: Send>(threads: usize, f: impl FnOnce() -> T + Send) -> T {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap()
+        .install(f)
+}
+
+#[test]
+fn test_parallel_reproducible() {
+    const N_GRID: usize = 20;
+    let mut rng = thread_rng();
+
+    let springs = grid_springs(N_GRID);
+    let x = Col::from_fn(2 * N_GRID * N_GRID, |i| {
+        (i / 2) as f64 * 0.01 + rng.gen_range(-0.1..0.1)
+    });
+    let obj = SpringEnergy {
+        k: 2.5,
+        restlen: 0.3,
+    };
+
+    let serial = obj.compute(&x, &springs, &());
+    let reference = in_pool(1, || obj.par_compute(&x, &springs, &()));
+
+    // Grouped differently than the serial sums, so only equal up to rounding
+    assert_abs_diff_eq!(reference.value, serial.value, epsilon = EPS);
+    assert_abs_diff_eq!(
+        (&reference.grad - &serial.grad).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+    assert_eq!(reference.hess_trips, serial.hess_trips);
+
+    for threads in [2, 3, 8] {
+        let par = in_pool(threads, || obj.par_compute(&x, &springs, &()));
+        assert_eq!(par.value.to_bits(), reference.value.to_bits());
+        assert_eq!(par.grad, reference.grad);
+        assert_eq!(par.hess_trips, reference.hess_trips);
+
+        let grad = in_pool(threads, || obj.par_grad(&x, &springs, &()));
+        assert_eq!(grad, reference.grad);
+
+        let value = in_pool(threads, || obj.par_value(&x, &springs, &()));
+        let value_1 = in_pool(1, || obj.par_value(&x, &springs, &()));
+        assert_eq!(value.to_bits(), value_1.to_bits());
+        assert_abs_diff_eq!(value, serial.value, epsilon = EPS);
+
+        let trips = in_pool(threads, || obj.par_hess_trips(&x, &springs, &()));
+        assert_eq!(trips, serial.hess_trips);
+
+        let hess = in_pool(threads, || obj.par_hess(&x, &springs, &())).unwrap();
+        let expected = obj.hess(&x, &springs, &()).unwrap();
+        assert_eq!(hess.to_dense(), expected.to_dense());
+    }
+}
+
+#[test]
+fn test_parallel_few_elements() {
+    // Fewer elements than chunks, and nodes untouched by any spring
+    let springs = vec![[0, 1, 4, 5], [4, 5, 8, 9], [8, 9, 0, 1]];
+    assert!(springs.len() < PAR_CHUNKS);
+    let x = Col::from_fn(12, |i| (i as f64 * 0.9).sin());
+    let obj = SpringEnergy {
+        k: 2.5,
+        restlen: 0.3,
+    };
+
+    let serial = obj.compute(&x, &springs, &());
+    let par = in_pool(3, || obj.par_compute(&x, &springs, &()));
+    assert_abs_diff_eq!(par.value, serial.value, epsilon = EPS);
+    assert_abs_diff_eq!((&par.grad - &serial.grad).norm_l2(), 0.0, epsilon = EPS);
+    assert_eq!(par.grad[2], 0.0);
+
+    let empty = obj.par_compute(&x, &[], &());
+    assert_eq!(empty.value, 0.0);
+    assert_eq!(empty.grad, Col::<f64>::zeros(12));
+    assert!(empty.hess_trips.is_empty());
+}
+
+#[test]
+fn test_parallel_f32() {
+    const N_GRID: usize = 8;
+
+    let springs = grid_springs(N_GRID);
+    let x = Col::from_fn(2 * N_GRID * N_GRID, |i| {
+        (i / 2) as f32 * 0.01 + (i as f32 * 0.7).sin() * 0.05
+    });
+    let obj = SpringEnergy {
+        k: 2.5,
+        restlen: 0.3,
+    };
+
+    let serial = obj.compute(&x, &springs, &());
+    let par = in_pool(3, || obj.par_compute(&x, &springs, &()));
+    assert_abs_diff_eq!(par.value, serial.value, epsilon = 1e-5);
+    assert_abs_diff_eq!((&par.grad - &serial.grad).norm_l2(), 0.0, epsilon = 1e-5);
+    assert_eq!(par.hess_trips, serial.hess_trips);
+
+    let value = in_pool(3, || obj.par_value(&x, &springs, &()));
+    assert_abs_diff_eq!(value, serial.value, epsilon = 1e-5);
+    let hess = in_pool(3, || obj.par_hess(&x, &springs, &())).unwrap();
+    assert_eq!(hess.nrows(), x.nrows());
+}
@@ -0,0 +1,80 @@
+use crate::{
+    sparse::{objective::Objective, pattern::HessianPattern},
+    test::{SpringEnergy, EPS},
+};
+use approx::assert_abs_diff_eq;
+use faer::Col;
+use rand::{thread_rng, Rng};
+
+#[test]
+fn test_hess_in_place() {
+    let mut rng = thread_rng();
+    // Repeated and shared nodes exercise accumulation into the same slots
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [4, 5, 0, 1], [0, 1, 2, 3]];
+    let obj = SpringEnergy {
+        k: 2.5,
+        restlen: 0.7,
+    };
+
+    let pattern = HessianPattern::new(6, &springs).unwrap();
+    assert_eq!(pattern.n_elements(), springs.len());
+    assert_eq!(pattern.nnz(), 36);
+
+    let mut hess = pattern.zeros();
+    let mut grad = Col::zeros(6);
+
+    // The same buffers are refilled for several iterates
+    for _ in 0..5 {
+        let x = Col::from_fn(6, |_| rng.gen_range(-1.0..1.0));
+
+        obj.hess_in_place(&x, &springs, &(), &pattern, &mut hess);
+        let expected = obj.hess(&x, &springs, &()).unwrap().to_dense();
+        // Entries may be summed in a different order than by faer
+        assert_abs_diff_eq!((hess.to_dense() - &expected).norm_l2(), 0.0, epsilon = EPS);
+
+        let value = obj.compute_in_place(&x, &springs, &(), &pattern, &mut grad, &mut hess);
+        assert_eq!(value, obj.value(&x, &springs, &()));
+        assert_eq!(grad, obj.grad(&x, &springs, &()));
+        assert_abs_diff_eq!((hess.to_dense() - &expected).norm_l2(), 0.0, epsilon = EPS);
+    }
+}
+
+#[test]
+fn test_pattern_sparse_structure() {
+    let springs = vec![[0, 1, 2, 3], [4, 5, 6, 7]];
+    let pattern = HessianPattern::new(8, &springs).unwrap();
+    // Two disjoint 4x4 blocks
+    assert_eq!(pattern.nnz(), 32);
+    assert!(HessianPattern::new(6, &springs).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_pattern_mismatch() {
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    let pattern = HessianPattern::new(6, &springs).unwrap();
+    let mut hess = pattern.zeros();
+    let x = Col::zeros(6);
+    SpringEnergy {
+        k: 1.0,
+        restlen: 1.0,
+    }
+    .hess_in_place(&x, &springs[..1], &(), &pattern, &mut hess);
+}
+
+#[test]
+#[should_panic(expected = "Operand indices mismatch at element 1")]
+fn test_pattern_indices_mismatch() {
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    let pattern = HessianPattern::new(6, &springs).unwrap();
+    let mut hess = pattern.zeros();
+    let x = Col::zeros(6);
+
+    // Same element count and global pattern, but the second spring is reversed
+    let reversed = vec![[0, 1, 2, 3], [4, 5, 2, 3]];
+    SpringEnergy {
+        k: 1.0,
+        restlen: 1.0,
+    }
+    .hess_in_place(&x, &reversed, &(), &pattern, &mut hess);
+}
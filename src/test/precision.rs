@@ -0,0 +1,110 @@
+use crate::{
+    make::{val, var},
+    sparse::objective::{Objective, SparseReal},
+    types::{advec, Real},
+    Ad,
+};
+use approx::assert_relative_eq;
+use faer::Col;
+use na::{SMatrix, SVector};
+
+const F32_EPS: f64 = 1e-5;
+
+fn expression<T: Real>(x: &advec<2, 2, T>) -> Ad<2, T> {
+    let two = val::scalar_of(T::from_subset(&2.0));
+    (&x[0] * &x[1]).sin() * (&x[1] / &two).exp() + (&x[0] * &x[0] + &two).ln() - x[1].powi(3)
+}
+
+fn assert_close<const N: usize>(single: &Ad<N, f32>, double: &Ad<N>) {
+    assert_relative_eq!(
+        single.value() as f64,
+        double.value(),
+        max_relative = F32_EPS
+    );
+    assert_relative_eq!(
+        single.grad().cast::<f64>(),
+        double.grad(),
+        epsilon = F32_EPS,
+        max_relative = F32_EPS
+    );
+    assert_relative_eq!(
+        single.hess().cast::<f64>(),
+        double.hess(),
+        epsilon = F32_EPS,
+        max_relative = F32_EPS
+    );
+}
+
+#[test]
+fn test_f32_matches_f64() {
+    let single = expression(&var::vector_from_slice_of(&[0.7f32, -1.3]));
+    let double = expression(&var::vector_from_slice(&[0.7, -1.3]));
+
+    assert_close(&single, &double);
+}
+
+#[test]
+fn test_f32_determinant() {
+    let values = [2.0, -1.0, 0.5, 0.3, 1.5, -0.2, 1.1, 0.4, 3.0];
+
+    let single: SMatrix<Ad<9, f32>, 3, 3> =
+        SMatrix::from_column_slice(var::vector_from_fn_of::<9, _>(|i| values[i] as f32).as_slice());
+    let double: SMatrix<Ad<9>, 3, 3> =
+        SMatrix::from_column_slice(var::vector_from_slice::<9>(&values).as_slice());
+
+    assert_close(&single.determinant(), &double.determinant());
+}
+
+#[test]
+fn test_f32_compare() {
+    let x = var::scalar_of(1.5f32);
+
+    assert!(x > 1.0f32 && 2.0f32 > x);
+    assert_eq!(x, 1.5f32);
+    assert_eq!(
+        val::scalar_of::<1, _>(2.0f32).grad(),
+        SVector::<f32, 1>::zeros()
+    );
+}
+
+struct Spring;
+
+impl<T: SparseReal> Objective<4, T> for Spring {
+    type EvalArgs = T;
+
+    fn eval(&self, x: &advec<4, 4, T>, rest_length: &T) -> Ad<4, T> {
+        let d = SVector::<_, 2>::new(&x[2] - &x[0], &x[3] - &x[1]);
+        let stretch = d.norm() - val::scalar_of(*rest_length);
+
+        stretch.square()
+    }
+}
+
+#[test]
+fn test_f32_objective() {
+    let positions = [0.0, 0.0, 1.2, 0.1, 0.4, 1.3];
+    let springs = [[0, 1, 2, 3], [2, 3, 4, 5], [4, 5, 0, 1]];
+
+    let x32 = Col::from_fn(positions.len(), |i| positions[i] as f32);
+    let x64 = Col::from_fn(positions.len(), |i| positions[i]);
+
+    let single = Spring.compute(&x32, &springs, &1.0f32);
+    let double = Spring.compute(&x64, &springs, &1.0);
+
+    assert_relative_eq!(single.value as f64, double.value, max_relative = F32_EPS);
+    for i in 0..positions.len() {
+        assert_relative_eq!(
+            single.grad[i] as f64,
+            double.grad[i],
+            epsilon = F32_EPS,
+            max_relative = F32_EPS
+        );
+    }
+    for (s, d) in single.hess_trips.iter().zip(&double.hess_trips) {
+        assert_eq!((s.0, s.1), (d.0, d.1));
+        assert_relative_eq!(s.2 as f64, d.2, epsilon = F32_EPS, max_relative = F32_EPS);
+    }
+
+    let hess = Spring.hess(&x32, &springs, &1.0f32).unwrap();
+    assert_eq!(hess.nrows(), positions.len());
+}
@@ -0,0 +1,106 @@
+use crate::{
+    make::var,
+    projection::{ProjectionMode, PsdProjection},
+    sparse::objective::Objective,
+    test::{SpringEnergy, EPS},
+    types::advec,
+};
+use approx::assert_abs_diff_eq;
+use faer::sparse::SparseColMat;
+use na::{Matrix3, Rotation3, SMatrix, Vector3};
+
+fn sorted_eigenvalues<const N: usize>(m: &SMatrix<f64, N, N>) -> Vec<f64> {
+    let mut evs: Vec<f64> = na::DMatrix::from_column_slice(N, N, m.as_slice())
+        .symmetric_eigenvalues()
+        .iter()
+        .copied()
+        .collect();
+    evs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    evs
+}
+
+fn rotated_diagonal(diag: Vector3<f64>) -> Matrix3<f64> {
+    let q = Rotation3::from_euler_angles(0.3, -1.1, 2.2).into_inner();
+    q * Matrix3::from_diagonal(&diag) * q.transpose()
+}
+
+#[test]
+fn test_projection_modes() {
+    let h = rotated_diagonal(Vector3::new(-2.0, 1.0, 3.0));
+
+    let clamped = PsdProjection::new(ProjectionMode::Clamp, 0.0).project(&h);
+    let evs = sorted_eigenvalues(&clamped);
+    assert_abs_diff_eq!(evs[0], 0.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(evs[1], 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(evs[2], 3.0, epsilon = 1e-10);
+
+    let flipped = PsdProjection::new(ProjectionMode::Abs, 0.0).project(&h);
+    let evs = sorted_eigenvalues(&flipped);
+    assert_abs_diff_eq!(evs[0], 1.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(evs[1], 2.0, epsilon = 1e-10);
+    assert_abs_diff_eq!(evs[2], 3.0, epsilon = 1e-10);
+
+    let floored = PsdProjection::new(ProjectionMode::Clamp, 1.5).project(&h);
+    let evs = sorted_eigenvalues(&floored);
+    assert_abs_diff_eq!(evs[0], 1.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(evs[1], 1.5, epsilon = 1e-10);
+    assert_abs_diff_eq!(evs[2], 3.0, epsilon = 1e-10);
+
+    // Already positive definite: untouched
+    let spd = rotated_diagonal(Vector3::new(0.5, 1.0, 3.0));
+    assert_eq!(PsdProjection::default().project(&spd), spd);
+}
+
+#[test]
+fn test_projected_ad() {
+    let x: advec<2, 2> = var::vector_from_slice(&[0.3, -0.7]);
+    // saddle
+    let f = &x[0] * &x[0] - &x[1] * &x[1];
+    let p = f.projected(&PsdProjection::default());
+
+    assert_eq!(p.value(), f.value());
+    assert_eq!(p.grad(), f.grad());
+    assert_abs_diff_eq!(p.hess()[(0, 0)], 2.0, epsilon = EPS);
+    assert_abs_diff_eq!(p.hess()[(1, 1)], 0.0, epsilon = EPS);
+    assert_abs_diff_eq!(p.hess()[(0, 1)], 0.0, epsilon = EPS);
+}
+
+#[test]
+fn test_objective_projected() {
+    // Compressed springs have indefinite Hessians
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [0, 1, 4, 5]];
+    let x0 = faer::col::from_slice(&[0.0, 0.0, 0.3, 0.1, 0.2, 0.4]).to_owned();
+    let obj = SpringEnergy {
+        k: 3.0,
+        restlen: 1.0,
+    };
+
+    let projection = PsdProjection::new(ProjectionMode::Clamp, 1e-3);
+    let plain = obj.compute(&x0, &springs, &());
+    let projected = obj.compute_projected(&x0, &springs, &(), &projection);
+
+    assert_eq!(plain.value, projected.value);
+    assert_eq!(plain.grad, projected.grad);
+    assert_eq!(
+        projected.hess_trips,
+        obj.hess_trips_projected(&x0, &springs, &(), &projection)
+    );
+
+    let dense = |trips: &[(usize, usize, f64)]| {
+        let h = SparseColMat::try_new_from_triplets(6, 6, trips)
+            .unwrap()
+            .to_dense();
+        SMatrix::<f64, 6, 6>::from_fn(|i, j| h.read(i, j))
+    };
+
+    assert!(sorted_eigenvalues(&dense(&plain.hess_trips))[0] < 0.0);
+    // A sum of positive semi-definite matrices is positive semi-definite
+    assert!(sorted_eigenvalues(&dense(&projected.hess_trips))[0] > -EPS);
+
+    let h = obj
+        .hess_projected(&x0, &springs, &(), &projection)
+        .unwrap()
+        .to_dense();
+    let hd = SMatrix::<f64, 6, 6>::from_fn(|i, j| h.read(i, j)) - dense(&projected.hess_trips);
+    assert_abs_diff_eq!(hd.norm_squared(), 0.0, epsilon = EPS);
+}
@@ -0,0 +1,81 @@
+use crate::{
+    make::val,
+    sparse::residual::Residual,
+    test::{BIG_EPS, EPS},
+    types::advec_grad,
+};
+use approx::assert_abs_diff_eq;
+use faer::{sparse::SparseColMat, Col};
+
+/// Stretch of an edge, and a nonlinear coupling of its endpoints
+struct EdgeResidual;
+
+impl Residual<4, 2> for EdgeResidual {
+    type EvalArgs = f64;
+
+    fn eval(&self, variables: &advec_grad<4, 4>, restlen: &f64) -> advec_grad<4, 2> {
+        let p1 = advec_grad::<4, 2>::new(variables[0].clone(), variables[1].clone());
+        let p2 = advec_grad::<4, 2>::new(variables[2].clone(), variables[3].clone());
+
+        advec_grad::<4, 2>::new(
+            (p2 - p1).norm() - val::grad_scalar(*restlen),
+            (&variables[0] * &variables[3]).sin(),
+        )
+    }
+}
+
+#[test]
+fn test_residual_jacobian() {
+    let edges = vec![[0, 1, 2, 3], [2, 3, 4, 5], [4, 5, 0, 1]];
+    let values = [0.1, 0.2, 1.3, -0.4, 0.6, 1.1];
+    let x = Col::from_fn(values.len(), |i| values[i]);
+
+    let r = EdgeResidual.residuals(&x, &edges, &1.0);
+    assert_eq!(r.nrows(), edges.len() * 2);
+    assert_abs_diff_eq!(
+        EdgeResidual.value(&x, &edges, &1.0),
+        0.5 * r.squared_norm_l2(),
+        epsilon = EPS
+    );
+
+    let trips = EdgeResidual.jacobian_trips(&x, &edges, &1.0);
+    assert_eq!(trips.len(), edges.len() * 2 * 4);
+    let jac = SparseColMat::try_new_from_triplets(r.nrows(), x.nrows(), &trips)
+        .unwrap()
+        .to_dense();
+
+    // Central finite differences of the stacked residuals
+    let h = 1e-6;
+    for j in 0..x.nrows() {
+        let mut xp = x.clone();
+        let mut xm = x.clone();
+        xp[j] += h;
+        xm[j] -= h;
+        let fd = (EdgeResidual.residuals(&xp, &edges, &1.0)
+            - EdgeResidual.residuals(&xm, &edges, &1.0))
+            / (2.0 * h);
+
+        for i in 0..r.nrows() {
+            assert_abs_diff_eq!(jac[(i, j)], fd[i], epsilon = BIG_EPS);
+        }
+    }
+}
+
+#[test]
+fn test_residual_compute() {
+    let edges = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    let x = Col::from_fn(6, |i| 0.4 * i as f64 - 1.0);
+
+    let computed = EdgeResidual.compute(&x, &edges, &0.5);
+    let r = EdgeResidual.residuals(&x, &edges, &0.5);
+    assert_abs_diff_eq!((&computed.residuals - &r).norm_l2(), 0.0, epsilon = EPS);
+    assert_eq!(
+        computed.jacobian_trips,
+        EdgeResidual.jacobian_trips(&x, &edges, &0.5)
+    );
+
+    let jac = EdgeResidual.jacobian(&x, &edges, &0.5).unwrap();
+    assert_eq!((jac.nrows(), jac.ncols()), (4, 6));
+    // Each residual depends on the 4 variables of its edge
+    assert_eq!(jac.compute_nnz(), 16);
+}
@@ -0,0 +1,188 @@
+use crate::{
+    make::{val, var},
+    sparse::objective::Objective,
+    test::{SpringEnergy, EPS},
+    Tape,
+};
+use approx::assert_abs_diff_eq;
+use faer::Col;
+use rand::{thread_rng, Rng};
+
+#[test]
+fn test_reverse_matches_forward() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let values = [
+            rng.gen_range(0.1..0.9),
+            rng.gen_range(0.1..0.9),
+            rng.gen_range(1.1..2.0),
+        ];
+
+        let tape = Tape::new();
+        let xr = tape.vars(&values);
+        let rev = (xr[0] * xr[1]).sin() / xr[2].sqrt()
+            + xr[0].atan2(&xr[1]) * xr[2].ln()
+            + (2.0 - xr[0]).powi(3) * xr[1].exp()
+            - xr[2].acosh() / (1.0 + xr[1].tanh())
+            + xr[0].asin().hypot(&xr[2].cbrt())
+            + xr[1].pow(&xr[0])
+            - 3.0 / xr[2];
+
+        let x = var::vector_from_slice::<3>(&values);
+        let two = val::scalar(2.0);
+        let one = val::scalar(1.0);
+        let three = val::scalar(3.0);
+        let fwd = (&x[0] * &x[1]).sin() / x[2].sqrt()
+            + x[0].atan2(&x[1]) * x[2].ln()
+            + (&two - &x[0]).powi(3) * x[1].exp()
+            - x[2].acosh() / (&one + x[1].tanh())
+            + x[0].asin().hypot(&x[2].powf(1.0 / 3.0))
+            + x[1].pow(&x[0])
+            - &three / &x[2];
+
+        assert_abs_diff_eq!(rev.value(), fwd.value(), epsilon = EPS);
+
+        let grad = rev.grad(&xr);
+        for i in 0..3 {
+            assert_abs_diff_eq!(grad[i], fwd.grad()[i], epsilon = EPS);
+        }
+
+        let v = Col::from_fn(3, |_| rng.gen_range(-1.0..1.0));
+        let hv = rev.hess_vec(&xr, &v);
+        for i in 0..3 {
+            let expected = (0..3).map(|j| fwd.hess()[(i, j)] * v[j]).sum::<f64>();
+            assert_abs_diff_eq!(hv[i], expected, epsilon = 1e-6);
+        }
+    }
+}
+
+#[test]
+fn test_reverse_many_variables() {
+    const N_VARS: usize = 2000;
+
+    let values = (0..N_VARS)
+        .map(|i| (i as f64 * 0.01).sin())
+        .collect::<Vec<_>>();
+
+    let tape = Tape::new();
+    let x = tape.vars(&values);
+    // Does not decompose into elements: the mean couples all variables
+    let terms = x
+        .windows(2)
+        .map(|w| w[0].square() * w[1].sin())
+        .collect::<Vec<_>>();
+    let loss = tape.sum(&terms) * (tape.sum(&x) / N_VARS as f64).exp();
+
+    let grad = loss.grad(&x);
+    assert_eq!(grad.nrows(), N_VARS);
+
+    let sum_terms: f64 = values.windows(2).map(|w| w[0] * w[0] * w[1].sin()).sum();
+    let scale = (values.iter().sum::<f64>() / N_VARS as f64).exp();
+    for i in 0..N_VARS {
+        let mut d_terms = 0.0;
+        if i + 1 < N_VARS {
+            d_terms += 2.0 * values[i] * values[i + 1].sin();
+        }
+        if i > 0 {
+            d_terms += values[i - 1] * values[i - 1] * values[i].cos();
+        }
+        let expected = d_terms * scale + sum_terms * scale / N_VARS as f64;
+
+        assert_abs_diff_eq!(grad[i], expected, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn test_reverse_embedded_objective() {
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [4, 5, 6, 7], [6, 7, 0, 1]];
+    let values = [0.0, 0.0, 4.0, 1.0, 3.0, 2.0, 1.0, 5.0];
+    let obj = SpringEnergy {
+        k: 2.0,
+        restlen: 1.0,
+    };
+
+    let tape = Tape::new();
+    let x = tape.vars(&values);
+    let energy = tape.objective(&obj, &x, &springs, &());
+    // A global term coupling all nodes: squared distance of the centroid from the origin
+    let cx = tape.sum(&[x[0], x[2], x[4], x[6]]) / 4.0;
+    let cy = tape.sum(&[x[1], x[3], x[5], x[7]]) / 4.0;
+    let loss = energy + cx.square() + cy.square();
+
+    // One node per variable, spring, sum, centroid and final combination
+    assert!(tape.len() < 30);
+
+    let x_col = Col::from_fn(values.len(), |i| values[i]);
+    let expected_grad = obj.grad(&x_col, &springs, &());
+    let (cxv, cyv) = (cx.value(), cy.value());
+
+    assert_abs_diff_eq!(
+        loss.value(),
+        obj.value(&x_col, &springs, &()) + cxv * cxv + cyv * cyv,
+        epsilon = EPS
+    );
+
+    let grad = loss.grad(&x);
+    for i in 0..values.len() {
+        let centroid = if i % 2 == 0 { cxv } else { cyv } / 2.0;
+        assert_abs_diff_eq!(grad[i], expected_grad[i] + centroid, epsilon = EPS);
+    }
+
+    // The spring Hessian blocks are recorded on the tape, the centroid adds 1/8 per same-axis pair
+    let v = Col::from_fn(values.len(), |i| (i as f64 * 0.7).cos());
+    let expected_hv = obj.hess_vec(&x_col, &springs, &(), &v);
+    let hv = loss.hess_vec(&x, &v);
+    for i in 0..values.len() {
+        let centroid = (0..values.len())
+            .filter(|j| j % 2 == i % 2)
+            .map(|j| v[j] / 8.0)
+            .sum::<f64>();
+        assert_abs_diff_eq!(hv[i], expected_hv[i] + centroid, epsilon = EPS);
+    }
+}
+
+#[test]
+fn test_reverse_eval_node() {
+    let tape = Tape::new();
+    let x = tape.vars(&[0.3, 1.7]);
+    let y = tape.eval(&[x[0], x[1]], |v| (&v[0] * &v[1]).sin());
+    let z = y * x[0];
+
+    let adjoints = z.backward();
+    let (a, b): (f64, f64) = (0.3, 1.7);
+    assert_abs_diff_eq!(
+        adjoints.wrt(&x[0]),
+        b * (a * b).cos() * a + (a * b).sin(),
+        epsilon = EPS
+    );
+    assert_abs_diff_eq!(adjoints.wrt(&x[1]), a * (a * b).cos() * a, epsilon = EPS);
+    assert_abs_diff_eq!(adjoints.wrt(&y), a, epsilon = EPS);
+}
+
+#[test]
+fn test_reverse_constants() {
+    let tape = Tape::new();
+    let x = tape.var(2.0);
+    let c = tape.constant(3.0);
+    let k = (c * c).sin() + 1.0;
+    assert_eq!(tape.len(), 1);
+
+    let y = x * k + c.exp();
+    let adjoints = y.backward();
+    assert_abs_diff_eq!(adjoints.wrt(&x), k.value(), epsilon = EPS);
+    assert_eq!(adjoints.wrt(&c), 0.0);
+    assert_eq!(k.backward().wrt(&x), 0.0);
+
+    assert_eq!(format!("{:?}", x), "AdRev { value: 2.0, index: Some(0) }");
+    assert_eq!(format!("{:?}", c), "AdRev { value: 3.0, index: None }");
+}
+
+#[test]
+#[should_panic(expected = "different tapes")]
+fn test_reverse_different_tapes() {
+    let t1 = Tape::new();
+    let t2 = Tape::new();
+
+    let _ = t1.var(1.0) + t2.var(2.0);
+}
@@ -16,6 +16,7 @@ use na::{Const, DimName, Matrix3, SMatrix, SVector, U3, U5};
 use rand::{thread_rng, Rng};
 use std::{
     any::Any,
+    f64::consts::PI,
     ops::{Add, Div, Mul, Sub},
 };
 
@@ -240,3 +241,119 @@ fn test_relative() {
     let g = expr.grad()[(0, 0)];
     float_close(g, grad_3(sv));
 }
+
+#[test]
+fn test_pow_ad_exponent() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let (xv, yv) = (rng.gen_range(0.1..3.0), rng.gen_range(-2.5..2.5));
+        let v: SVector<Ad<2>, 2> = var::vector_from_slice(&[xv, yv]);
+        let (x, y) = (&v[0], &v[1]);
+
+        let f = x.pow(y);
+        assert_abs_diff_eq!(f.value(), xv.powf(yv), epsilon = EPS);
+
+        let f_trait = ComplexField::powf(x.clone(), y.clone());
+        let f_powc = ComplexField::powc(x.clone(), y.clone());
+        assert_eq!(f_trait.hess(), f.hess());
+        assert_eq!(f_powc.hess(), f.hess());
+
+        let ln_x = xv.ln();
+        let g = SVector::<f64, 2>::new(yv * xv.powf(yv - 1.0), xv.powf(yv) * ln_x);
+        let h01 = xv.powf(yv - 1.0) * (1.0 + yv * ln_x);
+        let h = SMatrix::<f64, 2, 2>::new(
+            yv * (yv - 1.0) * xv.powf(yv - 2.0),
+            h01,
+            h01,
+            xv.powf(yv) * ln_x * ln_x,
+        );
+        assert_abs_diff_eq!((f.grad() - g).norm(), 0.0, epsilon = BIG_EPS);
+        assert_abs_diff_eq!((f.hess() - h).norm(), 0.0, epsilon = BIG_EPS);
+
+        // Same as composition
+        let composed = (y * x.ln()).exp();
+        assert_abs_diff_eq!((f.hess() - composed.hess()).norm(), 0.0, epsilon = BIG_EPS);
+    }
+
+    // Constant exponents allow negative bases
+    let x = var::scalar(-2.0);
+    let f = x.pow(&make::val::scalar(3.0));
+    assert_abs_diff_eq!(f.value(), -8.0, epsilon = EPS);
+    assert_abs_diff_eq!(f.grad()[0], 12.0, epsilon = EPS);
+    assert_abs_diff_eq!(f.hess()[0], -12.0, epsilon = EPS);
+}
+
+#[test]
+#[should_panic]
+fn test_pow_ad_exponent_negative_base() {
+    let v: SVector<Ad<2>, 2> = var::vector_from_slice(&[-1.5, 2.0]);
+    v[0].pow(&v[1]);
+}
+
+#[test]
+fn test_log_ad_base() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let (xv, bv) = (rng.gen_range(0.1..5.0), rng.gen_range(1.5..5.0));
+        let v: SVector<Ad<2>, 2> = var::vector_from_slice(&[xv, bv]);
+        let (x, b) = (&v[0], &v[1]);
+
+        let f = ComplexField::log(x.clone(), b.clone());
+        assert_abs_diff_eq!(f.value(), xv.log(bv), epsilon = EPS);
+
+        let (ln_x, ln_b) = (xv.ln(), bv.ln());
+        let g = SVector::<f64, 2>::new(1.0 / (xv * ln_b), -ln_x / (bv * ln_b * ln_b));
+        assert_abs_diff_eq!((f.grad() - g).norm(), 0.0, epsilon = BIG_EPS);
+
+        // Same as composition
+        let composed = x.ln() / b.ln();
+        assert_abs_diff_eq!((f.grad() - composed.grad()).norm(), 0.0, epsilon = BIG_EPS);
+        assert_abs_diff_eq!((f.hess() - composed.hess()).norm(), 0.0, epsilon = BIG_EPS);
+    }
+
+    // Constant base
+    let x = var::scalar(8.0);
+    let f = x.log_base(&make::val::scalar(2.0));
+    assert_abs_diff_eq!(f.value(), 3.0, epsilon = EPS);
+    assert_abs_diff_eq!(f.grad()[0], 1.0 / (8.0 * 2_f64.ln()), epsilon = EPS);
+}
+
+#[test]
+fn test_atan2() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        // All four quadrants, away from the origin where the derivatives blow up like 1/r^2
+        let (r, t): (f64, f64) = (rng.gen_range(0.5..3.0), rng.gen_range(-PI..PI));
+        let (yv, xv) = (r * t.sin(), r * t.cos());
+        let v: SVector<Ad<2>, 2> = var::vector_from_slice(&[yv, xv]);
+        let (y, x) = (&v[0], &v[1]);
+
+        let f = y.atan2(x);
+        assert_eq!(f.value(), yv.atan2(xv));
+
+        let r2 = xv * xv + yv * yv;
+        let r4 = r2 * r2;
+        let g = SVector::<f64, 2>::new(xv / r2, -yv / r2);
+        let h = SMatrix::<f64, 2, 2>::new(
+            -2.0 * xv * yv / r4,
+            (yv * yv - xv * xv) / r4,
+            (yv * yv - xv * xv) / r4,
+            2.0 * xv * yv / r4,
+        );
+        assert_abs_diff_eq!((f.grad() - g).norm(), 0.0, epsilon = BIG_EPS);
+        assert_abs_diff_eq!((f.hess() - h).norm(), 0.0, epsilon = BIG_EPS);
+
+        let f_trait = na::RealField::atan2(y.clone(), x.clone());
+        assert_eq!(f_trait.hess(), f.hess());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_atan2_origin() {
+    let v: SVector<Ad<2>, 2> = var::vector_from_slice(&[0.0, 0.0]);
+    v[0].atan2(&v[1]);
+}
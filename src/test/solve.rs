@@ -0,0 +1,432 @@
+use crate::{
+    make::val,
+    solve::{
+        line_search::{LinePoint, StrongWolfe},
+        ConjugateGradientSolver, GaussNewtonSolver, LbfgsSolver, LeastSquaresProblem,
+        LevenbergMarquardtSolver, NewtonSolver, ObjectiveTerm, Problem, ResidualTerm, Termination,
+    },
+    sparse::{objective::Objective, residual::Residual},
+    test::SpringEnergy,
+    types::{advec, advec_grad},
+    Ad,
+};
+use approx::assert_abs_diff_eq;
+use faer::Col;
+use na::SVector;
+use std::ops::ControlFlow;
+
+/// Pins a 2d node to a target position
+struct AnchorEnergy {
+    k: f64,
+}
+
+impl Objective<2> for AnchorEnergy {
+    type EvalArgs = [f64; 2];
+    fn eval(&self, variables: &advec<2, 2>, target: &[f64; 2]) -> Ad<2> {
+        let dx = &variables[0] - val::scalar(target[0]);
+        let dy = &variables[1] - val::scalar(target[1]);
+        val::scalar(0.5 * self.k) * (&dx * &dx + &dy * &dy)
+    }
+}
+
+struct Rosenbrock;
+
+impl Objective<2> for Rosenbrock {
+    type EvalArgs = ();
+    fn eval(&self, variables: &advec<2, 2>, _: &()) -> Ad<2> {
+        let x = &variables[0];
+        let y = &variables[1];
+        (val::scalar(1.0) - x).powi(2) + val::scalar(100.0) * (y - x * x).powi(2)
+    }
+}
+
+fn len(x: &Col<f64>, a: usize, b: usize) -> f64 {
+    let pa = SVector::<f64, 2>::new(x[2 * a], x[2 * a + 1]);
+    let pb = SVector::<f64, 2>::new(x[2 * b], x[2 * b + 1]);
+    (pb - pa).norm()
+}
+
+#[test]
+fn test_newton_rosenbrock() {
+    let x0 = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let ops = [[0, 1]];
+    let term = ObjectiveTerm::new(&Rosenbrock, &ops, &());
+
+    let result = NewtonSolver::default().solve(&term, &x0);
+
+    assert_eq!(result.termination, Termination::Converged);
+    assert!(result.converged());
+    assert_abs_diff_eq!(result.x[0], 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.x[1], 1.0, epsilon = 1e-6);
+    assert!(result.grad_norm <= 1e-8);
+    // The line search is needed from this initial guess
+    assert!(result.reports.iter().any(|r| r.line_search_steps > 0));
+    // Monotone decrease
+    assert!(result.reports.windows(2).all(|w| w[1].value <= w[0].value));
+    assert!(result.value <= result.reports.last().unwrap().value);
+}
+
+#[test]
+fn test_newton_mass_spring_shift() {
+    // Nearly collapsed triangle, with indefinite and singular Hessians
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [0, 1, 4, 5]];
+    let x0 = faer::col::from_slice(&[0.0, 0.0, 0.001, 0.0, 0.001, 0.01]).to_owned();
+    let obj = SpringEnergy {
+        k: 10000.0,
+        restlen: 1.0,
+    };
+    let term = ObjectiveTerm::new(&obj, &springs, &());
+
+    let solver = NewtonSolver {
+        tolerance: 1e-6,
+        ..Default::default()
+    };
+    let result = solver.solve(&term, &x0);
+
+    assert!(result.converged());
+    assert!(result.reports.iter().any(|r| r.shift > 0.0));
+    assert_abs_diff_eq!(len(&result.x, 0, 1), 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(len(&result.x, 1, 2), 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(len(&result.x, 0, 2), 1.0, epsilon = 1e-6);
+}
+
+#[test]
+fn test_newton_sum_of_objectives() {
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    let anchors = vec![[0, 1]];
+    let x0 = faer::col::from_slice(&[0.1, 0.2, 0.5, 0.1, 1.2, 0.3]).to_owned();
+
+    let spring = SpringEnergy {
+        k: 10.0,
+        restlen: 1.0,
+    };
+    let anchor = AnchorEnergy { k: 100.0 };
+    let target = [1.0, 2.0];
+
+    let spring_term = ObjectiveTerm::new(&spring, &springs, &());
+    let anchor_term = ObjectiveTerm::new(&anchor, &anchors, &target);
+    let terms: [&dyn Problem; 2] = [&spring_term, &anchor_term];
+
+    let x = faer::col::from_slice(&[0.3, -0.4, 0.7, 1.1, 2.0, 0.5]).to_owned();
+    assert_abs_diff_eq!(
+        terms[..].value(&x),
+        spring.value(&x, &springs, &()) + anchor.value(&x, &anchors, &target),
+        epsilon = 1e-12
+    );
+
+    let result = NewtonSolver::default().solve(&terms[..], &x0);
+
+    assert!(result.converged());
+    assert_abs_diff_eq!(result.x[0], 1.0, epsilon = 1e-8);
+    assert_abs_diff_eq!(result.x[1], 2.0, epsilon = 1e-8);
+    assert_abs_diff_eq!(len(&result.x, 0, 1), 1.0, epsilon = 1e-8);
+    assert_abs_diff_eq!(len(&result.x, 1, 2), 1.0, epsilon = 1e-8);
+}
+
+#[test]
+fn test_newton_max_iterations() {
+    let x0 = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let ops = [[0, 1]];
+    let term = ObjectiveTerm::new(&Rosenbrock, &ops, &());
+
+    let solver = NewtonSolver {
+        max_iterations: 2,
+        ..Default::default()
+    };
+    let result = solver.solve(&term, &x0);
+
+    assert_eq!(result.termination, Termination::MaxIterations);
+    assert_eq!(result.reports.len(), 2);
+    assert_eq!(result.reports[1].iteration, 1);
+}
+
+/// Rosenbrock function as the residuals `10 (y - x^2)` and `1 - x`
+struct RosenbrockResidual;
+
+impl Residual<2, 2> for RosenbrockResidual {
+    type EvalArgs = ();
+    fn eval(&self, variables: &advec_grad<2, 2>, _: &()) -> advec_grad<2, 2> {
+        let x = &variables[0];
+        let y = &variables[1];
+        advec_grad::<2, 2>::new(
+            val::grad_scalar(10.0) * (y - x * x),
+            val::grad_scalar(1.0) - x,
+        )
+    }
+}
+
+/// Distance of a point to the circle with center `(x[0], x[1])` and radius `x[2]`
+struct CircleResidual;
+
+impl Residual<3, 1> for CircleResidual {
+    type EvalArgs = [f64; 2];
+    fn eval(&self, variables: &advec_grad<3, 3>, point: &[f64; 2]) -> advec_grad<3, 1> {
+        let dx = &variables[0] - val::grad_scalar(point[0]);
+        let dy = &variables[1] - val::grad_scalar(point[1]);
+        advec_grad::<3, 1>::new((&dx * &dx + &dy * &dy).sqrt() - &variables[2])
+    }
+}
+
+/// Stretch of a spring, weighted by the square root of its stiffness
+struct SpringResidual;
+
+impl Residual<4, 1> for SpringResidual {
+    type EvalArgs = ();
+    fn eval(&self, variables: &advec_grad<4, 4>, _: &()) -> advec_grad<4, 1> {
+        let p1 = advec_grad::<4, 2>::new(variables[0].clone(), variables[1].clone());
+        let p2 = advec_grad::<4, 2>::new(variables[2].clone(), variables[3].clone());
+        advec_grad::<4, 1>::new((p2 - p1).norm() - val::grad_scalar(1.0))
+    }
+}
+
+/// Offset of a 2d node from a target position
+struct AnchorResidual;
+
+impl Residual<2, 2> for AnchorResidual {
+    type EvalArgs = [f64; 2];
+    fn eval(&self, variables: &advec_grad<2, 2>, target: &[f64; 2]) -> advec_grad<2, 2> {
+        advec_grad::<2, 2>::new(
+            &variables[0] - val::grad_scalar(target[0]),
+            &variables[1] - val::grad_scalar(target[1]),
+        )
+    }
+}
+
+#[test]
+fn test_least_squares_rosenbrock() {
+    let x0 = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let ops = [[0, 1]];
+    let term = ResidualTerm::new(&RosenbrockResidual, &ops, &());
+
+    // Same value as the scalar objective
+    let scalar = ObjectiveTerm::new(&Rosenbrock, &ops, &());
+    assert_abs_diff_eq!(2.0 * term.value(&x0), scalar.value(&x0), epsilon = 1e-12);
+
+    let gn = GaussNewtonSolver::default().solve(&term, &x0);
+    assert!(gn.converged());
+    assert_abs_diff_eq!(gn.x[0], 1.0, epsilon = 1e-8);
+    assert_abs_diff_eq!(gn.x[1], 1.0, epsilon = 1e-8);
+    assert!(gn.reports.iter().all(|r| r.damping == 0.0));
+
+    let lm = LevenbergMarquardtSolver::default().solve(&term, &x0);
+    assert_eq!(lm.termination, Termination::Converged);
+    assert_abs_diff_eq!(lm.x[0], 1.0, epsilon = 1e-8);
+    assert_abs_diff_eq!(lm.x[1], 1.0, epsilon = 1e-8);
+    assert!(lm.grad_norm <= 1e-8);
+    // Only accepted steps are reported, so the value decreases monotonically
+    assert!(lm.reports.windows(2).all(|w| w[1].value < w[0].value));
+    // The damping adapts to the quality of the linearization
+    assert!(lm.reports.last().unwrap().damping < lm.reports[0].damping);
+}
+
+#[test]
+fn test_levenberg_marquardt_damping_exceeded() {
+    let x0 = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let ops = [[0, 1]];
+    let term = ResidualTerm::new(&RosenbrockResidual, &ops, &());
+
+    let solver = LevenbergMarquardtSolver {
+        max_damping: 1e-6,
+        ..Default::default()
+    };
+    let result = solver.solve(&term, &x0);
+
+    assert_eq!(result.termination, Termination::DampingExceeded);
+    assert!(result.reports.is_empty());
+    assert_eq!(result.x, x0);
+}
+
+#[test]
+fn test_least_squares_circle_fit() {
+    let (cx, cy, r) = (1.0, -0.5, 2.0);
+    let points = (0..12)
+        .map(|i| {
+            let t = 0.4 * i as f64;
+            [cx + r * t.cos(), cy + r * t.sin()]
+        })
+        .collect::<Vec<_>>();
+    let ops = [[0, 1, 2]];
+    let terms = points
+        .iter()
+        .map(|p| ResidualTerm::new(&CircleResidual, &ops, p))
+        .collect::<Vec<_>>();
+    assert_eq!(terms[..].n_residuals(), points.len());
+
+    let x0 = faer::col::from_slice(&[0.0, 0.0, 1.0]).to_owned();
+    for result in [
+        GaussNewtonSolver::default().solve(&terms[..], &x0),
+        LevenbergMarquardtSolver::default().solve(&terms[..], &x0),
+    ] {
+        assert!(result.converged());
+        assert_abs_diff_eq!(result.x[0], cx, epsilon = 1e-8);
+        assert_abs_diff_eq!(result.x[1], cy, epsilon = 1e-8);
+        assert_abs_diff_eq!(result.x[2], r, epsilon = 1e-8);
+        assert_abs_diff_eq!(result.value, 0.0, epsilon = 1e-16);
+    }
+}
+
+#[test]
+fn test_least_squares_stacked_residuals() {
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    let anchors = vec![[0, 1]];
+    let target = [1.0, 2.0];
+    let x0 = faer::col::from_slice(&[0.1, 0.2, 0.5, 0.1, 1.2, 0.3]).to_owned();
+
+    let spring_term = ResidualTerm::new(&SpringResidual, &springs, &());
+    let anchor_term = ResidualTerm::new(&AnchorResidual, &anchors, &target);
+    let terms: [&dyn LeastSquaresProblem; 2] = [&spring_term, &anchor_term];
+
+    let computed = terms[..].compute(&x0);
+    assert_eq!(computed.residuals.nrows(), 4);
+    // The anchor residuals follow the spring residuals
+    assert!(computed
+        .jacobian_trips
+        .iter()
+        .any(|&(row, col, v)| row == 3 && col == 1 && v == 1.0));
+
+    // Free to rotate around the anchor: the Jacobian is rank deficient
+    let gn = GaussNewtonSolver {
+        damping: 1e-8,
+        ..Default::default()
+    };
+    for result in [
+        gn.solve(&terms[..], &x0),
+        LevenbergMarquardtSolver::default().solve(&terms[..], &x0),
+    ] {
+        assert!(result.converged());
+        assert_abs_diff_eq!(result.x[0], 1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(result.x[1], 2.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(len(&result.x, 0, 1), 1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(len(&result.x, 1, 2), 1.0, epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn test_lbfgs_rosenbrock() {
+    let x0 = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let ops = [[0, 1]];
+    let term = ObjectiveTerm::new(&Rosenbrock, &ops, &());
+
+    let result = LbfgsSolver::default().solve(&term, &x0);
+
+    assert_eq!(result.termination, Termination::Converged);
+    assert_abs_diff_eq!(result.x[0], 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.x[1], 1.0, epsilon = 1e-6);
+    assert!(result.grad_norm <= 1e-8);
+    // The strong Wolfe conditions imply sufficient decrease
+    assert!(result.reports.windows(2).all(|w| w[1].value < w[0].value));
+
+    // Without history, L-BFGS is steepest descent, which is much slower
+    let steepest = LbfgsSolver {
+        history: 0,
+        max_iterations: 100,
+        ..Default::default()
+    }
+    .solve(&term, &x0);
+    assert_eq!(steepest.termination, Termination::MaxIterations);
+    assert!(result.reports.len() < 100);
+}
+
+#[test]
+fn test_conjugate_gradient_rosenbrock() {
+    let x0 = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let ops = [[0, 1]];
+    let term = ObjectiveTerm::new(&Rosenbrock, &ops, &());
+
+    let result = ConjugateGradientSolver::default().solve(&term, &x0);
+
+    assert!(result.converged());
+    assert_abs_diff_eq!(result.x[0], 1.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.x[1], 1.0, epsilon = 1e-6);
+    assert!(result.reports.windows(2).all(|w| w[1].value < w[0].value));
+}
+
+#[test]
+fn test_first_order_sum_of_objectives() {
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    let anchors = vec![[0, 1]];
+    let x0 = faer::col::from_slice(&[0.1, 0.2, 0.5, 0.1, 1.2, 0.3]).to_owned();
+
+    let spring = SpringEnergy {
+        k: 10.0,
+        restlen: 1.0,
+    };
+    let anchor = AnchorEnergy { k: 100.0 };
+    let target = [1.0, 2.0];
+
+    let spring_term = ObjectiveTerm::new(&spring, &springs, &());
+    let anchor_term = ObjectiveTerm::new(&anchor, &anchors, &target);
+    let terms: [&dyn Problem; 2] = [&spring_term, &anchor_term];
+
+    let (value, grad) = terms[..].value_grad(&x0);
+    assert_abs_diff_eq!(value, terms[..].value(&x0), epsilon = 1e-12);
+    assert_abs_diff_eq!((grad - terms[..].grad(&x0)).norm_l2(), 0.0, epsilon = 1e-12);
+
+    for result in [
+        LbfgsSolver::default().solve(&terms[..], &x0),
+        ConjugateGradientSolver::default().solve(&terms[..], &x0),
+    ] {
+        assert!(result.converged());
+        assert_abs_diff_eq!(result.x[0], 1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(result.x[1], 2.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(len(&result.x, 0, 1), 1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(len(&result.x, 1, 2), 1.0, epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn test_first_order_callback() {
+    let x0 = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let ops = [[0, 1]];
+    let term = ObjectiveTerm::new(&Rosenbrock, &ops, &());
+
+    let mut iterates = Vec::new();
+    let result = LbfgsSolver::default().solve_with_callback(&term, &x0, |report, x| {
+        iterates.push((report.iteration, x.clone()));
+        if report.iteration == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    assert_eq!(result.termination, Termination::Stopped);
+    assert_eq!(result.reports.len(), 3);
+    assert_eq!(iterates.len(), 3);
+    assert_eq!(iterates[2].0, 2);
+    assert_eq!(iterates[2].1, result.x);
+    assert!(result.reports.iter().all(|r| r.evaluations >= 1));
+}
+
+#[test]
+fn test_strong_wolfe() {
+    let ops = [[0, 1]];
+    let term = ObjectiveTerm::new(&Rosenbrock, &ops, &());
+    let x = faer::col::from_slice(&[-1.2, 1.0]).to_owned();
+    let (value, grad) = term.value_grad(&x);
+    let dir = -&grad;
+    let slope = -grad.squared_norm_l2();
+
+    let start = LinePoint {
+        step: 0.0,
+        x,
+        value,
+        grad,
+        slope,
+    };
+
+    // Far too long a first step, so that the minimizer has to be bracketed
+    let line_search = StrongWolfe::default();
+    let accepted = line_search.search(&term, &start, &dir, 1.0).unwrap().point;
+
+    assert!(accepted.value <= value + line_search.c1 * accepted.step * slope);
+    assert!(accepted.slope.abs() <= -line_search.c2 * slope);
+
+    // A tighter curvature condition moves closer to the exact minimizer along `dir`
+    let tight = StrongWolfe {
+        c2: 1e-3,
+        ..Default::default()
+    };
+    let exact = tight.search(&term, &start, &dir, 1.0).unwrap().point;
+    assert!(exact.slope.abs() <= -1e-3 * slope);
+}
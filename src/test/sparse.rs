@@ -9,7 +9,7 @@ use crate::{
     Ad,
 };
 use approx::assert_abs_diff_eq;
-use faer::sparse::SparseColMat;
+use faer::{sparse::SparseColMat, Side};
 use na::SVector;
 
 struct SpringEnergy {
@@ -105,3 +105,112 @@ fn test_mass_spring_4() {
 
     assert_abs_diff_eq!(hd, 0.0, epsilon = EPS);
 }
+
+#[test]
+fn test_hess_triangular() {
+    // The last spring shares the global index 1 between its local operands 1 and 3
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [0, 1, 4, 5], [0, 1, 2, 1]];
+    let x0 = faer::col::from_slice(&[0.0, 0.0, 1.6, 0.3, 0.8, 0.6]).to_owned();
+
+    let obj = SpringEnergy {
+        k: 2.0,
+        restlen: 1.0,
+    };
+    let full = obj.hess(&x0, &springs, &()).unwrap().to_dense();
+
+    let lower_trips = obj.hess_trips_triangular(&x0, &springs, &(), Side::Lower);
+    assert!(lower_trips.iter().all(|&(row, col, _)| row >= col));
+    assert!(lower_trips.len() < obj.hess_trips(&x0, &springs, &()).len());
+
+    let lower = obj
+        .hess_triangular(&x0, &springs, &(), Side::Lower)
+        .unwrap()
+        .to_dense();
+    let upper = obj
+        .hess_triangular(&x0, &springs, &(), Side::Upper)
+        .unwrap()
+        .to_dense();
+
+    for i in 0..6 {
+        for j in 0..6 {
+            let expected_lower = if i >= j { full.read(i, j) } else { 0.0 };
+            let expected_upper = if i <= j { full.read(i, j) } else { 0.0 };
+            assert_abs_diff_eq!(lower.read(i, j), expected_lower, epsilon = EPS);
+            assert_abs_diff_eq!(upper.read(i, j), expected_upper, epsilon = EPS);
+        }
+    }
+}
+
+#[test]
+fn test_hess_vec() {
+    // The last spring shares the global index 1 between its local operands 1 and 3
+    let springs = vec![[0, 1, 2, 3], [2, 3, 4, 5], [0, 1, 4, 5], [0, 1, 2, 1]];
+    let x0 = faer::col::from_slice(&[0.0, 0.0, 1.6, 0.3, 0.8, 0.6]).to_owned();
+    let v = faer::col::from_slice(&[0.5, -1.0, 2.0, 0.25, -0.75, 1.5]).to_owned();
+
+    let obj = SpringEnergy {
+        k: 2.0,
+        restlen: 1.0,
+    };
+    let expected = obj.hess(&x0, &springs, &()).unwrap() * &v;
+
+    let hv = obj.hess_vec(&x0, &springs, &(), &v);
+    assert_abs_diff_eq!((&hv - &expected).norm_l2(), 0.0, epsilon = EPS);
+
+    let op = obj.hess_operator(&x0, &springs, &());
+    assert_abs_diff_eq!((op.mul_col(&v) - &expected).norm_l2(), 0.0, epsilon = EPS);
+}
+
+#[cfg(feature = "linop")]
+#[test]
+fn test_hess_operator_cg() {
+    use faer::{
+        dyn_stack::{GlobalPodBuffer, PodStack},
+        linop::{
+            conjugate_gradient::{conjugate_gradient, conjugate_gradient_req},
+            IdentityPrecond, LinOp,
+        },
+        Mat, Parallelism,
+    };
+
+    // Stretched springs have positive semi-definite Hessians
+    let springs = vec![
+        [0, 1, 2, 3],
+        [2, 3, 4, 5],
+        [4, 5, 6, 7],
+        [6, 7, 0, 1],
+        [0, 1, 4, 5],
+    ];
+    let x0 = faer::col::from_slice(&[0.0, 0.0, 4.0, 1.0, 3.0, 2.0, 1.0, 5.0]).to_owned();
+    let v = faer::col::from_slice(&[0.5, -1.0, 2.0, 0.25, -0.75, 1.5, 1.0, -0.5]).to_owned();
+
+    let obj = SpringEnergy {
+        k: 1.0,
+        restlen: 0.5,
+    };
+    let op = obj.hess_operator(&x0, &springs, &());
+    assert_eq!((op.nrows(), op.ncols()), (8, 8));
+
+    // A consistent right-hand side, so that CG converges on the semi-definite Hessian
+    let rhs = op.mul_col(&v);
+    let mut sol = Mat::<f64>::zeros(8, 1);
+
+    let precond = IdentityPrecond { dim: 8 };
+    let req = conjugate_gradient_req(precond, &op, 1, Parallelism::None).unwrap();
+    let mut buf = GlobalPodBuffer::new(req);
+
+    conjugate_gradient(
+        sol.as_mut(),
+        precond,
+        &op,
+        rhs.as_ref().as_2d(),
+        Default::default(),
+        |_| {},
+        Parallelism::None,
+        PodStack::new(&mut buf),
+    )
+    .unwrap();
+
+    let residual = op.mul_col(&sol.col(0).to_owned()) - &rhs;
+    assert!(residual.norm_l2() < 1e-8 * rhs.norm_l2());
+}
@@ -0,0 +1,221 @@
+use crate::{
+    make::{val, var},
+    sparse::{
+        objective::Objective,
+        sparsity::{LocalSparsity, SparsityError},
+    },
+    test::{BIG_EPS, EPS},
+    types::advec,
+    Ad,
+};
+use approx::assert_abs_diff_eq;
+use faer::Col;
+use rand::{thread_rng, Rng};
+
+#[test]
+fn test_sparse_ad_matches_dense() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let values = [
+            rng.gen_range(0.1..0.9),
+            rng.gen_range(0.1..0.9),
+            rng.gen_range(1.1..2.0),
+        ];
+
+        let xs = var::sparse_vector_from_slice(&values);
+        let two = val::sparse_scalar(2.0);
+        let one = val::sparse_scalar(1.0);
+        let sparse = (&xs[0] * &xs[1]).sin() / xs[2].sqrt()
+            + xs[0].atan2(&xs[1]) * xs[2].ln()
+            + (&two - &xs[0]).powi(3) * xs[1].exp()
+            - xs[2].acosh() / (&one + xs[1].tanh())
+            + xs[0].asin().hypot(&xs[2].cbrt())
+            + xs[1].exp2() * xs[2].recip();
+
+        let x = var::vector_from_slice::<3>(&values);
+        let two = val::scalar(2.0);
+        let one = val::scalar(1.0);
+        let dense = (&x[0] * &x[1]).sin() / x[2].sqrt()
+            + x[0].atan2(&x[1]) * x[2].ln()
+            + (&two - &x[0]).powi(3) * x[1].exp()
+            - x[2].acosh() / (&one + x[1].tanh())
+            + x[0].asin().hypot(&x[2].powf(1.0 / 3.0))
+            + (x[1].clone() * val::scalar(std::f64::consts::LN_2)).exp() * x[2].recip();
+
+        assert_abs_diff_eq!(sparse.value(), dense.value(), epsilon = EPS);
+        for i in 0..3 {
+            assert_abs_diff_eq!(sparse.grad_entry(i), dense.grad()[i], epsilon = EPS);
+            for j in 0..3 {
+                assert_abs_diff_eq!(
+                    sparse.hess_entry(i, j),
+                    dense.hess()[(i, j)],
+                    epsilon = BIG_EPS
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sparse_ad_many_variables() {
+    const N_VARS: usize = 2000;
+
+    let values = (0..N_VARS)
+        .map(|i| 1.0 + (i as f64 * 0.01).sin())
+        .collect::<Vec<_>>();
+    let x = var::sparse_vector_from_slice(&values);
+
+    let mut f = val::sparse_scalar(0.0);
+    for w in x.windows(2) {
+        f += w[0].square() * w[1].sin();
+    }
+
+    // Diagonal and first super-diagonal only, rather than N_VARS^2 entries
+    assert_eq!(f.grad().len(), N_VARS);
+    assert_eq!(f.hess().len(), 2 * N_VARS - 1);
+    assert_eq!(f.hess_trips().len(), 3 * N_VARS - 2);
+
+    let grad = f.grad_col(N_VARS);
+    for i in 0..N_VARS {
+        let mut expected = 0.0;
+        if i + 1 < N_VARS {
+            expected += 2.0 * values[i] * values[i + 1].sin();
+        }
+        if i > 0 {
+            expected += values[i - 1] * values[i - 1] * values[i].cos();
+        }
+        assert_abs_diff_eq!(grad[i], expected, epsilon = EPS);
+    }
+
+    for i in 0..N_VARS - 1 {
+        assert_abs_diff_eq!(
+            f.hess_entry(i + 1, i),
+            2.0 * values[i] * values[i + 1].cos(),
+            epsilon = EPS
+        );
+    }
+    assert_eq!(f.hess_entry(0, 2), 0.0);
+}
+
+/// Couples the first two variables only, the last two being separate terms
+struct PartlyCoupled;
+
+impl Objective<4> for PartlyCoupled {
+    type EvalArgs = ();
+
+    fn eval(&self, variables: &advec<4, 4>, _: &()) -> Ad<4> {
+        &variables[0] * &variables[1] + variables[2].powi(3) + variables[3].sin()
+    }
+}
+
+#[test]
+fn test_sparsity_sample() {
+    let operand_indices = vec![[0, 1, 2, 3], [2, 3, 4, 5]];
+    // The second derivative of x^3 vanishes at x[2] = 0, which must not hide it
+    let x = Col::from_fn(6, |i| [0.5, 1.5, 0.0, 0.3, 0.0, -0.7][i]);
+
+    let sparsity = LocalSparsity::sample(&PartlyCoupled, &x, &operand_indices, &());
+
+    assert_eq!(
+        sparsity,
+        LocalSparsity::from_entries(&[(0, 1), (2, 2), (3, 3)])
+    );
+    assert_eq!(sparsity.nnz(), 4);
+    assert!(!sparsity.contains(0, 0));
+    assert!(sparsity.contains(1, 0));
+}
+
+#[test]
+fn test_hess_trips_sparse() {
+    let operand_indices = vec![[0, 1, 2, 3], [2, 3, 4, 5], [4, 5, 0, 1]];
+    let x = Col::from_fn(6, |i| 0.3 * i as f64 - 0.4);
+    let obj = PartlyCoupled;
+
+    let sparsity = LocalSparsity::sample(&obj, &x, &operand_indices, &());
+    let trips = obj
+        .hess_trips_sparse(&x, &operand_indices, &(), &sparsity)
+        .unwrap();
+    assert_eq!(trips.len(), operand_indices.len() * 4);
+    assert!(trips.len() < obj.hess_trips(&x, &operand_indices, &()).len());
+
+    let sparse = obj
+        .hess_sparse(&x, &operand_indices, &(), &sparsity)
+        .unwrap()
+        .to_dense();
+    let dense = obj.hess(&x, &operand_indices, &()).unwrap().to_dense();
+    assert_abs_diff_eq!((sparse - dense).norm_l2(), 0.0, epsilon = EPS);
+
+    // The dense sparsity reproduces `hess_trips`
+    let all = obj
+        .hess_trips_sparse(&x, &operand_indices, &(), &LocalSparsity::dense())
+        .unwrap();
+    assert_eq!(all.len(), operand_indices.len() * 16);
+}
+
+/// Barrier coupling the first two variables only when they are closer than 1
+struct Barrier;
+
+impl Objective<2> for Barrier {
+    type EvalArgs = ();
+
+    fn eval(&self, variables: &advec<2, 2>, _: &()) -> Ad<2> {
+        let d = &variables[0] - &variables[1];
+        let mut energy = variables[0].square() + variables[1].square();
+        if d.value().abs() < 1.0 {
+            energy += (val::scalar(1.0) - d.square()).square();
+        }
+        energy
+    }
+}
+
+#[test]
+fn test_sparsity_sample_misses_branches() {
+    let operand_indices = vec![[0, 1]];
+    let far = Col::from_fn(2, |i| [0.0, 2.0][i]);
+    let near = Col::from_fn(2, |i| [0.0, 0.5][i]);
+
+    // The barrier is inactive around `far`, so its coupling is not sampled
+    let sampled = LocalSparsity::sample(&Barrier, &far, &operand_indices, &());
+    assert_eq!(sampled, LocalSparsity::from_entries(&[(0, 0), (1, 1)]));
+    assert_eq!(
+        LocalSparsity::sample(&Barrier, &near, &operand_indices, &()),
+        LocalSparsity::dense()
+    );
+
+    let sparsity = sampled.union(&LocalSparsity::from_entries(&[(0, 1)]));
+    assert_eq!(sparsity, LocalSparsity::dense());
+    let sparse = Barrier
+        .hess_sparse(&near, &operand_indices, &(), &sparsity)
+        .unwrap()
+        .to_dense();
+    let dense = Barrier
+        .hess(&near, &operand_indices, &())
+        .unwrap()
+        .to_dense();
+    assert_abs_diff_eq!((sparse - dense).norm_l2(), 0.0, epsilon = EPS);
+}
+
+#[test]
+fn test_hess_trips_sparse_checks_sparsity() {
+    let operand_indices = vec![[0, 1], [1, 2]];
+    let far = Col::from_fn(3, |i| [0.0, 2.0, 4.0][i]);
+    let near = Col::from_fn(3, |i| [0.0, 2.0, 2.5][i]);
+
+    let sampled = LocalSparsity::sample(&Barrier, &far, &operand_indices, &());
+    assert!(Barrier
+        .hess_sparse(&far, &operand_indices, &(), &sampled)
+        .is_ok());
+    assert_eq!(
+        Barrier.hess_trips_sparse(&near, &operand_indices, &(), &sampled),
+        Err(SparsityError::MissingEntry {
+            element: 1,
+            row: 1,
+            col: 0
+        })
+    );
+    assert!(matches!(
+        Barrier.hess_sparse(&near, &operand_indices, &(), &sampled),
+        Err(SparsityError::MissingEntry { element: 1, .. })
+    ));
+}
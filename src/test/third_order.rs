@@ -0,0 +1,151 @@
+use crate::make::{val, var};
+use crate::{Ad, AdThird, GetValue};
+use approx::assert_relative_eq;
+use na::{RealField, SMatrix, SVector};
+
+const FD_STEP: f64 = 1e-5;
+const FD_EPS: f64 = 1e-6;
+const N_CASES: usize = 32;
+
+/// A family of expressions of three variables, selected by `case`,
+/// covering the elementary functions and binary operators.
+fn expression<T: RealField>(x: &SVector<T, 3>, case: usize) -> T {
+    let c = |v: f64| T::from_subset(&v);
+    let (x0, x1, x2) = (x[0].clone(), x[1].clone(), x[2].clone());
+    // Lies in (0, 1) around the test point
+    let u = x0.clone() * x1.clone() + x2.clone() * c(0.3);
+
+    match case {
+        0 => u.sqrt(),
+        1 => u.cbrt(),
+        2 => u.powi(2),
+        3 => u.powi(-3),
+        4 => u.powf(c(2.5)),
+        5 => (u - c(1.0)).abs(),
+        6 => u.exp(),
+        7 => u.exp2(),
+        8 => u.exp_m1(),
+        9 => u.ln(),
+        10 => u.ln_1p(),
+        11 => u.log2(),
+        12 => u.log10(),
+        13 => u.log(c(3.0)),
+        14 => u.sin(),
+        15 => u.cos(),
+        16 => u.tan(),
+        17 => u.asin(),
+        18 => u.acos(),
+        19 => u.atan(),
+        20 => u.sinh(),
+        21 => u.cosh(),
+        22 => u.tanh(),
+        23 => u.asinh(),
+        24 => (u + c(1.0)).acosh(),
+        25 => u.atanh(),
+        26 => u.recip(),
+        27 => x0.hypot(x1 * x2),
+        28 => (x0 - x2).atan2(x1.clone() * x1),
+        29 => (x0 + c(1.0)).powf(x1 * x2),
+        30 => (x0 + x1).log(x2 + c(1.5)),
+        31 => x0.clone() * x1 / x2.clone() - x2 + -x0,
+        _ => unreachable!(),
+    }
+}
+
+/// Checks value, gradient and Hessian against `Ad`,
+/// and the third derivatives against central differences of the `Ad` Hessian.
+fn check<const N: usize>(
+    x: &SVector<f64, N>,
+    ad: impl Fn(&SVector<f64, N>) -> Ad<N>,
+    third: impl Fn(&SVector<f64, N>) -> AdThird<N>,
+) {
+    let expected = ad(x);
+    let actual = third(x);
+
+    assert_relative_eq!(actual.value(), expected.value(), max_relative = FD_EPS);
+    assert_relative_eq!(
+        actual.grad(),
+        expected.grad(),
+        epsilon = FD_EPS,
+        max_relative = FD_EPS
+    );
+    assert_relative_eq!(
+        actual.hess(),
+        expected.hess(),
+        epsilon = FD_EPS,
+        max_relative = FD_EPS
+    );
+
+    let tensor = actual.third();
+    for (k, slice) in tensor.iter().enumerate() {
+        let mut step = SVector::<f64, N>::zeros();
+        step[k] = FD_STEP;
+        let fd = (ad(&(x + step)).hess() - ad(&(x - step)).hess()) / (2.0 * FD_STEP);
+
+        assert_relative_eq!(*slice, fd, epsilon = FD_EPS, max_relative = FD_EPS);
+    }
+}
+
+#[test]
+fn test_third_elementary() {
+    let x = SVector::from([0.4, 0.7, 0.5]);
+
+    for case in 0..N_CASES {
+        check(
+            &x,
+            |x| expression(&var::vector(x), case),
+            |x| expression(&var::third_vector(x), case),
+        );
+    }
+}
+
+#[test]
+fn test_third_symmetric() {
+    let x = var::third_vector_from_slice::<3>(&[0.4, 0.7, 0.5]);
+
+    for case in 0..N_CASES {
+        let t = expression(&x, case).third();
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    let v = t[k][(i, j)];
+                    assert_relative_eq!(v, t[k][(j, i)], epsilon = 1e-12, max_relative = 1e-12);
+                    assert_relative_eq!(v, t[i][(k, j)], epsilon = 1e-12, max_relative = 1e-12);
+                    assert_relative_eq!(v, t[j][(i, k)], epsilon = 1e-12, max_relative = 1e-12);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_third_monomial() {
+    let x = var::third_vector_from_slice::<3>(&[1.5, -2.0, 3.0]);
+    let f = x[0].powi(2) * &x[1] * &x[2] + val::third_scalar(4.0) * &x[1];
+
+    let t = f.third();
+    // ∂³f/∂x0²∂x1 = 2 x2, ∂³f/∂x0²∂x2 = 2 x1, ∂³f/∂x0∂x1∂x2 = 2 x0
+    assert_eq!(t[1][(0, 0)], 6.0);
+    assert_eq!(t[2][(0, 0)], -4.0);
+    assert_eq!(t[2][(0, 1)], 3.0);
+    assert_eq!(t[0][(1, 1)], 0.0);
+    assert_eq!(t[2][(2, 2)], 0.0);
+}
+
+#[test]
+fn test_third_determinant() {
+    let x = SVector::from([2.0, -1.0, 0.5, 1.5]);
+
+    fn det<T: RealField>(x: &SVector<T, 4>) -> T {
+        let m = SMatrix::<T, 2, 2>::from_column_slice(x.as_slice());
+        (&m * m.transpose()).determinant().sqrt()
+    }
+
+    check(&x, |x| det(&var::vector(x)), |x| det(&var::third_vector(x)));
+
+    let m = SMatrix::<AdThird<4>, 2, 2>::from_column_slice(var::third_vector(&x).as_slice());
+    assert_eq!(
+        m.value(),
+        SMatrix::<f64, 2, 2>::from_column_slice(x.as_slice())
+    );
+}
@@ -0,0 +1,129 @@
+use crate::{
+    energy::MassSpring,
+    make::var,
+    sparse::objective::{ComputedObjective, Objective},
+    test::EPS,
+    Ad,
+};
+use approx::assert_abs_diff_eq;
+use faer::Col;
+use na::SVector;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Counts heap allocations of the current thread, as tests run in parallel
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let res = f();
+    (res, ALLOCATIONS.with(Cell::get) - before)
+}
+
+/// A chain of springs in 2D
+fn chain(n_springs: usize) -> (Col<f64>, Vec<[usize; 4]>) {
+    let x = Col::from_fn(2 * (n_springs + 1), |i| {
+        if i % 2 == 0 {
+            i as f64 * 0.6
+        } else {
+            (i as f64).sin()
+        }
+    });
+    let springs = (0..n_springs)
+        .map(|i| [2 * i, 2 * i + 1, 2 * i + 2, 2 * i + 3])
+        .collect();
+    (x, springs)
+}
+
+#[test]
+fn test_active_vector_no_allocation() {
+    let x = SVector::<f64, 6>::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+
+    let (vars, count) = allocations(|| var::vector(&x));
+    assert_eq!(count, 0);
+    let (from_slice, count) = allocations(|| Ad::<6>::active_from_slice(x.as_slice()));
+    assert_eq!(count, 0);
+
+    for i in 0..6 {
+        assert_eq!(vars[i].value, x[i]);
+        assert_eq!(from_slice[i].grad, vars[i].grad);
+        assert_eq!(vars[i].grad[i], 1.0);
+        assert_eq!(vars[i].grad.sum(), 1.0);
+    }
+}
+
+#[test]
+fn test_allocations_per_element() {
+    let spring = MassSpring { stiffness: 2.0 };
+
+    let (x, few) = chain(10);
+    let (_, few_grad) = allocations(|| spring.grad(&x, &few, &0.5));
+    let (_, few_trips) = allocations(|| spring.hess_trips(&x, &few, &0.5));
+    let (_, few_compute) = allocations(|| spring.compute(&x, &few, &0.5));
+
+    let (x, many) = chain(1000);
+    let (_, many_grad) = allocations(|| spring.grad(&x, &many, &0.5));
+    let (_, many_trips) = allocations(|| spring.hess_trips(&x, &many, &0.5));
+    let (_, many_compute) = allocations(|| spring.compute(&x, &many, &0.5));
+
+    assert_eq!(few_grad, many_grad);
+    assert_eq!(few_trips, many_trips);
+    assert_eq!(few_compute, many_compute);
+}
+
+#[test]
+fn test_compute_into() {
+    let spring = MassSpring { stiffness: 2.0 };
+    let (x, springs) = chain(100);
+    let expected = spring.compute(&x, &springs, &0.5);
+
+    let mut computed = ComputedObjective::new();
+    spring.compute_into(&x, &springs, &0.5, &mut computed);
+
+    // Warm workspace: no allocations at all
+    let x = &x * faer::scale(1.1);
+    let expected_scaled = spring.compute(&x, &springs, &0.5);
+    let (_, count) = allocations(|| spring.compute_into(&x, &springs, &0.5, &mut computed));
+    assert_eq!(count, 0);
+
+    assert_abs_diff_eq!(computed.value, expected_scaled.value, epsilon = EPS);
+    assert_abs_diff_eq!(
+        (&computed.grad - &expected_scaled.grad).norm_l2(),
+        0.0,
+        epsilon = EPS
+    );
+    assert_eq!(computed.hess_trips, expected_scaled.hess_trips);
+    assert!(computed.value != expected.value);
+
+    let mut grad = Col::zeros(0);
+    let mut trips = Vec::new();
+    spring.grad_into(&x, &springs, &0.5, &mut grad);
+    spring.hess_trips_into(&x, &springs, &0.5, &mut trips);
+    let (_, count) = allocations(|| {
+        spring.grad_into(&x, &springs, &0.5, &mut grad);
+        spring.hess_trips_into(&x, &springs, &0.5, &mut trips);
+    });
+    assert_eq!(count, 0);
+    assert_eq!(grad, expected_scaled.grad);
+    assert_eq!(trips, expected_scaled.hess_trips);
+}
@@ -0,0 +1,438 @@
+use crate::third_order::AdThird;
+use std::f64::consts::{LN_10, LN_2};
+
+// ################################### Unary Operators ###################################
+
+impl<const N: usize> AdThird<N> {
+    pub fn neg(&self) -> Self {
+        -self
+    }
+
+    pub fn sqrt(&self) -> Self {
+        if self.value < -0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Sqrt on negative value!");
+        }
+        let x = self.value;
+        let f = x.sqrt();
+
+        Self::chain(f, 0.5 / f, -0.25 / (f * x), 0.375 / (f * x * x), self)
+    }
+
+    pub fn square(&self) -> Self {
+        Self::chain(self.value * self.value, 2.0 * self.value, 2.0, 0.0, self)
+    }
+
+    pub fn powi(&self, exponent: i32) -> Self {
+        if self.value.abs() == 0.0 && exponent == 0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("0.pow(0) is undefined!");
+        }
+
+        // exponent in float
+        let ef = exponent as f64;
+        // `c * x^e`, skipping powers that vanish for small integer exponents
+        let term = |c: f64, e: i32| {
+            if c == 0.0 {
+                0.0
+            } else {
+                c * self.value.powi(e)
+            }
+        };
+
+        Self::chain(
+            self.value.powi(exponent),
+            term(ef, exponent - 1),
+            term(ef * (ef - 1.0), exponent - 2),
+            term(ef * (ef - 1.0) * (ef - 2.0), exponent - 3),
+            self,
+        )
+    }
+
+    pub fn powf(&self, exponent: f64) -> Self {
+        if self.value.abs() == 0.0 && exponent.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("0.pow(0) is undefined!");
+        }
+
+        let e = exponent;
+        let term = |c: f64, e: f64| {
+            if c == 0.0 {
+                0.0
+            } else {
+                c * self.value.powf(e)
+            }
+        };
+
+        Self::chain(
+            self.value.powf(e),
+            term(e, e - 1.0),
+            term(e * (e - 1.0), e - 2.0),
+            term(e * (e - 1.0) * (e - 2.0), e - 3.0),
+            self,
+        )
+    }
+
+    pub fn abs(&self) -> Self {
+        let sign = if self.value >= 0.0 { 1.0 } else { -1.0 };
+
+        Self::chain(self.value.abs(), sign, 0.0, 0.0, self)
+    }
+
+    pub fn exp(&self) -> Self {
+        let exp_val = self.value.exp();
+
+        Self::chain(exp_val, exp_val, exp_val, exp_val, self)
+    }
+
+    pub fn ln(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Ln on non-positive value!");
+        }
+        let inv = 1.0 / self.value;
+
+        Self::chain(
+            self.value.ln(),
+            inv,
+            -inv * inv,
+            2.0 * inv * inv * inv,
+            self,
+        )
+    }
+
+    pub fn log(&self, base: f64) -> Self {
+        if base <= 0.0 {
+            panic!("Base must be positive!");
+        }
+
+        self.ln().mul_value(1.0 / base.ln())
+    }
+
+    pub fn log2(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Log2 on non-positive value!");
+        }
+
+        self.ln().mul_value(1.0 / LN_2)
+    }
+
+    pub fn log10(&self) -> Self {
+        if self.value <= 0.0 {
+            panic!("Log10 on non-positive value!");
+        }
+
+        self.ln().mul_value(1.0 / LN_10)
+    }
+
+    pub fn sin(&self) -> Self {
+        let sin_val = self.value.sin();
+        let cos_val = self.value.cos();
+
+        Self::chain(sin_val, cos_val, -sin_val, -cos_val, self)
+    }
+
+    pub fn cos(&self) -> Self {
+        let cos_val = self.value.cos();
+        let sin_val = self.value.sin();
+
+        Self::chain(cos_val, -sin_val, -cos_val, sin_val, self)
+    }
+
+    pub fn tan(&self) -> Self {
+        let t = self.value.tan();
+        let s = 1.0 + t * t;
+
+        Self::chain(t, s, 2.0 * t * s, s * (2.0 + 6.0 * t * t), self)
+    }
+
+    pub fn asin(&self) -> Self {
+        if self.value < -1.0 || self.value > 1.0 {
+            panic!("Asin out of domain!");
+        }
+        let x = self.value;
+        let s = 1.0 - x * x;
+        let s_sqrt = s.sqrt();
+
+        Self::chain(
+            x.asin(),
+            1.0 / s_sqrt,
+            x / (s * s_sqrt),
+            (1.0 + 2.0 * x * x) / (s * s * s_sqrt),
+            self,
+        )
+    }
+
+    pub fn acos(&self) -> Self {
+        if self.value < -1.0 || self.value > 1.0 {
+            panic!("Acos out of domain!");
+        }
+        let x = self.value;
+        let s = 1.0 - x * x;
+        let s_sqrt = s.sqrt();
+
+        Self::chain(
+            x.acos(),
+            -1.0 / s_sqrt,
+            -x / (s * s_sqrt),
+            -(1.0 + 2.0 * x * x) / (s * s * s_sqrt),
+            self,
+        )
+    }
+
+    #[deprecated = "Please use atan2 instead."]
+    pub fn atan(&self) -> Self {
+        let x = self.value;
+        let s = x * x + 1.0;
+
+        Self::chain(
+            x.atan(),
+            1.0 / s,
+            -2.0 * x / (s * s),
+            (6.0 * x * x - 2.0) / (s * s * s),
+            self,
+        )
+    }
+
+    pub fn sinh(&self) -> Self {
+        let sinh_val = self.value.sinh();
+        let cosh_val = self.value.cosh();
+
+        Self::chain(sinh_val, cosh_val, sinh_val, cosh_val, self)
+    }
+
+    pub fn cosh(&self) -> Self {
+        let sinh_val = self.value.sinh();
+        let cosh_val = self.value.cosh();
+
+        Self::chain(cosh_val, sinh_val, cosh_val, sinh_val, self)
+    }
+
+    pub fn tanh(&self) -> Self {
+        let t = self.value.tanh();
+        let s = 1.0 - t * t;
+
+        Self::chain(t, s, -2.0 * t * s, s * (6.0 * t * t - 2.0), self)
+    }
+
+    pub fn asinh(&self) -> Self {
+        let x = self.value;
+        let s = x * x + 1.0;
+        let s_sqrt = s.sqrt();
+
+        Self::chain(
+            x.asinh(),
+            1.0 / s_sqrt,
+            -x / (s * s_sqrt),
+            (2.0 * x * x - 1.0) / (s * s * s_sqrt),
+            self,
+        )
+    }
+
+    pub fn acosh(&self) -> Self {
+        if self.value < 1.0 {
+            panic!("Acosh out of domain!");
+        }
+        let x = self.value;
+        let s = x * x - 1.0;
+        let s_sqrt = s.sqrt();
+
+        Self::chain(
+            x.acosh(),
+            1.0 / s_sqrt,
+            -x / (s * s_sqrt),
+            (2.0 * x * x + 1.0) / (s * s * s_sqrt),
+            self,
+        )
+    }
+
+    pub fn atanh(&self) -> Self {
+        if self.value <= -1.0 || self.value >= 1.0 {
+            panic!("Atanh out of domain!");
+        }
+        let x = self.value;
+        let s = 1.0 - x * x;
+
+        Self::chain(
+            x.atanh(),
+            1.0 / s,
+            2.0 * x / (s * s),
+            (2.0 + 6.0 * x * x) / (s * s * s),
+            self,
+        )
+    }
+
+    pub fn cbrt(&self) -> Self {
+        let x = self.value;
+        let f = x.cbrt();
+        let f_sq = f * f;
+
+        Self::chain(
+            f,
+            1.0 / (3.0 * f_sq),
+            -2.0 / (9.0 * f_sq * x),
+            10.0 / (27.0 * f_sq * x * x),
+            self,
+        )
+    }
+
+    pub fn exp2(&self) -> Self {
+        let exp_val = self.value.exp2();
+        let d = exp_val * LN_2;
+
+        Self::chain(exp_val, d, d * LN_2, d * LN_2 * LN_2, self)
+    }
+}
+
+// ################################### Binary Operators ###################################
+
+impl<const N: usize> AdThird<N> {
+    pub fn add_value(&self, other: f64) -> Self {
+        Self::chain(self.value + other, 1.0, 0.0, 0.0, self)
+    }
+
+    pub fn sub_value(&self, other: f64) -> Self {
+        Self::chain(self.value - other, 1.0, 0.0, 0.0, self)
+    }
+
+    pub fn mul_value(&self, other: f64) -> Self {
+        Self::chain(self.value * other, other, 0.0, 0.0, self)
+    }
+
+    pub fn recip(&self) -> Self {
+        AdThird::inactive_scalar(1.0) / self
+    }
+
+    pub fn div_value(&self, other: f64) -> Self {
+        if other.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        Self::chain(self.value / other, 1.0 / other, 0.0, 0.0, self)
+    }
+
+    /// ## self is y
+    pub fn atan2(&self, x: &Self) -> Self {
+        if self.value == 0.0 && x.value == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Atan2 at origin!");
+        }
+
+        let (y, x_val) = (self.value, x.value);
+        let r = x_val * x_val + y * y;
+        let (r2, r3) = (r * r, r * r * r);
+
+        let dyy = -2.0 * x_val * y / r2;
+        let dyx = (y * y - x_val * x_val) / r2;
+        let dyyy = 2.0 * x_val * (3.0 * y * y - x_val * x_val) / r3;
+        let dyyx = 2.0 * y * (3.0 * x_val * x_val - y * y) / r3;
+        let dyxx = 2.0 * x_val * (x_val * x_val - 3.0 * y * y) / r3;
+        let dxxx = 2.0 * y * (y * y - 3.0 * x_val * x_val) / r3;
+
+        Self::compose(
+            y.atan2(x_val),
+            [x_val / r, -y / r],
+            [[dyy, dyx], [dyx, -dyy]],
+            [[[dyyy, dyyx], [dyyx, dyxx]], [[dyyx, dyxx], [dyxx, dxxx]]],
+            [self, x],
+        )
+    }
+
+    /// `self^exponent`, differentiated w.r.t. both base and exponent.
+    /// For a constant exponent this is `powf`, otherwise the base must be positive.
+    pub fn pow(&self, exponent: &Self) -> Self {
+        if exponent.is_constant() {
+            return self.powf(exponent.value);
+        }
+        if self.value <= 0.0 {
+            panic!("Pow of non-positive base with active exponent!");
+        }
+
+        let (a, b) = (self.value, exponent.value);
+        let ln_a = a.ln();
+        let f = a.powf(b);
+        let f_a = f / a;
+        let f_aa = f_a / a;
+
+        let dab = f_a * (1.0 + b * ln_a);
+        let dbb = f * ln_a * ln_a;
+        let daab = f_aa * (2.0 * b - 1.0 + b * (b - 1.0) * ln_a);
+        let dabb = f_a * ln_a * (2.0 + b * ln_a);
+
+        Self::compose(
+            f,
+            [b * f_a, f * ln_a],
+            [[b * (b - 1.0) * f_aa, dab], [dab, dbb]],
+            [
+                [[b * (b - 1.0) * (b - 2.0) * f_aa / a, daab], [daab, dabb]],
+                [[daab, dabb], [dabb, dbb * ln_a]],
+            ],
+            [self, exponent],
+        )
+    }
+
+    /// Logarithm of `self` to `base`, differentiated w.r.t. both.
+    /// For a constant base this is `log`.
+    pub fn log_base(&self, base: &Self) -> Self {
+        if base.is_constant() {
+            return self.log(base.value);
+        }
+        if self.value <= 0.0 {
+            panic!("Log on non-positive value!");
+        }
+        if base.value <= 0.0 || base.value == 1.0 {
+            panic!("Base must be positive and not 1!");
+        }
+
+        let (a, b) = (self.value, base.value);
+        let ln_b = b.ln();
+        let f = a.ln() / ln_b;
+
+        let dab = -1.0 / (a * b * ln_b * ln_b);
+        let dbb = f * (ln_b + 2.0) / (b * b * ln_b * ln_b);
+        let daab = 1.0 / (a * a * b * ln_b * ln_b);
+        let dabb = (ln_b + 2.0) / (a * b * b * ln_b * ln_b * ln_b);
+        let dbbb = -f * (2.0 * ln_b * ln_b + 6.0 * ln_b + 6.0) / (b * b * b * ln_b * ln_b * ln_b);
+
+        Self::compose(
+            f,
+            [1.0 / (a * ln_b), -f / (b * ln_b)],
+            [[-1.0 / (a * a * ln_b), dab], [dab, dbb]],
+            [
+                [[2.0 / (a * a * a * ln_b), daab], [daab, dabb]],
+                [[daab, dabb], [dabb, dbbb]],
+            ],
+            [self, base],
+        )
+    }
+
+    /// The derivatives are those of the selected operand, a valid one-sided derivative.
+    /// On ties, `other` is selected.
+    pub fn min(&self, other: &Self) -> Self {
+        if self < other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+
+    /// The derivatives are those of the selected operand, a valid one-sided derivative.
+    /// On ties, `other` is selected.
+    pub fn max(&self, other: &Self) -> Self {
+        if self > other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+
+    /// The derivatives are those of the selected one of `self`, `low` and `high`.
+    pub fn clamp(&self, low: &Self, high: &Self) -> Self {
+        self.max(low).min(high)
+    }
+
+    // Computes hypot(self, b) = sqrt(self^2 + b^2) with derivatives up to third order.
+    pub fn hypot(&self, other: &Self) -> Self {
+        (self.square() + other.square()).sqrt()
+    }
+}
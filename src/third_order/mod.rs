@@ -0,0 +1,266 @@
+/// Implementations of operators on `&AdThird<N>`
+pub mod borrow_operator_traits_impl;
+/// Implementations of nalgebra field traits on `AdThird<N>`.
+pub mod field_impl;
+pub mod num_traits_impl;
+pub mod operator_traits_impl;
+pub mod scalar_matrix_mul;
+
+use crate::types::{mat, vec};
+use na::SVector;
+
+// ################################### Data Structure ###################################
+
+/// Automatic differentiation value tracking first, second and third derivatives
+///
+/// An extension of [`Ad<N>`](crate::Ad) for methods that need third derivatives
+/// (Halley-type solvers, sensitivity of equilibria, differentiating through Newton steps).
+/// Every operation costs O(N³).
+///
+/// # Value getters:
+/// - `value() -> f64`: Returns the current numerical value
+/// - `grad() -> SVector<f64, N>`: Returns the gradient vector
+/// - `hess() -> SMatrix<f64, N, N>`: Returns the Hessian matrix
+/// - `third() -> [SMatrix<f64, N, N>; N]`: Returns the third derivative tensor
+///
+/// # Type Parameters
+/// * `N` - The dimension of the input space (number of variables)
+///
+/// # Fields (private)
+/// * `value` - The current value of the function
+/// * `grad` - The gradient (first derivatives) as a vector
+/// * `hess` - The Hessian matrix (second derivatives)
+/// * `third` - The third derivatives, `third[k][(i, j)]` being `∂³f / ∂xi ∂xj ∂xk`
+#[derive(Debug, Clone)]
+pub struct AdThird<const N: usize> {
+    pub(crate) value: f64,
+    pub(crate) grad: vec<N>,
+    pub(crate) hess: mat<N>,
+    pub(crate) third: [mat<N>; N],
+}
+
+// ################################### Accessors ###################################
+
+impl<const N: usize> AdThird<N> {
+    /// Returns the current value of the AD variable
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the gradient (first derivatives) of the AD variable
+    ///
+    /// # Returns
+    /// The gradient (A vector containing the partial derivatives with respect to each input variable).
+    pub fn grad(&self) -> vec<N> {
+        self.grad
+    }
+
+    /// Returns the Hessian matrix (second derivatives) of the AD variable
+    ///
+    /// # Returns
+    /// The [hessian](https://en.wikipedia.org/wiki/Hessian_matrix).
+    pub fn hess(&self) -> mat<N> {
+        self.hess
+    }
+
+    /// Returns the third derivatives of the AD variable
+    ///
+    /// # Returns
+    /// The symmetric third derivative tensor as N matrices,
+    /// the `k`-th being the derivative of the Hessian w.r.t. the `k`-th variable.
+    pub fn third(&self) -> [mat<N>; N] {
+        self.third
+    }
+}
+
+// ################################### Public Constructors ###################################
+
+impl AdThird<1> {
+    /// Creates an active scalar AD value with unit gradient
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    ///
+    /// # Returns
+    /// A new AdThird<1> instance that is active (gradient = 1.0)
+    pub fn active_scalar(value: f64) -> Self {
+        Self::_active_scalar_with_index(value, 0)
+    }
+}
+
+impl<const N: usize> AdThird<N> {
+    /// Creates an inactive scalar AD value with zero derivatives
+    ///
+    /// # Arguments
+    /// * `value` - The scalar value
+    ///
+    /// # Returns
+    /// A new `AdThird<N>` instance that is inactive (gradient = 0)
+    pub fn inactive_scalar(value: f64) -> Self {
+        let mut res = Self::_zeroed();
+        res.value = value;
+        res
+    }
+
+    /// Creates a vector of inactive AD values from a vector of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Input vector of numerical values
+    ///
+    /// # Type Parameters
+    /// * `L` - Length of the output vector
+    ///
+    /// # Returns
+    /// A vector of inactive AD values
+    pub fn inactive_vector<const L: usize>(values: &SVector<f64, L>) -> SVector<Self, L> {
+        SVector::from_iterator(values.iter().map(|&v| Self::inactive_scalar(v)))
+    }
+
+    /// Creates a vector of inactive AD values from a slice of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    ///
+    /// # Type Parameters
+    /// * `L` - Length of the output vector
+    ///
+    /// # Returns
+    /// A vector of inactive AD values
+    ///
+    /// # Panics
+    /// If the slice length doesn't match the vector length L
+    pub fn inactive_from_slice<const L: usize>(values: &[f64]) -> SVector<Self, L> {
+        assert_eq!(
+            values.len(),
+            L,
+            "Slice length mismatch: expected {}, got {}",
+            L,
+            values.len()
+        );
+        Self::inactive_vector(&SVector::from_column_slice(values))
+    }
+
+    /// Creates a vector of active AD values from a vector of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Input vector of numerical values
+    ///
+    /// # Returns
+    /// A vector of active AD values where each element has unit gradient
+    /// in its corresponding dimension
+    pub fn active_vector(vector: &SVector<f64, N>) -> SVector<Self, N> {
+        SVector::from_iterator((0..N).map(|i| Self::_active_scalar_with_index(vector[i], i)))
+    }
+
+    /// Creates a vector of active AD values from a slice of f64 values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of numerical values
+    ///
+    /// # Returns
+    /// A vector of active AD values
+    ///
+    /// # Panics
+    /// If the slice length doesn't match the input dimension N
+    pub fn active_from_slice(values: &[f64]) -> SVector<Self, N> {
+        assert_eq!(
+            values.len(),
+            N,
+            "Slice length mismatch: expected {}, got {}",
+            N,
+            values.len()
+        );
+        Self::active_vector(&SVector::from_column_slice(values))
+    }
+}
+
+// ################################### Private Constructors ###################################
+
+impl<const N: usize> AdThird<N> {
+    fn _active_scalar_with_index(value: f64, index: usize) -> Self {
+        let mut res = Self::_zeroed();
+
+        res.value = value;
+        res.grad[index] = 1.0;
+
+        res
+    }
+
+    fn _zeroed() -> Self {
+        Self {
+            value: 0.0,
+            grad: vec::zeros(),
+            hess: mat::zeros(),
+            third: [mat::zeros(); N],
+        }
+    }
+}
+
+// ################################### Utils ###################################
+
+impl<const N: usize> AdThird<N> {
+    fn chain(
+        value: f64, // f
+        d: f64,     // df/da
+        d2: f64,    // ddf/daa
+        d3: f64,    // dddf/daaa
+        a: &Self,
+    ) -> Self {
+        Self::compose(value, [d], [[d2]], [[[d3]]], [a])
+    }
+
+    /// Applies a function of `M` AD values by the multivariate chain rule
+    ///
+    /// `d1`, `d2` and `d3` are its (symmetric) first, second and third partial derivatives
+    /// w.r.t. its arguments `args`.
+    fn compose<const M: usize>(
+        value: f64,
+        d1: [f64; M],
+        d2: [[f64; M]; M],
+        d3: [[[f64; M]; M]; M],
+        args: [&Self; M],
+    ) -> Self {
+        let mut res = Self::_zeroed();
+        res.value = value;
+
+        for (p, a) in args.iter().enumerate() {
+            if d1[p] != 0.0 {
+                res.grad += a.grad * d1[p];
+                res.hess += a.hess * d1[p];
+                for k in 0..N {
+                    res.third[k] += a.third[k] * d1[p];
+                }
+            }
+
+            for (q, b) in args.iter().enumerate() {
+                let ab = a.grad * b.grad.transpose();
+
+                if d2[p][q] != 0.0 {
+                    res.hess += ab * d2[p][q];
+                    for k in 0..N {
+                        // Symmetrized Hessian of `a` times gradient of `b`
+                        let hg = a.hess.column(k) * b.grad.transpose();
+                        res.third[k] += (a.hess * b.grad[k] + hg + hg.transpose()) * d2[p][q];
+                    }
+                }
+
+                for (r, c) in args.iter().enumerate() {
+                    if d3[p][q][r] != 0.0 {
+                        for k in 0..N {
+                            res.third[k] += ab * (c.grad[k] * d3[p][q][r]);
+                        }
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Whether this value has zero derivatives, i.e. is a constant
+    fn is_constant(&self) -> bool {
+        self.grad.iter().all(|&g| g == 0.0)
+            && self.hess.iter().all(|&h| h == 0.0)
+            && self.third.iter().flatten().all(|&t| t == 0.0)
+    }
+}
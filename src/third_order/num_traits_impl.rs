@@ -0,0 +1,56 @@
+#![allow(unused)]
+use crate::AdThird;
+use num_traits::{Num, One, Signed, Zero};
+use std::fmt::Display;
+
+impl<const N: usize> Display for AdThird<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AdThird[{}]", self.value)
+    }
+}
+
+impl<const N: usize> Zero for AdThird<N> {
+    fn zero() -> Self {
+        AdThird::_zeroed()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.abs() == 0.0 && self.is_constant()
+    }
+}
+
+impl<const N: usize> One for AdThird<N> {
+    fn one() -> Self {
+        AdThird::inactive_scalar(1.0)
+    }
+}
+
+impl<const N: usize> Num for AdThird<N> {
+    type FromStrRadixErr = ();
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        unimplemented!()
+    }
+}
+
+impl<const N: usize> Signed for AdThird<N> {
+    fn abs(&self) -> Self {
+        AdThird::abs(self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        unimplemented!()
+    }
+
+    fn signum(&self) -> Self {
+        unimplemented!()
+    }
+
+    fn is_positive(&self) -> bool {
+        self.value > 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.value < -0.0
+    }
+}
@@ -0,0 +1,467 @@
+/*
+
+This code is generated by meta/operators.py at 12:18:17 @ 2026.10.18
+Do not modify it directly.
+
+*/
+
+#![allow(unused)]
+
+use crate::AdThird;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+impl<const N: usize> Neg for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn neg(self) -> AdThird<N> {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = -self.value;
+        res.grad = -self.grad;
+        res.hess = -self.hess;
+        for k in 0..N {
+            res.third[k] = -self.third[k];
+        }
+
+        res
+    }
+}
+
+impl<const N: usize> Neg for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn neg(self) -> AdThird<N> {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = -self.value;
+        res.grad = -self.grad;
+        res.hess = -self.hess;
+        for k in 0..N {
+            res.third[k] = -self.third[k];
+        }
+
+        res
+    }
+}
+
+// &T + &T
+impl<const N: usize> Add<&AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn add(self, rhs: &AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+        res.hess = self.hess + rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] + rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// &T + T
+impl<const N: usize> Add<AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn add(self, rhs: AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+        res.hess = self.hess + rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] + rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// T + &T
+impl<const N: usize> Add<&AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn add(self, rhs: &AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+        res.hess = self.hess + rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] + rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// T + T
+impl<const N: usize> Add<AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn add(self, rhs: AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value + rhs.value;
+        res.grad = self.grad + rhs.grad;
+        res.hess = self.hess + rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] + rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// &T - &T
+impl<const N: usize> Sub<&AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn sub(self, rhs: &AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+        res.hess = self.hess - rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] - rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// &T - T
+impl<const N: usize> Sub<AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn sub(self, rhs: AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+        res.hess = self.hess - rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] - rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// T - &T
+impl<const N: usize> Sub<&AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn sub(self, rhs: &AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+        res.hess = self.hess - rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] - rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// T - T
+impl<const N: usize> Sub<AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn sub(self, rhs: AdThird<N>) -> Self::Output {
+        let mut res = AdThird::<N>::_zeroed();
+        res.value = self.value - rhs.value;
+        res.grad = self.grad - rhs.grad;
+        res.hess = self.hess - rhs.hess;
+        for k in 0..N {
+            res.third[k] = self.third[k] - rhs.third[k];
+        }
+
+        res
+    }
+}
+
+// &T * &T
+impl<const N: usize> Mul<&AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn mul(self, rhs: &AdThird<N>) -> Self::Output {
+        AdThird::compose(
+            self.value * rhs.value,
+            [rhs.value, self.value],
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[[0.0; 2]; 2]; 2],
+            [self, rhs],
+        )
+    }
+}
+
+// &T * T
+impl<const N: usize> Mul<AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn mul(self, rhs: AdThird<N>) -> Self::Output {
+        AdThird::compose(
+            self.value * rhs.value,
+            [rhs.value, self.value],
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[[0.0; 2]; 2]; 2],
+            [self, &rhs],
+        )
+    }
+}
+
+// T * &T
+impl<const N: usize> Mul<&AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn mul(self, rhs: &AdThird<N>) -> Self::Output {
+        AdThird::compose(
+            self.value * rhs.value,
+            [rhs.value, self.value],
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[[0.0; 2]; 2]; 2],
+            [&self, rhs],
+        )
+    }
+}
+
+// T * T
+impl<const N: usize> Mul<AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn mul(self, rhs: AdThird<N>) -> Self::Output {
+        AdThird::compose(
+            self.value * rhs.value,
+            [rhs.value, self.value],
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[[0.0; 2]; 2]; 2],
+            [&self, &rhs],
+        )
+    }
+}
+
+// &T / &T
+impl<const N: usize> Div<&AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn div(self, rhs: &AdThird<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+        let inv2 = inv * inv;
+
+        AdThird::compose(
+            value,
+            [inv, -value * inv],
+            [[0.0, -inv2], [-inv2, 2.0 * value * inv2]],
+            [
+                [[0.0, 0.0], [0.0, 2.0 * inv2 * inv]],
+                [
+                    [0.0, 2.0 * inv2 * inv],
+                    [2.0 * inv2 * inv, -6.0 * value * inv2 * inv],
+                ],
+            ],
+            [self, rhs],
+        )
+    }
+}
+
+// &T / T
+impl<const N: usize> Div<AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn div(self, rhs: AdThird<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+        let inv2 = inv * inv;
+
+        AdThird::compose(
+            value,
+            [inv, -value * inv],
+            [[0.0, -inv2], [-inv2, 2.0 * value * inv2]],
+            [
+                [[0.0, 0.0], [0.0, 2.0 * inv2 * inv]],
+                [
+                    [0.0, 2.0 * inv2 * inv],
+                    [2.0 * inv2 * inv, -6.0 * value * inv2 * inv],
+                ],
+            ],
+            [self, &rhs],
+        )
+    }
+}
+
+// T / &T
+impl<const N: usize> Div<&AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn div(self, rhs: &AdThird<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+        let inv2 = inv * inv;
+
+        AdThird::compose(
+            value,
+            [inv, -value * inv],
+            [[0.0, -inv2], [-inv2, 2.0 * value * inv2]],
+            [
+                [[0.0, 0.0], [0.0, 2.0 * inv2 * inv]],
+                [
+                    [0.0, 2.0 * inv2 * inv],
+                    [2.0 * inv2 * inv, -6.0 * value * inv2 * inv],
+                ],
+            ],
+            [&self, rhs],
+        )
+    }
+}
+
+// T / T
+impl<const N: usize> Div<AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn div(self, rhs: AdThird<N>) -> Self::Output {
+        if rhs.value.abs() == 0.0 {
+            // We don't want to mute this behavior or get NaN as this is fucking undebuggable.
+            panic!("Division By Zero!");
+        }
+
+        let inv = 1.0 / rhs.value;
+        let value = self.value * inv;
+        let inv2 = inv * inv;
+
+        AdThird::compose(
+            value,
+            [inv, -value * inv],
+            [[0.0, -inv2], [-inv2, 2.0 * value * inv2]],
+            [
+                [[0.0, 0.0], [0.0, 2.0 * inv2 * inv]],
+                [
+                    [0.0, 2.0 * inv2 * inv],
+                    [2.0 * inv2 * inv, -6.0 * value * inv2 * inv],
+                ],
+            ],
+            [&self, &rhs],
+        )
+    }
+}
+
+// &T % &T
+impl<const N: usize> Rem<&AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn rem(self, rhs: &AdThird<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// &T % T
+impl<const N: usize> Rem<AdThird<N>> for &AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn rem(self, rhs: AdThird<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % &T
+impl<const N: usize> Rem<&AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn rem(self, rhs: &AdThird<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T % T
+impl<const N: usize> Rem<AdThird<N>> for AdThird<N> {
+    type Output = AdThird<N>;
+
+    fn rem(self, rhs: AdThird<N>) -> Self::Output {
+        unimplemented!();
+    }
+}
+
+// T += &T
+impl<const N: usize> AddAssign<&AdThird<N>> for AdThird<N> {
+    fn add_assign(&mut self, rhs: &AdThird<N>) {
+        *self = &*self + rhs;
+    }
+}
+
+// T += T
+impl<const N: usize> AddAssign<AdThird<N>> for AdThird<N> {
+    fn add_assign(&mut self, rhs: AdThird<N>) {
+        *self = &*self + rhs;
+    }
+}
+
+// T -= &T
+impl<const N: usize> SubAssign<&AdThird<N>> for AdThird<N> {
+    fn sub_assign(&mut self, rhs: &AdThird<N>) {
+        *self = &*self - rhs;
+    }
+}
+
+// T -= T
+impl<const N: usize> SubAssign<AdThird<N>> for AdThird<N> {
+    fn sub_assign(&mut self, rhs: AdThird<N>) {
+        *self = &*self - rhs;
+    }
+}
+
+// T *= &T
+impl<const N: usize> MulAssign<&AdThird<N>> for AdThird<N> {
+    fn mul_assign(&mut self, rhs: &AdThird<N>) {
+        *self = &*self * rhs;
+    }
+}
+
+// T *= T
+impl<const N: usize> MulAssign<AdThird<N>> for AdThird<N> {
+    fn mul_assign(&mut self, rhs: AdThird<N>) {
+        *self = &*self * rhs;
+    }
+}
+
+// T /= &T
+impl<const N: usize> DivAssign<&AdThird<N>> for AdThird<N> {
+    fn div_assign(&mut self, rhs: &AdThird<N>) {
+        *self = &*self / rhs;
+    }
+}
+
+// T /= T
+impl<const N: usize> DivAssign<AdThird<N>> for AdThird<N> {
+    fn div_assign(&mut self, rhs: AdThird<N>) {
+        *self = &*self / rhs;
+    }
+}
+
+// T %= &T
+impl<const N: usize> RemAssign<&AdThird<N>> for AdThird<N> {
+    fn rem_assign(&mut self, rhs: &AdThird<N>) {
+        unimplemented!();
+    }
+}
+
+// T %= T
+impl<const N: usize> RemAssign<AdThird<N>> for AdThird<N> {
+    fn rem_assign(&mut self, rhs: AdThird<N>) {
+        unimplemented!();
+    }
+}
@@ -0,0 +1,47 @@
+/*
+
+This code is generated by meta/scalar_matrix_mul.py at 12:18:17 @ 2026.10.18
+Do not modify it directly.
+
+*/
+use crate::AdThird;
+use na::SMatrix;
+use std::ops::Mul;
+
+impl<const N: usize, const R: usize, const C: usize> Mul<SMatrix<AdThird<N>, R, C>> for AdThird<N> {
+    type Output = SMatrix<AdThird<N>, R, C>;
+
+    fn mul(self, rhs: SMatrix<AdThird<N>, R, C>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const N: usize, const R: usize, const C: usize> Mul<SMatrix<AdThird<N>, R, C>>
+    for &AdThird<N>
+{
+    type Output = SMatrix<AdThird<N>, R, C>;
+
+    fn mul(self, rhs: SMatrix<AdThird<N>, R, C>) -> Self::Output {
+        rhs * self.clone()
+    }
+}
+
+impl<const N: usize, const R: usize, const C: usize> Mul<&SMatrix<AdThird<N>, R, C>>
+    for AdThird<N>
+{
+    type Output = SMatrix<AdThird<N>, R, C>;
+
+    fn mul(self, rhs: &SMatrix<AdThird<N>, R, C>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<const N: usize, const R: usize, const C: usize> Mul<&SMatrix<AdThird<N>, R, C>>
+    for &AdThird<N>
+{
+    type Output = SMatrix<AdThird<N>, R, C>;
+
+    fn mul(self, rhs: &SMatrix<AdThird<N>, R, C>) -> Self::Output {
+        rhs * self.clone()
+    }
+}
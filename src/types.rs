@@ -1,13 +1,47 @@
 #![allow(non_camel_case_types)]
 
-use crate::Ad;
+use crate::{Ad, AdDyn, AdGrad, AdThird};
 
-pub(crate) type vec<const L: usize> = na::SVector<f64, L>;
-pub(crate) type mat<const RC: usize> = na::SMatrix<f64, RC, RC>;
+/// Real number type underlying [`Ad`], such as `f64` (the default) or `f32`.
+///
+/// Any copyable nalgebra real field qualifies, so higher-precision types
+/// (e.g. double-double) can be used for validation.
+pub trait Real: na::RealField + Copy {}
+
+impl<T: na::RealField + Copy> Real for T {}
+
+/// Converts an `f64` constant to the real type `T`
+#[inline]
+pub(crate) fn lit<T: Real>(value: f64) -> T {
+    T::from_subset(&value)
+}
+
+pub(crate) type vec<const L: usize, T = f64> = na::SVector<T, L>;
+pub(crate) type mat<const RC: usize, T = f64> = na::SMatrix<T, RC, RC>;
 
 /// Automatic differentiated vector (from nalgebra)
 /// N is the variable size the vector is w.r.t.
-pub type advec<const N: usize, const L: usize> = na::SVector<Ad<N>, L>;
+pub type advec<const N: usize, const L: usize, T = f64> = na::SVector<Ad<N, T>, L>;
 /// Automatic differentiated matrix (from nalgebra)
 /// N is the variable size the matrix is w.r.t.
-pub type admat<const N: usize, const R: usize, const C: usize> = na::SMatrix<Ad<N>, R, C>;
+pub type admat<const N: usize, const R: usize, const C: usize, T = f64> =
+    na::SMatrix<Ad<N, T>, R, C>;
+/// First-order automatic differentiated vector (from nalgebra)
+/// N is the variable size the vector is w.r.t.
+pub type advec_grad<const N: usize, const L: usize> = na::SVector<AdGrad<N>, L>;
+/// First-order automatic differentiated matrix (from nalgebra)
+/// N is the variable size the matrix is w.r.t.
+pub type admat_grad<const N: usize, const R: usize, const C: usize> = na::SMatrix<AdGrad<N>, R, C>;
+/// Third-order automatic differentiated vector (from nalgebra)
+/// N is the variable size the vector is w.r.t.
+pub type advec_third<const N: usize, const L: usize> = na::SVector<AdThird<N>, L>;
+/// Third-order automatic differentiated matrix (from nalgebra)
+/// N is the variable size the matrix is w.r.t.
+pub type admat_third<const N: usize, const R: usize, const C: usize> =
+    na::SMatrix<AdThird<N>, R, C>;
+/// Automatic differentiated vector with runtime dimensions (from nalgebra)
+/// Both the variable size and the length are chosen at runtime.
+pub type advec_dyn = na::DVector<AdDyn>;
+/// Automatic differentiated matrix with runtime dimensions (from nalgebra)
+/// Both the variable size and the shape are chosen at runtime.
+pub type admat_dyn = na::DMatrix<AdDyn>;